@@ -117,7 +117,7 @@ fn main() -> anyhow::Result<()> {
     println!("Exiting");
 
     server
-        .delete_node(&variable_node_id)
+        .delete_node(&variable_node_id, true)
         .context("delete variable node")?;
 
     println!("Done");
@@ -96,7 +96,7 @@ fn main() -> anyhow::Result<()> {
     println!("Exiting");
 
     server
-        .delete_node(&method_node_id)
+        .delete_node(&method_node_id, true)
         .context("delete method node")?;
 
     println!("Done");
@@ -10,14 +10,14 @@ use std::{
 };
 
 use open62541_sys::{
-    UA_Client, UA_Client_disconnectAsync, UA_Client_run_iterate, UA_UInt32,
-    __UA_Client_AsyncService, UA_STATUSCODE_BADCONNECTIONCLOSED, UA_STATUSCODE_BADDISCONNECT,
+    UA_Client, UA_Client_disconnectAsync, UA_ResponseHeader, UA_UInt32, __UA_Client_AsyncService,
+    UA_STATUSCODE_BADCONNECTIONCLOSED, UA_STATUSCODE_BADDISCONNECT,
 };
 use tokio::{sync::oneshot, task, time::Instant};
 
 use crate::{
-    ua, AsyncSubscription, Attribute, BrowseResult, CallbackOnce, DataType, DataValue, Error,
-    Result, ServiceRequest, ServiceResponse,
+    browse_result::to_browse_result, ua, AsyncSubscription, Attribute, BrowseResult, CallbackOnce,
+    DataType, DataValue, Error, Result, ServiceRequest, ServiceResponse,
 };
 
 /// Timeout for `UA_Client_run_iterate()`.
@@ -38,6 +38,21 @@ const RUN_ITERATE_TIMEOUT: Duration = Duration::from_millis(200);
 /// is dropped when still connected, it will _synchronously_ clean up after itself, thereby blocking
 /// while being dropped. In most cases, this is not the desired behavior.
 ///
+/// When the future returned by a service method is dropped before completion, the request is still
+/// processed by the background task but its result is discarded. Resources held by the pending
+/// request are released when the response arrives (or the client is dropped), and the client stays
+/// usable. The server executes the request nevertheless: this makes idempotent services such as
+/// reading, writing, and browsing cancel-safe, but not services that create state on the server.
+///
+/// In particular, dropping the future of [`create_subscription()`] or
+/// [`AsyncSubscription::create_monitored_item()`] after the request has been sent leaks the
+/// subscription or monitored item: the server creates it, but there is no handle left to delete it.
+/// It remains on the server until the session ends. Likewise, dropped method calls with
+/// [`call_method()`] may still have run on the server.
+///
+/// [`create_subscription()`]: Self::create_subscription
+/// [`call_method()`]: Self::call_method
+///
 /// See [Client](crate::Client) for more details.
 #[derive(Debug)]
 pub struct AsyncClient {
@@ -67,9 +82,7 @@ impl AsyncClient {
         Ok(crate::Client::new(endpoint_url)?.into_async())
     }
 
-    pub(crate) fn from_sync(client: ua::Client) -> Self {
-        let client = Arc::new(client);
-
+    pub(crate) fn from_sync(client: Arc<ua::Client>) -> Self {
         let background_canceled = Arc::new(AtomicBool::new(false));
 
         // Run the event loop concurrently. We do so on a thread where we may block: we need to call
@@ -132,6 +145,9 @@ impl AsyncClient {
     pub async fn disconnect(mut self) {
         log::info!("Disconnecting from endpoint");
 
+        // Otherwise, the background task would treat the disconnect as lost connection.
+        self.client.stop_reconnect();
+
         let status_code = ua::StatusCode::new(unsafe {
             UA_Client_disconnectAsync(
                 // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
@@ -501,20 +517,7 @@ fn background_task(client: &ua::Client, canceled: &AtomicBool) {
         // Track time of iteration start to report iteration times below.
         let start_of_iteration = Instant::now();
 
-        let status_code = ua::StatusCode::new({
-            log::trace!("Running iterate");
-
-            // This returns after the timeout even when nothing was processed. The internal mutex is
-            // _not_ held for the entire time though, so we can send out requests concurrently while
-            // the client is running the iteration.
-            unsafe {
-                UA_Client_run_iterate(
-                    // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
-                    client.as_ptr().cast_mut(),
-                    timeout_millis,
-                )
-            }
-        });
+        let status_code = client.run_iterate(timeout_millis);
         if let Err(error) = Error::verify_good(&status_code) {
             // Context-sensitive handling of bad status codes.
             match status_code.into_raw() {
@@ -545,7 +548,8 @@ async fn service_request<R: ServiceRequest>(
     client: &ua::Client,
     request: R,
 ) -> Result<R::Response> {
-    type Cb<R> = CallbackOnce<std::result::Result<<R as ServiceRequest>::Response, ua::StatusCode>>;
+    type Cb<R> =
+        CallbackOnce<std::result::Result<<R as ServiceRequest>::Response, ua::ResponseHeader>>;
 
     unsafe extern "C" fn callback_c<R: ServiceRequest>(
         _client: *mut UA_Client,
@@ -568,7 +572,9 @@ async fn service_request<R: ServiceRequest>(
         let result = if status_code.is_good() {
             Ok(response)
         } else {
-            Err(status_code)
+            // SAFETY: All service responses start with `UA_ResponseHeader`, like requests do.
+            let response_header = unsafe { &*response.as_ptr().cast::<UA_ResponseHeader>() };
+            Err(ua::ResponseHeader::clone_raw(response_header))
         };
 
         // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
@@ -583,7 +589,7 @@ async fn service_request<R: ServiceRequest>(
         // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
         // care if that succeeds though: the receiver might already have gone out of scope (when its
         // future has been canceled) and we must not panic in FFI callbacks.
-        let _unused = tx.send(result.map_err(Error::new));
+        let _unused = tx.send(result.map_err(Error::service_fault));
     };
 
     log::debug!("Running {}", R::type_name());
@@ -617,27 +623,38 @@ async fn service_request<R: ServiceRequest>(
         .unwrap_or(Err(Error::internal("callback should send result")))
 }
 
-/// Converts [`ua::BrowseResult`] to our public result type.
-fn to_browse_result(result: &ua::BrowseResult, node_id: Option<&ua::NodeId>) -> BrowseResult {
-    // Make sure to verify the inner status code inside `BrowseResult`. The service request finishes
-    // without error, even when browsing the node has failed.
-    Error::verify_good(&result.status_code())?;
-
-    let references = if let Some(references) = result.references() {
-        references.into_vec()
-    } else {
-        // When no references exist, some OPC UA servers do not return an empty references array but
-        // an invalid (unset) one instead, e.g. Siemens SIMOTION. We treat it as an empty array, and
-        // continue without error.
-        if let Some(node_id) = node_id {
-            log::debug!("Browsing {node_id} returned unset references, assuming none exist");
-        } else {
-            log::debug!(
-                "Browsing continuation point returned unset references, assuming none exist",
-            );
-        }
-        Vec::new()
-    };
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::FutureExt as _;
+    use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME;
+
+    use crate::{test_server::TestServer, ua};
+
+    #[test]
+    fn drop_pending_read() {
+        // With a single thread, nothing else runs while the future is pending.
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("should create runtime");
+
+        let server = TestServer::start();
+        let client = server.connect().into_async();
+        let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
 
-    Ok((references, result.continuation_point()))
+        runtime.block_on(async {
+            // Poll once to send the request, then drop the future before the response arrives.
+            assert!(client.read_value(&node_id).now_or_never().is_none());
+
+            let value = tokio::time::timeout(Duration::from_secs(5), client.read_value(&node_id))
+                .await
+                .expect("should not hang after dropped read")
+                .expect("should read value");
+            assert!(value.value().to_scalar::<ua::DateTime>().is_some());
+
+            client.disconnect().await;
+        });
+    }
 }
@@ -42,7 +42,7 @@ attribute_impl!(
     (EventNotifier, Byte),
     (Value, Variant),
     (DataType, NodeId),
-    (ValueRank, UInt32),
+    (ValueRank, Int32),
     (ArrayDimensions, Variant),
     (AccessLevel, Byte),
     (AccessLevelEx, UInt32),
@@ -1,4 +1,32 @@
-use crate::{ua, Result};
+use crate::{ua, Error, Result};
 
 /// Result type for browsing.
 pub type BrowseResult = Result<(Vec<ua::ReferenceDescription>, Option<ua::ContinuationPoint>)>;
+
+/// Converts [`ua::BrowseResult`] to our public result type.
+pub(crate) fn to_browse_result(
+    result: &ua::BrowseResult,
+    node_id: Option<&ua::NodeId>,
+) -> BrowseResult {
+    // Make sure to verify the inner status code inside `BrowseResult`. The service request finishes
+    // without error, even when browsing the node has failed.
+    Error::verify_good(&result.status_code())?;
+
+    let references = if let Some(references) = result.references() {
+        references.to_vec()
+    } else {
+        // When no references exist, some OPC UA servers do not return an empty references array but
+        // an invalid (unset) one instead, e.g. Siemens SIMOTION. We treat it as an empty array, and
+        // continue without error.
+        if let Some(node_id) = node_id {
+            log::debug!("Browsing {node_id} returned unset references, assuming none exist");
+        } else {
+            log::debug!(
+                "Browsing continuation point returned unset references, assuming none exist",
+            );
+        }
+        Vec::new()
+    };
+
+    Ok((references, result.continuation_point()))
+}
@@ -1,8 +1,32 @@
-use std::{ffi::CString, time::Duration};
+#[cfg(feature = "config")]
+mod config_file;
 
-use open62541_sys::{UA_ClientConfig, UA_Client_connect};
+use std::{
+    collections::HashSet,
+    ffi::{c_void, CString},
+    num::NonZeroUsize,
+    ptr, slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
 
-use crate::{ua, DataType as _, Error, Result};
+use open62541_sys::{
+    UA_Client, UA_ClientConfig, UA_Client_HistoryRead_raw, UA_Client_connect,
+    UA_Client_findServers, UA_Client_getEndpoints, __UA_Client_Service,
+    UA_ExtensionObject, UA_NodeId, UA_RequestHeader, UA_ResponseHeader,
+    UA_STATUSCODE_BADCONNECTIONCLOSED, UA_STATUSCODE_BADDISCONNECT,
+};
+
+#[cfg(feature = "config")]
+pub use self::config_file::ConfigFileError;
+use crate::{
+    browse_result::to_browse_result, ua, Attribute, BrowseResult, DataChangeSubscription, DataType,
+    DataValue, Error, EventSubscription, Result, ServiceRequest, ServiceResponse, Subscription,
+};
 
 /// Builder for [`Client`].
 ///
@@ -25,19 +49,19 @@ use crate::{ua, DataType as _, Error, Result};
 /// # }
 /// ```
 #[derive(Debug, Default)]
-pub struct ClientBuilder(ua::ClientConfig);
+pub struct ClientBuilder(ua::ClientConfig, ClientOptions);
 
 impl ClientBuilder {
     /// Sets (response) timeout.
     ///
-    /// # Panics
+    /// This applies to all requests, including those sent when connecting: when the server does not
+    /// respond in time (or is not reachable at all), [`connect()`](Self::connect) fails with status
+    /// code [`ua::StatusCode::BADTIMEOUT`] (see [`Error::is_timeout()`]).
     ///
-    /// The given duration must be non-negative and less than 4,294,967,295 milliseconds (less than
-    /// 49.7 days).
+    /// Durations longer than 4,294,967,295 milliseconds (about 49.7 days) are capped to this value.
     #[must_use]
     pub fn timeout(mut self, timeout: Duration) -> Self {
-        self.config_mut().timeout = u32::try_from(timeout.as_millis())
-            .expect("timeout (in milliseconds) should be in range of u32");
+        self.config_mut().timeout = duration_millis(timeout);
         self
     }
 
@@ -51,6 +75,49 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets application URI of client.
+    ///
+    /// When connecting with certificates, this must match the URI in the subject alternative name
+    /// of the client certificate. Otherwise, the server rejects the connection with status code
+    /// [`ua::StatusCode::BADCERTIFICATEURIINVALID`].
+    ///
+    /// # Panics
+    ///
+    /// The URI must not contain any NUL bytes.
+    #[must_use]
+    pub fn application_uri(mut self, application_uri: &str) -> Self {
+        ua::String::new(application_uri)
+            .expect("application URI should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().clientDescription.applicationUri);
+        self
+    }
+
+    /// Sets product URI of client.
+    ///
+    /// # Panics
+    ///
+    /// The URI must not contain any NUL bytes.
+    #[must_use]
+    pub fn product_uri(mut self, product_uri: &str) -> Self {
+        ua::String::new(product_uri)
+            .expect("product URI should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().clientDescription.productUri);
+        self
+    }
+
+    /// Sets application name of client (with locale).
+    ///
+    /// # Panics
+    ///
+    /// The strings must not contain any NUL bytes.
+    #[must_use]
+    pub fn application_name(mut self, locale: &str, application_name: &str) -> Self {
+        ua::LocalizedText::new(locale, application_name)
+            .expect("application name should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().clientDescription.applicationName);
+        self
+    }
+
     /// Sets user identity token.
     #[must_use]
     pub fn user_identity_token(mut self, user_identity_token: &ua::UserIdentityToken) -> Self {
@@ -60,52 +127,245 @@ impl ClientBuilder {
         self
     }
 
-    /// Sets secure channel life time.
+    /// Sets user name and password for authentication.
+    ///
+    /// This is a shortcut for [`user_identity_token()`](Self::user_identity_token) with a
+    /// [`ua::UserNameIdentityToken`]. Without it, the client connects anonymously.
+    ///
+    /// The credentials are kept in the client configuration for as long as the client exists: they
+    /// are needed to re-activate the session after reconnects.
+    ///
+    /// When the server rejects the credentials, [`connect()`](Self::connect) fails with status code
+    /// [`ua::StatusCode::BADUSERACCESSDENIED`] (or a related status code such as
+    /// [`ua::StatusCode::BADIDENTITYTOKENREJECTED`]).
+    ///
+    /// # Panics
+    ///
+    /// The user name and password must not contain any NUL bytes.
+    #[must_use]
+    pub fn username_password(self, username: &str, password: &str) -> Self {
+        self.user_identity_token(&ua::UserIdentityToken::UserName(
+            ua::UserNameIdentityToken::new(username, password),
+        ))
+    }
+
+    /// Sets required message security mode.
+    ///
+    /// Only endpoints with this security mode are considered when connecting. By default, the
+    /// client selects any matching endpoint.
+    ///
+    /// Note: The bundled build of open62541 does not include encryption support. This means that
+    /// only endpoints with [`ua::MessageSecurityMode::NONE`] can be connected to at the moment. Use
+    /// this to make sure that the client never picks any other endpoint.
+    #[must_use]
+    pub fn security_mode(mut self, security_mode: &ua::MessageSecurityMode) -> Self {
+        security_mode.clone_into_raw(&mut self.config_mut().securityMode);
+        self
+    }
+
+    /// Sets required security policy URI.
     ///
-    /// After this life time, the channel needs to be renewed.
+    /// Only endpoints with this security policy are considered when connecting. By default, the
+    /// client selects any matching security policy. See [`security_mode()`](Self::security_mode)
+    /// for restrictions.
     ///
     /// # Panics
     ///
-    /// The given duration must be non-negative and less than 4,294,967,295 milliseconds (less than
-    /// 49.7 days).
+    /// The URI must not contain any NUL bytes.
+    #[must_use]
+    pub fn security_policy_uri(mut self, security_policy_uri: &str) -> Self {
+        ua::String::new(security_policy_uri)
+            .expect("security policy URI should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().securityPolicyUri);
+        self
+    }
+
+    /// Sets secure channel life time.
+    ///
+    /// After this life time, the channel needs to be renewed. The client renews the channel by
+    /// itself before it expires, as long as its event loop is running (see
+    /// [`Client::run_iterate()`]).
+    ///
+    /// Durations longer than 4,294,967,295 milliseconds (about 49.7 days) are capped to this value.
     #[must_use]
     pub fn secure_channel_life_time(mut self, secure_channel_life_time: Duration) -> Self {
-        self.config_mut().secureChannelLifeTime =
-            u32::try_from(secure_channel_life_time.as_millis())
-                .expect("secure channel life time (in milliseconds) should be in range of u32");
+        self.config_mut().secureChannelLifeTime = duration_millis(secure_channel_life_time);
         self
     }
 
     /// Sets requested session timeout.
     ///
-    /// # Panics
+    /// The server may revise this value. When the client does not communicate with the server for
+    /// this amount of time, the server closes the session.
     ///
-    /// The given duration must be non-negative and less than 4,294,967,295 milliseconds (less than
-    /// 49.7 days).
+    /// Durations longer than 4,294,967,295 milliseconds (about 49.7 days) are capped to this value.
     #[must_use]
     pub fn requested_session_timeout(mut self, requested_session_timeout: Duration) -> Self {
-        self.config_mut().requestedSessionTimeout =
-            u32::try_from(requested_session_timeout.as_millis())
-                .expect("secure channel life time (in milliseconds) should be in range of u32");
+        self.config_mut().requestedSessionTimeout = duration_millis(requested_session_timeout);
+        self
+    }
+
+    /// Enables or disables automatic reconnect.
+    ///
+    /// This is enabled by default. When the connection is lost unexpectedly, the client reopens
+    /// the secure channel and re-activates its session (or creates a new one when the server has
+    /// closed it in the meantime). This happens while running the event loop, i.e. when calling
+    /// [`Client::run_iterate()`] or running service requests.
+    ///
+    /// Reconnecting is retried in every iteration of the event loop, unless a backoff has been set
+    /// with [`reconnect_backoff()`](Self::reconnect_backoff). Server state that is bound to the
+    /// session does not survive when a new session must be created: subscriptions and monitored
+    /// items are gone (unless re-created, see below), and continuation points become invalid.
+    ///
+    /// When disabled, the client becomes unusable after losing its connection (or session). Check
+    /// with [`Client::state()`] and create a new client.
+    #[must_use]
+    pub fn auto_reconnect(mut self, auto_reconnect: bool) -> Self {
+        let config = self.config_mut();
+        config.noReconnect = !auto_reconnect;
+        config.noNewSession = !auto_reconnect;
+        self
+    }
+
+    /// Sets exponential backoff for automatic reconnect.
+    ///
+    /// Without this, the client tries to reconnect in every iteration of its event loop after the
+    /// connection has been lost. With a backoff, the first attempt is made `min` after the loss and
+    /// the delay doubles with each failed attempt, up to `max`. It is reset to `min` once the
+    /// session has been activated again.
+    ///
+    /// The backoff applies to the event loop, i.e. to [`Client::run_iterate()`], the driver thread
+    /// from [`Client::spawn_driver()`], and the background task of `AsyncClient`. Blocking service
+    /// methods still try to reconnect right away when they are called in the meantime.
+    ///
+    /// When the server no longer knows the previous session (e.g. because it has been restarted),
+    /// a new session is created. Once it has been activated, the event loop re-creates all
+    /// subscriptions and monitored items that have been created with `Client` and whose handles
+    /// are still alive, with the same callbacks. Their IDs change in the process. Subscriptions of
+    /// `AsyncClient`, detached subscriptions, and subscriptions from before disconnecting on
+    /// purpose are not re-created. Continuation points become invalid in any case.
+    ///
+    /// This has no effect when automatic reconnect has been disabled with
+    /// [`auto_reconnect()`](Self::auto_reconnect).
+    #[must_use]
+    pub fn reconnect_backoff(mut self, min: Duration, max: Duration) -> Self {
+        self.1.reconnect_backoff = Some((min, max));
         self
     }
 
     /// Sets connectivity check interval.
     ///
-    /// Use `None` to disable background task.
+    /// When the client has not communicated with the server for this amount of time, it reads the
+    /// server state as a keep-alive. This prevents idle sessions from timing out on the server (see
+    /// [`requested_session_timeout()`](Self::requested_session_timeout)) and detects connections
+    /// that were lost silently. As with channel renewal, this requires the client's event loop to be
+    /// running.
     ///
-    /// # Panics
+    /// Use `None` to disable background task.
     ///
-    /// The given duration must be non-negative and less than 4,294,967,295 milliseconds (less than
-    /// 49.7 days).
+    /// Durations longer than 4,294,967,295 milliseconds (about 49.7 days) are capped to this value.
     #[must_use]
     pub fn connectivity_check_interval(
         mut self,
         connectivity_check_interval: Option<Duration>,
     ) -> Self {
         self.config_mut().connectivityCheckInterval =
-            u32::try_from(connectivity_check_interval.map_or(0, |interval| interval.as_millis()))
-                .expect("connectivity check interval (in milliseconds) should be in range of u32");
+            connectivity_check_interval.map_or(0, duration_millis);
+        self
+    }
+
+    /// Sets number of outstanding publish requests.
+    ///
+    /// The client keeps this many publish requests queued on the server, for all subscriptions
+    /// combined. The server answers each of them with notifications when they become available,
+    /// and the client sends a new publish request in return, as long as its event loop is running
+    /// (see [`Client::spawn_driver()`]). When there are too few requests outstanding, e.g. with many
+    /// busy subscriptions or high latency, the server must hold back notifications and may
+    /// eventually drop them.
+    ///
+    /// This is `10` by default.
+    #[must_use]
+    pub fn outstanding_publish_requests(mut self, outstanding_publish_requests: u16) -> Self {
+        self.config_mut().outStandingPublishRequests = outstanding_publish_requests;
+        self
+    }
+
+    /// Sets maximum number of operations per request.
+    ///
+    /// Servers limit the number of nodes that may be read or written in a single request (see the
+    /// `MaxNodesPerRead` and `MaxNodesPerWrite` properties in `ServerCapabilities/OperationLimits`)
+    /// and reject larger requests with [`ua::StatusCode::BADTOOMANYOPERATIONS`]. With this option,
+    /// [`Client::read_values()`], [`Client::write_values()`], and related methods split long lists
+    /// into several requests of at most `max_operations_per_request` operations each. The results
+    /// are returned in order, as if a single request had been made.
+    ///
+    /// When a request fails as a whole, the remaining requests are not sent. Note that values from
+    /// earlier requests may already have been written in this case.
+    ///
+    /// This is `0` by default, which sends all operations in a single request. The option does not
+    /// carry over to `AsyncClient`.
+    #[must_use]
+    pub fn max_operations_per_request(mut self, max_operations_per_request: usize) -> Self {
+        self.1.max_operations_per_request = NonZeroUsize::new(max_operations_per_request);
+        self
+    }
+
+    /// Sets custom logger.
+    ///
+    /// By default, log messages from `open62541` are forwarded to the [`log`] crate. With a custom
+    /// logger, they are passed to the given closure instead, already formatted as string. This can
+    /// be used to route them elsewhere, e.g. into `tracing`, or to capture them when diagnosing
+    /// connection problems.
+    ///
+    /// The closure may be called from any thread that runs client operations. It must not call
+    /// back into the client. When the closure panics, the message is dropped.
+    ///
+    /// ```
+    /// use open62541::ClientBuilder;
+    ///
+    /// let builder = ClientBuilder::default().logger(|level, category, message| {
+    ///     eprintln!("{level:?} {category:?}: {message}");
+    /// });
+    /// ```
+    #[must_use]
+    pub fn logger(
+        mut self,
+        logger: impl Fn(ua::LogLevel, ua::LogCategory, &str) + Send + Sync + 'static,
+    ) -> Self {
+        let logging = self.config_mut().logging;
+        // SAFETY: The default config has been initialized with our logger, and the builder has
+        // exclusive access to it.
+        unsafe { crate::set_log_handler(logging, Box::new(logger)) };
+        self
+    }
+
+    /// Sets callback for changes of connection state.
+    ///
+    /// The callback is called with the secure channel state, the session state, and the connect
+    /// status whenever any of them changes, e.g. when the client connects, disconnects, or loses its
+    /// session. This avoids polling [`Client::state()`]. See [`ua::ClientState`] for the meaning of
+    /// the individual values.
+    ///
+    /// The callback is kept for as long as the client lives. It is called from within the client's
+    /// event loop, e.g. in [`Client::run_iterate()`] or while service methods wait for their
+    /// response. It must not call back into the client. When the callback panics, the state change
+    /// is dropped.
+    ///
+    /// ```
+    /// use open62541::{ua, ClientBuilder};
+    ///
+    /// let builder = ClientBuilder::default().on_state_change(|channel_state, session_state, _| {
+    ///     let connected = channel_state == ua::SecureChannelState::OPEN
+    ///         && session_state == ua::SessionState::ACTIVATED;
+    ///     println!("Connected: {connected}");
+    /// });
+    /// ```
+    #[must_use]
+    pub fn on_state_change<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(ua::SecureChannelState, ua::SessionState, ua::StatusCode) + Send + 'static,
+    {
+        self.0.set_state_callback(Box::new(callback));
         self
     }
 
@@ -113,7 +373,8 @@ impl ClientBuilder {
     ///
     /// # Errors
     ///
-    /// This fails when the target server is not reachable.
+    /// This fails when the target server is not reachable, or when it rejects the user identity
+    /// token (see [`username_password()`](Self::username_password)).
     ///
     /// # Panics
     ///
@@ -124,10 +385,115 @@ impl ClientBuilder {
         Ok(client)
     }
 
+    /// Gets endpoints offered by server.
+    ///
+    /// This connects to the server at `endpoint_url` only for the duration of the request, without
+    /// creating a session. See [`Client::get_endpoints()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the target server is not reachable.
+    ///
+    /// # Panics
+    ///
+    /// The endpoint URL must not contain any NUL bytes.
+    pub fn get_endpoints(self, endpoint_url: &str) -> Result<Vec<ua::EndpointDescription>> {
+        log::info!("Getting endpoints of {endpoint_url}");
+
+        let endpoint_url =
+            CString::new(endpoint_url).expect("endpoint URL does not contain NUL bytes");
+
+        let mut client = ua::Client::new_with_config(self.0);
+
+        let mut endpoint_descriptions_size = 0;
+        let mut endpoint_descriptions = ptr::null_mut();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_getEndpoints(
+                // SAFETY: The method does not take ownership of `client`.
+                client.as_mut_ptr(),
+                endpoint_url.as_ptr(),
+                &mut endpoint_descriptions_size,
+                &mut endpoint_descriptions,
+            )
+        });
+        Error::verify_good(&status_code)?;
+
+        // SAFETY: We take ownership of the returned array. An empty response may leave the array
+        // unset (null pointer) which we treat as empty list.
+        let endpoint_descriptions: Option<ua::Array<ua::EndpointDescription>> = unsafe {
+            ua::Array::from_owned_raw_parts(endpoint_descriptions_size, endpoint_descriptions)
+        };
+
+        Ok(endpoint_descriptions
+            .map(ua::Array::into_vec)
+            .unwrap_or_default())
+    }
+
+    /// Finds servers known to discovery server.
+    ///
+    /// This connects to the server at `discovery_url` only for the duration of the request, without
+    /// creating a session. See [`Client::find_servers()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the target server is not reachable.
+    ///
+    /// # Panics
+    ///
+    /// The discovery URL must not contain any NUL bytes.
+    pub fn find_servers(self, discovery_url: &str) -> Result<Vec<ua::ApplicationDescription>> {
+        log::info!("Finding servers at {discovery_url}");
+
+        let discovery_url =
+            CString::new(discovery_url).expect("discovery URL does not contain NUL bytes");
+
+        let mut client = ua::Client::new_with_config(self.0);
+
+        let mut registered_servers_size = 0;
+        let mut registered_servers = ptr::null_mut();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_findServers(
+                // SAFETY: The method does not take ownership of `client`.
+                client.as_mut_ptr(),
+                discovery_url.as_ptr(),
+                // Do not filter by server URIs.
+                0,
+                ptr::null_mut(),
+                // Do not request specific locales.
+                0,
+                ptr::null_mut(),
+                &mut registered_servers_size,
+                &mut registered_servers,
+            )
+        });
+        Error::verify_good(&status_code)?;
+
+        // SAFETY: We take ownership of the returned array. An empty response may leave the array
+        // unset (null pointer) which we treat as empty list.
+        let registered_servers: Option<ua::Array<ua::ApplicationDescription>> =
+            unsafe { ua::Array::from_owned_raw_parts(registered_servers_size, registered_servers) };
+
+        Ok(registered_servers
+            .map(ua::Array::into_vec)
+            .unwrap_or_default())
+    }
+
     /// Builds OPC UA client.
     #[must_use]
-    fn build(self) -> Client {
-        Client(ua::Client::new_with_config(self.0))
+    fn build(mut self) -> Client {
+        // We replace the automatic reconnect of `open62541` with our own. It must not be enabled
+        // when automatic reconnect has been disabled altogether.
+        let auto_reconnect = !self.config_mut().noReconnect;
+        let reconnect_backoff = self.1.reconnect_backoff.filter(|_| auto_reconnect);
+        if reconnect_backoff.is_some() {
+            self.config_mut().noReconnect = true;
+        }
+
+        let mut client = ua::Client::new_with_config(self.0);
+        if let Some((min, max)) = reconnect_backoff {
+            client = client.with_reconnect_backoff(ua::ReconnectBackoff::new(min, max));
+        }
+        Client(Arc::new(client), self.1)
     }
 
     /// Access client configuration.
@@ -150,11 +516,46 @@ impl ClientBuilder {
 ///
 /// To disconnect, prefer method [`disconnect()`](Self::disconnect) over simply dropping the client:
 /// disconnection involves server communication and might take a short amount of time.
+///
+/// Since `disconnect()` consumes the client, it is not possible to run services on a client that
+/// has been disconnected on purpose. When the connection is lost unexpectedly, services fail with
+/// an [`Error`] (e.g. with status code [`ua::StatusCode::BADCONNECTIONCLOSED`]) until the client
+/// has reconnected. Use [`state()`](Self::state) to inspect the current connection state. To
+/// connect again later with the same configuration, use
+/// [`into_disconnected()`](Self::into_disconnected) instead of `disconnect()`.
+///
+/// Service methods of `Client` such as [`read_value()`](Self::read_value) block the current thread
+/// until the response arrives. In asynchronous code, turn the client into an [`AsyncClient`] with
+/// [`into_async()`](Self::into_async) instead.
+///
+/// # Thread safety
+///
+/// `Client` is [`Send`] and [`Sync`]: it may be shared between threads, e.g. behind an [`Arc`],
+/// and service methods may be called from several threads concurrently. No additional locking is
+/// required. `open62541` is built with thread-safety enabled and guards the underlying `UA_Client`
+/// by an internal mutex. Concurrent service calls take turns on this lock, and so does the event
+/// loop run by [`spawn_driver()`] or [`run_iterate()`].
+///
+/// Callbacks, e.g. of subscriptions, are called from within the event loop. They should not call
+/// blocking service methods on the same client: these wait for the event loop to process their
+/// response.
+///
+/// [`AsyncClient`]: crate::AsyncClient
+/// [`run_iterate()`]: Self::run_iterate
+/// [`spawn_driver()`]: Self::spawn_driver
 #[derive(Debug)]
-pub struct Client(
-    #[allow(dead_code)] // --no-default-features
-    ua::Client,
-);
+pub struct Client(Arc<ua::Client>, ClientOptions);
+
+/// Options that apply to [`Client`].
+///
+/// Unlike the client config, these are handled by us and not passed to `open62541`.
+#[derive(Debug, Default)]
+struct ClientOptions {
+    /// Maximum number of nodes to read or write in single request.
+    max_operations_per_request: Option<NonZeroUsize>,
+    /// Minimum and maximum delay when reconnecting.
+    reconnect_backoff: Option<(Duration, Duration)>,
+}
 
 impl Client {
     /// Creates default client connected to endpoint.
@@ -173,6 +574,76 @@ impl Client {
         ClientBuilder::default().connect(endpoint_url)
     }
 
+    /// Gets endpoints offered by server.
+    ///
+    /// This does not require an existing connection: it connects to the server at `endpoint_url`
+    /// only for the duration of the request. Use this to discover which security modes and policies
+    /// the server supports before connecting with [`ClientBuilder`].
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example() -> Result<()> {
+    /// let endpoints = Client::get_endpoints("opc.tcp://localhost:4840")?;
+    ///
+    /// for endpoint in &endpoints {
+    ///     println!(
+    ///         "{} ({:?}): {}",
+    ///         endpoint.endpoint_url(),
+    ///         endpoint.security_mode(),
+    ///         endpoint.security_policy_uri(),
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the target server is not reachable.
+    ///
+    /// # Panics
+    ///
+    /// The endpoint URL must not contain any NUL bytes.
+    pub fn get_endpoints(endpoint_url: &str) -> Result<Vec<ua::EndpointDescription>> {
+        ClientBuilder::default().get_endpoints(endpoint_url)
+    }
+
+    /// Finds servers known to discovery server.
+    ///
+    /// This does not require an existing connection: it connects to the server at `discovery_url`
+    /// only for the duration of the request. The discovery server may be a Local Discovery Server
+    /// (LDS) that other servers register with, or any other server, which then describes itself.
+    ///
+    /// When the discovery server knows no servers, this returns an empty list.
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example() -> Result<()> {
+    /// let servers = Client::find_servers("opc.tcp://localhost:4840")?;
+    ///
+    /// for server in &servers {
+    ///     println!(
+    ///         "{} ({:?}): {:?}",
+    ///         server.application_uri(),
+    ///         server.application_type(),
+    ///         server.discovery_urls(),
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the discovery server is not reachable.
+    ///
+    /// # Panics
+    ///
+    /// The discovery URL must not contain any NUL bytes.
+    pub fn find_servers(discovery_url: &str) -> Result<Vec<ua::ApplicationDescription>> {
+        ClientBuilder::default().find_servers(discovery_url)
+    }
+
     /// Turns client into [`AsyncClient`].
     ///
     /// The [`AsyncClient`] can be used to access methods in an asynchronous way.
@@ -185,37 +656,3940 @@ impl Client {
     }
 
     /// Gets current channel and session state, and connect status.
+    ///
+    /// When the client is connected, the channel state is [`ua::SecureChannelState::OPEN`] and the
+    /// session state is [`ua::SessionState::ACTIVATED`]. The connect status tells the reason when
+    /// the connection has been lost for good.
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, ua};
+    /// # fn example(client: &Client) {
+    /// let state = client.state();
+    /// let healthy = state.channel_state == ua::SecureChannelState::OPEN
+    ///     && state.session_state == ua::SessionState::ACTIVATED;
+    /// if !healthy {
+    ///     println!("Client is not connected: {}", state.connect_status);
+    /// }
+    /// # }
+    /// ```
     #[must_use]
     pub fn state(&self) -> ua::ClientState {
         self.0.state()
     }
 
-    /// Connects to endpoint.
+    /// Gets raw pointer to underlying `UA_Client`.
     ///
-    /// This method is always called internally before passing new [`Client`] instances to the user:
-    /// our contract states that a `Client` should always be connected.
-    fn connect(&mut self, endpoint_url: &str) -> Result<()> {
-        log::info!("Connecting to endpoint {endpoint_url}");
+    /// This allows calling functions from [`open62541_sys`] that are not (yet) wrapped by this
+    /// crate. The client remains owned by `self` and the pointer is valid only as long as `self` is
+    /// alive.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::Client;
+    /// use open62541_sys::{
+    ///     UA_Client_getState, UA_SecureChannelState, UA_SessionState, UA_StatusCode,
+    /// };
+    ///
+    /// # fn example(client: &Client) {
+    /// let mut channel_state = UA_SecureChannelState::UA_SECURECHANNELSTATE_CLOSED;
+    /// let mut session_state = UA_SessionState::UA_SESSIONSTATE_CLOSED;
+    /// let mut connect_status: UA_StatusCode = 0;
+    /// unsafe {
+    ///     UA_Client_getState(
+    ///         client.as_raw(),
+    ///         &mut channel_state,
+    ///         &mut session_state,
+    ///         &mut connect_status,
+    ///     );
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The client must not be freed, e.g. with `UA_Client_delete()`, and its configuration must not
+    /// be cleared. Do not disconnect or reconnect the client behind the wrapper's back, use
+    /// [`disconnect()`](Self::disconnect) instead. Functions that are not marked `UA_THREADSAFE`
+    /// must not be called while the client's event loop may run elsewhere, e.g. with a
+    /// [`ClientDriver`] or from [`AsyncClient`](crate::AsyncClient).
+    #[must_use]
+    pub unsafe fn as_raw(&self) -> *mut UA_Client {
+        // SAFETY: Caller upholds invariants of `ua::Client`.
+        unsafe { self.0.as_ptr() }.cast_mut()
+    }
 
-        let endpoint_url =
-            CString::new(endpoint_url).expect("endpoint URL does not contain NUL bytes");
+    /// Runs single iteration of the client's event loop.
+    ///
+    /// This processes pending network events, e.g. responses to asynchronous requests, subscription
+    /// notifications, and renewal of the secure channel. It blocks for up to the given `timeout` if
+    /// nothing is to be done (timeouts longer than `u32::MAX` milliseconds are capped).
+    ///
+    /// Blocking service methods such as [`read_value()`](Self::read_value) run the event loop by
+    /// themselves while waiting for their response. In between, nothing happens in the background:
+    /// in particular, subscription notifications will not arrive unless this method is called
+    /// periodically. Use [`spawn_driver()`](Self::spawn_driver) to do so on a separate thread.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the connection has been closed or cannot be recovered. The client is no
+    /// longer usable in this case.
+    pub fn run_iterate(&self, timeout: Duration) -> Result<()> {
+        run_iterate(&self.0, timeout)
+    }
 
-        let status_code = ua::StatusCode::new(unsafe {
-            // SAFETY: The method does not take ownership of `client`.
-            UA_Client_connect(self.0.as_mut_ptr(), endpoint_url.as_ptr())
-        });
-        Error::verify_good(&status_code)
+    /// Spawns thread that drives the client's event loop.
+    ///
+    /// The thread calls [`run_iterate()`](Self::run_iterate) in a loop until the returned handle is
+    /// dropped (or the connection is closed). Dropping the handle stops the loop and waits for the
+    /// thread to finish, which may take up to 200 ms.
+    ///
+    /// This is not required when using [`AsyncClient`]: it already runs its own event loop.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let driver = client.spawn_driver();
+    /// // Asynchronous events are processed while the driver is alive.
+    /// drop(driver);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`AsyncClient`]: crate::AsyncClient
+    #[must_use]
+    pub fn spawn_driver(&self) -> ClientDriver {
+        ClientDriver::spawn(&self.0)
     }
 
-    /// Disconnects from endpoint.
+    /// Spawns task on `tokio` runtime that drives the client's event loop.
     ///
-    /// This consumes the client and handles the graceful shutdown of the connection. This should be
-    /// preferred over simply dropping the instance to give the server a chance to clean up and also
-    /// to avoid blocking unexpectedly when the client is being dropped without calling this method.
-    // Forward any result as-is to detect mismatching method signatures at compile time if the
-    // return type of the inner method should ever change.
-    #[allow(clippy::semicolon_if_nothing_returned)]
-    pub fn disconnect(self) {
-        self.0.disconnect()
+    /// This is like [`spawn_driver()`](Self::spawn_driver) but runs the event loop as blocking task
+    /// on the given runtime instead of a thread of its own. Callbacks, e.g. of subscriptions, are
+    /// then invoked from the runtime's blocking thread pool.
+    ///
+    /// Dropping the returned handle stops the loop. This does not wait for the task to finish (it
+    /// may be dropped within the runtime): the task notices the cancellation after its current
+    /// iteration, i.e. within 200 ms. Note that the runtime waits for blocking tasks to finish
+    /// when shutting down.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result};
+    /// # async fn example(client: &Client) -> Result<()> {
+    /// let driver = client.spawn_driver_on(&tokio::runtime::Handle::current());
+    /// // Asynchronous events are processed while the driver is alive.
+    /// drop(driver);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn spawn_driver_on(&self, runtime: &tokio::runtime::Handle) -> ClientDriver {
+        ClientDriver::spawn_on(&self.0, runtime)
+    }
+
+    /// Reads node value.
+    ///
+    /// This blocks until the server has responded (or the request has timed out, see
+    /// [`ClientBuilder::timeout()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    /// let value = client.read_value(&node_id)?;
+    /// println!("Current time: {:?}", value.value());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read.
+    pub fn read_value(&self, node_id: &ua::NodeId) -> Result<DataValue<ua::Variant>> {
+        let mut values = self.read_values(slice::from_ref(node_id))?;
+
+        // ERROR: We give a slice with one item to `read_values()` and expect a single result value.
+        let Some(value) = values.pop() else {
+            return Err(Error::internal("read should return a result"));
+        };
+
+        value
+    }
+
+    /// Reads node attribute.
+    ///
+    /// The attribute determines the data type of the returned value. Use the typed constants in
+    /// [`ua::AttributeId`] such as [`ua::AttributeId::DISPLAYNAME_T`]:
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER);
+    /// let display_name = client.read_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T)?;
+    /// println!("Display name: {}", display_name.value().text());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// To read only the value attribute, you can also use [`read_value()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or the attribute cannot be read.
+    ///
+    /// [`read_value()`]: Self::read_value
+    pub fn read_attribute<T: Attribute>(
+        &self,
+        node_id: &ua::NodeId,
+        attribute: T,
+    ) -> Result<DataValue<T::Value>> {
+        let mut values = self.read_attributes(node_id, &[attribute.id()])?;
+
+        // ERROR: We give a slice with one item to `read_attributes()` and expect a single result.
+        let Some(value) = values.pop() else {
+            return Err(Error::internal("read should return a result"));
+        };
+
+        value.and_then(DataValue::into_scalar::<T::Value>)
+    }
+
+    /// Reads several node attributes.
+    ///
+    /// The size and order of the result list matches the size and order of the given attribute ID
+    /// list.
+    ///
+    /// This issues only a single request to the OPC UA server (and should be preferred over several
+    /// individual requests with [`read_attribute()`] when inspecting a node).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client, node_id: &ua::NodeId) -> Result<()> {
+    /// let attribute_ids = [
+    ///     ua::AttributeId::VALUE,
+    ///     ua::AttributeId::DATATYPE,
+    ///     ua::AttributeId::ACCESSLEVEL,
+    /// ];
+    /// for (attribute_id, value) in attribute_ids
+    ///     .iter()
+    ///     .zip(client.read_attributes(node_id, &attribute_ids)?)
+    /// {
+    ///     match value {
+    ///         Ok(value) => println!("{attribute_id:?}: {:?}", value.value()),
+    ///         Err(error) => println!("{attribute_id:?}: {error}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// To read only a single attribute, you can also use [`read_attribute()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When the node does not exist or one of the
+    /// attributes cannot be read, an inner `Err` is returned.
+    ///
+    /// [`read_attribute()`]: Self::read_attribute
+    pub fn read_attributes(
+        &self,
+        node_id: &ua::NodeId,
+        attribute_ids: &[ua::AttributeId],
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        self.read_many_attributes(
+            &attribute_ids
+                .iter()
+                .map(|attribute_id| (node_id.clone(), attribute_id.clone()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Reads values of several nodes at once.
+    ///
+    /// This issues only a single request to the OPC UA server (and should be preferred over several
+    /// individual requests with [`read_value()`] when reading multiple nodes).
+    ///
+    /// The size and order of the result list matches the size and order of the given node ID list.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_ids = [ua::NodeId::numeric(0, 2258), ua::NodeId::numeric(0, 2259)];
+    /// for (node_id, value) in node_ids.iter().zip(client.read_values(&node_ids)?) {
+    ///     match value {
+    ///         Ok(value) => println!("{node_id}: {:?}", value.value()),
+    ///         Err(error) => println!("{node_id}: {error}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or its value
+    /// attribute cannot be read, an inner `Err` is returned.
+    ///
+    /// [`read_value()`]: Self::read_value
+    pub fn read_values(
+        &self,
+        node_ids: &[ua::NodeId],
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        self.read_many_attributes(
+            &node_ids
+                .iter()
+                .map(|node_id| (node_id.clone(), ua::AttributeId::VALUE))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Reads a combination of node attributes.
+    ///
+    /// The size and order of the result list matches the size and order of the given node ID and
+    /// attribute ID list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or one of the
+    /// attributes cannot be read, an inner `Err` is returned.
+    pub fn read_many_attributes(
+        &self,
+        node_attributes: &[(ua::NodeId, ua::AttributeId)],
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        let nodes_to_read: Vec<_> = node_attributes
+            .iter()
+            .map(|(node_id, attribute_id)| {
+                ua::ReadValueId::init()
+                    .with_node_id(node_id)
+                    .with_attribute_id(attribute_id)
+            })
+            .collect();
+
+        self.read(&nodes_to_read, None)
+    }
+
+    /// Reads part of node value.
+    ///
+    /// This reads only the elements of the array value that are selected by `index_range`. Parse
+    /// the range from its string representation, this catches malformed ranges before sending the
+    /// request to the server.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client, node_id: &ua::NodeId) -> Result<()> {
+    /// let index_range: ua::NumericRange = "2:4".parse()?;
+    /// let value = client.read_value_range(node_id, &index_range)?;
+    /// println!("Elements 2 to 4: {:?}", value.value());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read, or when the
+    /// range does not match the value, e.g. when it selects no elements at all.
+    pub fn read_value_range(
+        &self,
+        node_id: &ua::NodeId,
+        index_range: &ua::NumericRange,
+    ) -> Result<DataValue<ua::Variant>> {
+        let node_to_read = ua::ReadValueId::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::VALUE)
+            .with_index_range(index_range);
+
+        let mut values = self.read(slice::from_ref(&node_to_read), None)?;
+
+        // ERROR: We give a slice with one item to `read()` and expect a single result value.
+        let Some(value) = values.pop() else {
+            return Err(Error::internal("read should return a result"));
+        };
+
+        value
+    }
+
+    /// Reads node value with timeout.
+    ///
+    /// This is like [`read_value()`] but waits at most `timeout` for the response, instead of the
+    /// default timeout set with [`ClientBuilder::timeout()`]. The default remains in place for all
+    /// other requests, including concurrent ones. Use this for latency-sensitive reads that should
+    /// give up quickly, or for reads that are known to take longer than usual.
+    ///
+    /// The timeout is also sent to the server as hint, to let it stop processing the request when
+    /// the client is no longer waiting. Timeouts below one millisecond are rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use std::time::Duration;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::string(1, "Boiler/Temperature");
+    /// match client.read_value_with_timeout(&node_id, Duration::from_millis(50)) {
+    ///     Ok(value) => println!("Temperature: {:?}", value.value()),
+    ///     Err(error) if error.is_timeout() => println!("Temperature not available in time"),
+    ///     Err(error) => return Err(error),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADTIMEOUT`] when the response does not arrive in time. It
+    /// also fails when the node does not exist or its value attribute cannot be read.
+    ///
+    /// [`read_value()`]: Self::read_value
+    pub fn read_value_with_timeout(
+        &self,
+        node_id: &ua::NodeId,
+        timeout: Duration,
+    ) -> Result<DataValue<ua::Variant>> {
+        let node_to_read = ua::ReadValueId::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::VALUE);
+
+        let mut values = self.read(slice::from_ref(&node_to_read), Some(timeout))?;
+
+        // ERROR: We give a slice with one item to `read()` and expect a single result value.
+        let Some(value) = values.pop() else {
+            return Err(Error::internal("read should return a result"));
+        };
+
+        value
+    }
+
+    /// Reads given list of attributes.
+    ///
+    /// This splits the list into several requests when it exceeds the configured maximum number of
+    /// operations per request. It uses the default timeout when `timeout` is `None`.
+    fn read(
+        &self,
+        nodes_to_read: &[ua::ReadValueId],
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        match self.1.max_operations_per_request {
+            Some(max_operations) if nodes_to_read.len() > max_operations.get() => {
+                let mut results = Vec::with_capacity(nodes_to_read.len());
+                for chunk in nodes_to_read.chunks(max_operations.get()) {
+                    results.extend(self.read_chunk(chunk, timeout)?);
+                }
+                Ok(results)
+            }
+            _ => self.read_chunk(nodes_to_read, timeout),
+        }
+    }
+
+    /// Reads given list of attributes in single request.
+    fn read_chunk(
+        &self,
+        nodes_to_read: &[ua::ReadValueId],
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        let request = ua::ReadRequest::init()
+            .with_timestamps_to_return(&ua::TimestampsToReturn::BOTH)
+            .with_nodes_to_read(nodes_to_read);
+
+        let response = match timeout {
+            Some(timeout) => service_request_with_timeout(&self.0, &request, timeout)?,
+            None => service_request(&self.0, &request)?,
+        };
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("read should return results"));
+        };
+
+        // This verifies the status code of each value itself: the service request finishes without
+        // error, even when reading individual nodes has failed.
+        let results: Vec<_> = results
+            .iter()
+            .map(ua::DataValue::to_generic::<ua::Variant>)
+            .collect();
+
+        // The OPC UA specification state that the resulting list has the same number of elements as
+        // the request list. If not, we would not be able to match elements in the two lists anyway.
+        if results.len() != nodes_to_read.len() {
+            return Err(Error::internal("unexpected number of read results"));
+        }
+
+        Ok(results)
+    }
+
+    /// Reads metadata of node.
+    ///
+    /// This reads the basic attributes of the node in a single request: node class, browse name,
+    /// display name, and description, as well as data type, value rank, and access level. The last
+    /// three exist only for variables (and some of them for variable types), they are returned as
+    /// `None` for other nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    /// let metadata = client.read_node_metadata(&node_id)?;
+    /// println!("{}: {:?}", metadata.display_name().text(), metadata.data_type());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist, or when one of the attributes that all nodes have
+    /// cannot be read.
+    pub fn read_node_metadata(&self, node_id: &ua::NodeId) -> Result<ua::NodeMetadata> {
+        // Attributes that not all nodes have may fail to be read. We treat them as absent.
+        fn optional<T: DataType>(value: Result<DataValue<ua::Variant>>) -> Option<T> {
+            value
+                .and_then(DataValue::into_scalar::<T>)
+                .ok()
+                .map(DataValue::into_value)
+        }
+
+        let values = self.read_attributes(
+            node_id,
+            &[
+                ua::AttributeId::NODECLASS,
+                ua::AttributeId::BROWSENAME,
+                ua::AttributeId::DISPLAYNAME,
+                ua::AttributeId::DESCRIPTION,
+                ua::AttributeId::DATATYPE,
+                ua::AttributeId::VALUERANK,
+                ua::AttributeId::ACCESSLEVEL,
+            ],
+        )?;
+
+        // ERROR: `read_attributes()` returns exactly one result for each attribute ID.
+        let Ok(
+            [node_class, browse_name, display_name, description, data_type, value_rank, access_level],
+        ) = <[_; 7]>::try_from(values)
+        else {
+            return Err(Error::internal("unexpected number of read results"));
+        };
+
+        Ok(ua::NodeMetadata::new(
+            node_class?.into_scalar::<ua::NodeClass>()?.into_value(),
+            browse_name?
+                .into_scalar::<ua::QualifiedName>()?
+                .into_value(),
+            display_name?
+                .into_scalar::<ua::LocalizedText>()?
+                .into_value(),
+            optional::<ua::LocalizedText>(description),
+            optional::<ua::NodeId>(data_type),
+            optional::<ua::Int32>(value_rank).map(|value_rank| value_rank.value()),
+            optional::<ua::Byte>(access_level)
+                .map(|access_level| ua::AccessLevel::from_u8(access_level.value())),
+        ))
+    }
+
+    /// Reads historical values of node.
+    ///
+    /// This reads the raw values that the server has stored for the given node's value attribute
+    /// between `start` and `end`. At most `max_values` values are returned, or all available values
+    /// when `max_values` is `0`. The server may return values in several chunks: this requests them
+    /// one after another, until `max_values` is reached or no more values are available.
+    ///
+    /// The returned values are in time order. When `end` is before `start`, the server reads values
+    /// backwards from `start` instead, so `max_values` limits the result to the most recent values.
+    /// Either bound may be left unspecified ([`ua::DateTime::init()`]): without `start`, the server
+    /// reads backwards from `end`; without `end`, it reads forwards from `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let now = SystemTime::now();
+    /// let values = client.history_read_raw(
+    ///     &ua::NodeId::string(1, "Boiler/Temperature"),
+    ///     ua::DateTime::try_from(now - Duration::from_secs(3600))?,
+    ///     ua::DateTime::try_from(now)?,
+    ///     100,
+    /// )?;
+    /// for value in values {
+    ///     println!("{:?}: {:?}", value.source_timestamp(), value.value());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist, or the server does not support historical access
+    /// for it.
+    pub fn history_read_raw(
+        &self,
+        node_id: &ua::NodeId,
+        start: ua::DateTime,
+        end: ua::DateTime,
+        max_values: u32,
+    ) -> Result<Vec<ua::DataValue>> {
+        struct Context {
+            max_values: Option<usize>,
+            values: Vec<ua::DataValue>,
+            error: Option<Error>,
+        }
+
+        unsafe extern "C" fn callback_c(
+            _client: *mut UA_Client,
+            _node_id: *const UA_NodeId,
+            _more_data_available: bool,
+            data: *const UA_ExtensionObject,
+            context: *mut c_void,
+        ) -> bool {
+            // SAFETY: `context` is the mutable reference passed below. It outlives the call.
+            let context = unsafe { &mut *context.cast::<Context>() };
+            // SAFETY: Incoming pointer is valid for access during the callback.
+            let data = ua::ExtensionObject::raw_ref(unsafe { &*data });
+
+            let Some(history_data) = data.decoded_content::<ua::HistoryData>() else {
+                context.error = Some(Error::internal("history read should return history data"));
+                // Do not fetch more data, this releases the continuation point.
+                return false;
+            };
+
+            if let Some(data_values) = history_data.data_values() {
+                context.values.extend(data_values.into_vec());
+            }
+
+            // Fetch more data until we have enough. This follows the continuation point when
+            // there is more data available.
+            context
+                .max_values
+                .map_or(true, |max_values| context.values.len() < max_values)
+        }
+
+        let mut context = Context {
+            max_values: (max_values != 0)
+                .then(|| usize::try_from(max_values).unwrap_or(usize::MAX)),
+            values: Vec::new(),
+            error: None,
+        };
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_HistoryRead_raw(
+                // SAFETY: Cast to `mut` pointer. The function only calls `__UA_Client_Service()`,
+                // which is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                node_id.as_ptr(),
+                Some(callback_c),
+                ua::DateTime::to_raw_copy(&start),
+                ua::DateTime::to_raw_copy(&end),
+                // SAFETY: The index range is only read for the scope of the function. It is empty
+                // and owns no memory.
+                ua::String::to_raw_copy(&ua::String::init()),
+                false,
+                max_values,
+                ua::TimestampsToReturn::BOTH.into_raw(),
+                ptr::addr_of_mut!(context).cast::<c_void>(),
+            )
+        });
+        Error::verify_good(&status_code)?;
+
+        if let Some(error) = context.error {
+            return Err(error);
+        }
+
+        let mut values = context.values;
+        if let Some(max_values) = context.max_values {
+            // The last chunk may have brought us past the requested number of values.
+            values.truncate(max_values);
+        }
+        if history_reads_backwards(&start, &end) {
+            // When reading backwards, the server returns the most recent values first.
+            values.reverse();
+        }
+
+        Ok(values)
+    }
+
+    /// Writes node value.
+    ///
+    /// This sends only the value itself: neither status code nor timestamps are set, leaving it up
+    /// to the server to fill them in. Many servers reject writes that include timestamps.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::string(1, "Boiler/Temperature");
+    /// client.write_value(&node_id, &ua::Variant::scalar(ua::Double::new(21.5)))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be written.
+    pub fn write_value(&self, node_id: &ua::NodeId, value: &ua::Variant) -> Result<()> {
+        let mut results = self.write_values(&[(node_id.clone(), value.clone())])?;
+
+        // ERROR: We give a slice with one item to `write_values()` and expect a single result.
+        let Some(result) = results.pop() else {
+            return Err(Error::internal("write should return a result"));
+        };
+
+        result
+    }
+
+    /// Writes node value with status code and timestamps.
+    ///
+    /// Unlike [`write_value()`], this sends the data value verbatim, including the status code and
+    /// timestamps that have been set. This allows forwarding values of bad or uncertain quality.
+    /// Note that many servers reject writes that include timestamps.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::string(1, "Boiler/Temperature");
+    /// let source_timestamp = ua::DateTime::try_from(std::time::SystemTime::now())?;
+    /// let value = ua::DataValue::new(ua::Variant::scalar(ua::Double::new(21.5)))
+    ///     .with_status_code(&ua::StatusCode::UNCERTAIN)
+    ///     .with_source_timestamp(&source_timestamp);
+    /// client.write_data_value(&node_id, &value)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be written.
+    ///
+    /// [`write_value()`]: Self::write_value
+    pub fn write_data_value(&self, node_id: &ua::NodeId, value: &ua::DataValue) -> Result<()> {
+        let node_to_write = ua::WriteValue::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::VALUE)
+            .with_value(value);
+
+        let mut results = self.write(slice::from_ref(&node_to_write))?;
+
+        // ERROR: We give a slice with one item to `write()` and expect a single result.
+        let Some(result) = results.pop() else {
+            return Err(Error::internal("write should return a result"));
+        };
+
+        result
+    }
+
+    /// Writes values of several nodes at once.
+    ///
+    /// This issues only a single request to the OPC UA server (and should be preferred over several
+    /// individual requests with [`write_value()`] when writing multiple nodes). See there for how
+    /// values are sent.
+    ///
+    /// The size and order of the result list matches the size and order of the given list.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let setpoint = ua::NodeId::string(1, "Boiler/Setpoint");
+    /// let enabled = ua::NodeId::string(1, "Boiler/Enabled");
+    /// let results = client.write_values(&[
+    ///     (setpoint, ua::Variant::scalar(ua::Double::new(21.5))),
+    ///     (enabled, ua::Variant::scalar(ua::Boolean::new(true))),
+    /// ])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or its value
+    /// attribute cannot be written, an inner `Err` is returned.
+    ///
+    /// [`write_value()`]: Self::write_value
+    pub fn write_values(
+        &self,
+        node_values: &[(ua::NodeId, ua::Variant)],
+    ) -> Result<Vec<Result<()>>> {
+        let attribute_id = ua::AttributeId::VALUE;
+
+        let nodes_to_write: Vec<_> = node_values
+            .iter()
+            .map(|(node_id, value)| {
+                // `ua::DataValue::new()` sets only `hasValue`, all other fields remain unset.
+                let value = ua::DataValue::new(value.clone());
+
+                ua::WriteValue::init()
+                    .with_node_id(node_id)
+                    .with_attribute_id(&attribute_id)
+                    .with_value(&value)
+            })
+            .collect();
+
+        self.write(&nodes_to_write)
+    }
+
+    /// Writes part of node value.
+    ///
+    /// This replaces only the elements of the array value that are selected by `index_range`,
+    /// leaving all other elements unchanged. The given value must be an array with exactly as many
+    /// elements as the range selects. See [`write_value()`] for how values are sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client, node_id: &ua::NodeId) -> Result<()> {
+    /// let index_range: ua::NumericRange = "2:3".parse()?;
+    /// let elements = ua::Array::from_slice(&[ua::Double::new(1.5), ua::Double::new(2.5)]);
+    /// client.write_value_range(node_id, &index_range, &ua::Variant::array(elements))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADINDEXRANGEINVALID`] when the number of elements in the
+    /// value does not match the range, without sending the request. It also fails when the node
+    /// does not exist or its value attribute cannot be written, or when the range does not match
+    /// the current value.
+    ///
+    /// [`write_value()`]: Self::write_value
+    pub fn write_value_range(
+        &self,
+        node_id: &ua::NodeId,
+        index_range: &ua::NumericRange,
+        value: &ua::Variant,
+    ) -> Result<()> {
+        // Check length here: the server's error would not tell us what was wrong with the request.
+        if value.array_length() != index_range.element_count() {
+            return Err(Error::Server(ua::StatusCode::BADINDEXRANGEINVALID));
+        }
+
+        // `ua::DataValue::new()` sets only `hasValue`, all other fields remain unset.
+        let node_to_write = ua::WriteValue::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::VALUE)
+            .with_index_range(index_range)
+            .with_value(&ua::DataValue::new(value.clone()));
+
+        let mut results = self.write(slice::from_ref(&node_to_write))?;
+
+        // ERROR: We give a slice with one item to `write()` and expect a single result.
+        let Some(result) = results.pop() else {
+            return Err(Error::internal("write should return a result"));
+        };
+
+        result
+    }
+
+    /// Writes given list of attributes.
+    ///
+    /// This splits the list into several requests when it exceeds the configured maximum number of
+    /// operations per request.
+    fn write(&self, nodes_to_write: &[ua::WriteValue]) -> Result<Vec<Result<()>>> {
+        match self.1.max_operations_per_request {
+            Some(max_operations) if nodes_to_write.len() > max_operations.get() => {
+                let mut results = Vec::with_capacity(nodes_to_write.len());
+                for chunk in nodes_to_write.chunks(max_operations.get()) {
+                    results.extend(self.write_chunk(chunk)?);
+                }
+                Ok(results)
+            }
+            _ => self.write_chunk(nodes_to_write),
+        }
+    }
+
+    /// Writes given list of values in single request.
+    fn write_chunk(&self, nodes_to_write: &[ua::WriteValue]) -> Result<Vec<Result<()>>> {
+        let request = ua::WriteRequest::init().with_nodes_to_write(nodes_to_write);
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("write should return results"));
+        };
+
+        // The OPC UA specification state that the resulting list has the same number of elements as
+        // the request list. If not, we would not be able to match elements in the two lists anyway.
+        if results.len() != nodes_to_write.len() {
+            return Err(Error::internal("unexpected number of write results"));
+        }
+
+        let results: Vec<_> = results.iter().map(Error::verify_good).collect();
+
+        Ok(results)
+    }
+
+    /// Calls specific method node at object node.
+    ///
+    /// The input arguments must match the method's signature. The output arguments are returned in
+    /// the order defined by the method.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let object_id = ua::NodeId::string(1, "Machine");
+    /// let method_id = ua::NodeId::string(1, "Machine/Start");
+    /// let output_arguments =
+    ///     client.call_method(&object_id, &method_id, &[ua::Variant::scalar(ua::UInt32::new(3))])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the object or method node does not exist, the method cannot be called, or
+    /// the input arguments are unexpected.
+    pub fn call_method(
+        &self,
+        object_id: &ua::NodeId,
+        method_id: &ua::NodeId,
+        input_arguments: &[ua::Variant],
+    ) -> Result<Vec<ua::Variant>> {
+        let request =
+            ua::CallRequest::init().with_methods_to_call(&[ua::CallMethodRequest::init()
+                .with_object_id(object_id)
+                .with_method_id(method_id)
+                .with_input_arguments(input_arguments)]);
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("call should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("call should return a result"));
+        };
+
+        Error::verify_good(&result.status_code())?;
+
+        let output_arguments = if let Some(output_arguments) = result.output_arguments() {
+            output_arguments.into_vec()
+        } else {
+            log::debug!("Calling {method_id} returned unset output arguments, assuming none exist");
+            Vec::new()
+        };
+
+        Ok(output_arguments)
+    }
+
+    /// Reads argument descriptions of method node.
+    ///
+    /// This returns the input and output arguments as defined by the method's `InputArguments` and
+    /// `OutputArguments` properties. When the method has no such property, no arguments are
+    /// returned for it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let method_id = ua::NodeId::string(1, "Machine/Start");
+    /// let (input_arguments, _) = client.method_arguments(&method_id)?;
+    /// for argument in &input_arguments {
+    ///     println!("{}: {:?}", argument.name(), argument.value_type());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the method node does not exist, or when the properties cannot be read or do
+    /// not hold arguments.
+    pub fn method_arguments(
+        &self,
+        method_id: &ua::NodeId,
+    ) -> Result<(Vec<ua::Argument>, Vec<ua::Argument>)> {
+        let input_arguments = self.read_arguments(method_id, "InputArguments")?;
+        let output_arguments = self.read_arguments(method_id, "OutputArguments")?;
+
+        Ok((input_arguments, output_arguments))
+    }
+
+    /// Reads arguments from method property.
+    fn read_arguments(&self, method_id: &ua::NodeId, property: &str) -> Result<Vec<ua::Argument>> {
+        let property_ids =
+            match self.translate_browse_path(method_id, &[ua::QualifiedName::new(0, property)]) {
+                Ok(property_ids) => property_ids,
+                // Methods without arguments may leave out the property entirely.
+                Err(error) if error.status_code() == ua::StatusCode::BADNOMATCH => {
+                    return Ok(Vec::new())
+                }
+                Err(error) => return Err(error),
+            };
+
+        let Some(property_id) = property_ids.first() else {
+            return Ok(Vec::new());
+        };
+
+        let value = self.read_value(property_id)?;
+
+        if value.value().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        value
+            .value()
+            .to_array::<ua::Argument>()
+            .map(ua::Array::into_vec)
+            .ok_or(Error::internal("method property should hold arguments"))
+    }
+
+    /// Browses specific node.
+    ///
+    /// Use [`ua::BrowseDescription::default()`](ua::BrowseDescription) to set sensible defaults to
+    /// browse a specific node's children (forward references of the `HierarchicalReferences` type)
+    /// like this:
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS);
+    /// let browse_description = ua::BrowseDescription::default().with_node_id(&node_id);
+    /// let (references, continuation_point) = client.browse(&browse_description)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// To narrow down the result, adjust the browse description. For example, this returns only
+    /// variables that are components of the given node:
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client, node_id: &ua::NodeId) -> Result<()> {
+    /// let browse_description = ua::BrowseDescription::default()
+    ///     .with_node_id(node_id)
+    ///     .with_reference_type_id(&ua::ReferenceTypeId::HASCOMPONENT.to_node_id())
+    ///     .with_node_class_mask(&ua::NodeClassMask::VARIABLE);
+    /// let (references, _) = client.browse(&browse_description)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Nodes without references return an empty list. When the server (or the client's request)
+    /// limits the number of references per node, the result contains a continuation point too. In
+    /// this case, not all references have been returned yet. Pass the continuation point to
+    /// [`browse_next()`](Self::browse_next) to fetch the remaining references.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or it cannot be browsed.
+    pub fn browse(&self, browse_description: &ua::BrowseDescription) -> BrowseResult {
+        let request =
+            ua::BrowseRequest::init().with_nodes_to_browse(slice::from_ref(browse_description));
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("browse should return a result"));
+        };
+
+        to_browse_result(result, Some(browse_description.node_id()))
+    }
+
+    /// Browses continuation points for more references.
+    ///
+    /// This uses continuation points returned from [`browse()`] whenever not all references were
+    /// returned (due to client or server limits). Each result may include another continuation
+    /// point; repeat until it is `None` to fetch all references:
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client, browse_description: &ua::BrowseDescription) -> Result<()> {
+    /// let (mut references, mut continuation_point) = client.browse(browse_description)?;
+    /// while let Some(next) = continuation_point.take() {
+    ///     for result in client.browse_next(&[next])? {
+    ///         let (more_references, next) = result?;
+    ///         references.extend(more_references);
+    ///         continuation_point = next;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The size and order of the result list matches the size and order of the given continuation
+    /// point list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a continuation point is invalid, an
+    /// inner `Err` is returned.
+    ///
+    /// [`browse()`]: Self::browse
+    pub fn browse_next(
+        &self,
+        continuation_points: &[ua::ContinuationPoint],
+    ) -> Result<Vec<BrowseResult>> {
+        let request = ua::BrowseNextRequest::init().with_continuation_points(continuation_points);
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        // The OPC UA specification state that the resulting list has the same number of elements as
+        // the request list. If not, we would not be able to match elements in the two lists anyway.
+        if results.len() != continuation_points.len() {
+            return Err(Error::internal("unexpected number of browse results"));
+        }
+
+        let results: Vec<_> = results
+            .iter()
+            .map(|result| to_browse_result(result, None))
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Browses nodes recursively.
+    ///
+    /// This browses the node given in `browse_description`, then the target nodes of all returned
+    /// references, and so on, up to `max_depth` levels deep. All nodes are browsed with the same
+    /// parameters, e.g. use [`ua::BrowseDescription::default()`](ua::BrowseDescription) to follow
+    /// hierarchical references only. Continuation points are followed until all references of each
+    /// node have been returned.
+    ///
+    /// The result holds all references in breadth-first order, each with the node ID of the node
+    /// that it was returned for. Each node is browsed only once, even when references to it occur
+    /// several times (or in cycles). References to nodes on other servers are returned but not
+    /// followed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_OBJECTSFOLDER;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let browse_description =
+    ///     ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER));
+    /// for (parent_id, reference) in client.browse_recursive(&browse_description, 3)? {
+    ///     println!("{parent_id} -> {}", reference.node_id().node_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when any of the nodes cannot be browsed.
+    pub fn browse_recursive(
+        &self,
+        browse_description: &ua::BrowseDescription,
+        max_depth: usize,
+    ) -> Result<Vec<(ua::NodeId, ua::ReferenceDescription)>> {
+        let root_id = browse_description.node_id();
+
+        let mut visited = HashSet::from([root_id.clone()]);
+        let mut node_ids = vec![root_id.clone()];
+        let mut results = Vec::new();
+
+        for _ in 0..max_depth {
+            let mut next_node_ids = Vec::new();
+
+            for node_id in node_ids {
+                let references =
+                    self.browse_all(&browse_description.clone().with_node_id(&node_id))?;
+
+                for reference in references {
+                    // Targets on this server are given by namespace index, skip all others.
+                    if let Some(target_id) = reference.node_id().to_node_id(&[]) {
+                        if visited.insert(target_id.clone()) {
+                            next_node_ids.push(target_id);
+                        }
+                    }
+                    results.push((node_id.clone(), reference));
+                }
+            }
+
+            if next_node_ids.is_empty() {
+                break;
+            }
+            node_ids = next_node_ids;
+        }
+
+        Ok(results)
+    }
+
+    /// Browses node, following continuation points until all references have been returned.
+    fn browse_all(
+        &self,
+        browse_description: &ua::BrowseDescription,
+    ) -> Result<Vec<ua::ReferenceDescription>> {
+        let (mut references, mut continuation_point) = self.browse(browse_description)?;
+
+        while let Some(current_continuation_point) = continuation_point {
+            let mut results = self.browse_next(slice::from_ref(&current_continuation_point))?;
+
+            // ERROR: We give a slice with one item to `browse_next()` and expect a single result.
+            let Some(result) = results.pop() else {
+                return Err(Error::internal("browse should return a result"));
+            };
+
+            let (next_references, next_continuation_point) = result?;
+            references.extend(next_references);
+            continuation_point = next_continuation_point;
+        }
+
+        Ok(references)
+    }
+
+    /// Translates browse path to node IDs.
+    ///
+    /// This follows `relative_path` from the `start` node, using forward references of the
+    /// `HierarchicalReferences` type (or any of its subtypes) for each path segment. See
+    /// [`ua::RelativePathElement::default()`] for details.
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_OBJECTSFOLDER;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// // Resolve `/Objects/2:Boiler/2:Temperature`.
+    /// let node_ids = client.translate_browse_path(
+    ///     &ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+    ///     &[
+    ///         ua::QualifiedName::new(2, "Boiler"),
+    ///         ua::QualifiedName::new(2, "Temperature"),
+    ///     ],
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// All matching targets are returned. Targets that the server could not follow to the end of
+    /// the path (e.g. because they reside on another server) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the start node does not exist or when the path does not lead to any node.
+    pub fn translate_browse_path(
+        &self,
+        start: &ua::NodeId,
+        relative_path: &[ua::QualifiedName],
+    ) -> Result<Vec<ua::NodeId>> {
+        let elements: Vec<_> = relative_path
+            .iter()
+            .map(|target_name| ua::RelativePathElement::default().with_target_name(target_name))
+            .collect();
+
+        let browse_path = ua::BrowsePath::init()
+            .with_starting_node(start)
+            .with_relative_path(&ua::RelativePath::init().with_elements(&elements));
+
+        let request = ua::TranslateBrowsePathsToNodeIdsRequest::init()
+            .with_browse_paths(slice::from_ref(&browse_path));
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("translation should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("translation should return a result"));
+        };
+
+        Error::verify_good(&result.status_code())?;
+
+        let Some(targets) = result.targets() else {
+            return Err(Error::internal("translation should return targets"));
+        };
+
+        let node_ids = targets
+            .iter()
+            // Partial matches have unprocessed path elements left, or refer to other servers.
+            .filter(|target| {
+                target.remaining_path_index().is_none() && target.target_id().server_index() == 0
+            })
+            .map(|target| target.target_id().node_id().clone())
+            .collect();
+
+        Ok(node_ids)
+    }
+
+    /// Registers nodes for repeated access.
+    ///
+    /// This tells the server that the given nodes will be accessed repeatedly, e.g. when polling
+    /// their values. The server may return alternative node IDs that it can resolve more quickly.
+    /// Use the returned node IDs in place of the original ones in subsequent requests such as
+    /// [`read_values()`](Self::read_values).
+    ///
+    /// The size and order of the result list matches the size and order of the given node ID list.
+    ///
+    /// Registered node IDs are valid only within the current session: they become invalid when the
+    /// client reconnects (and gets a new session), even when reconnecting automatically. Call this
+    /// again after reconnecting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_ids = [ua::NodeId::string(1, "Boiler/Temperature")];
+    /// let registered_node_ids = client.register_nodes(&node_ids)?;
+    /// for _ in 0..10 {
+    ///     let values = client.read_values(&registered_node_ids)?;
+    ///     println!("{values:?}");
+    /// }
+    /// client.unregister_nodes(&registered_node_ids)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails. Servers do not check whether the nodes exist: this is
+    /// reported by subsequent requests using the registered node IDs.
+    pub fn register_nodes(&self, node_ids: &[ua::NodeId]) -> Result<Vec<ua::NodeId>> {
+        let request = ua::RegisterNodesRequest::init().with_nodes_to_register(node_ids);
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(registered_node_ids) = response.registered_node_ids() else {
+            return Err(Error::internal("registration should return node IDs"));
+        };
+        let registered_node_ids = registered_node_ids.into_vec();
+
+        // The OPC UA specification state that the resulting list has the same number of elements as
+        // the request list. If not, we would not be able to match elements in the two lists anyway.
+        if registered_node_ids.len() != node_ids.len() {
+            return Err(Error::internal("unexpected number of registered node IDs"));
+        }
+
+        Ok(registered_node_ids)
+    }
+
+    /// Unregisters nodes.
+    ///
+    /// This releases node IDs that have been returned by [`register_nodes()`]. The node IDs should
+    /// not be used anymore afterwards.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails.
+    ///
+    /// [`register_nodes()`]: Self::register_nodes
+    pub fn unregister_nodes(&self, node_ids: &[ua::NodeId]) -> Result<()> {
+        let request = ua::UnregisterNodesRequest::init().with_nodes_to_unregister(node_ids);
+
+        let _response = service_request(&self.0, &request)?;
+
+        Ok(())
+    }
+
+    /// Creates subscription with monitored item for data changes.
+    ///
+    /// This subscribes to changes of the given node's value attribute. The server samples the value
+    /// at the given `sampling_interval` and `callback` is called with each new value received. The
+    /// subscription (and the monitored item) is deleted when the returned handle is dropped.
+    ///
+    /// The callback is called from the thread that runs the client's event loop. Notifications are
+    /// only received while the event loop runs, e.g. with [`spawn_driver()`](Self::spawn_driver).
+    /// Values are delivered one after another: when the callback itself runs service requests on
+    /// the client, new notifications are delivered only after the callback has returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME;
+    /// use std::time::Duration;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    /// let subscription = client.create_data_change_subscription(
+    ///     &node_id,
+    ///     Duration::from_millis(100),
+    ///     |value| println!("Received value: {value:?}"),
+    /// )?;
+    ///
+    /// let driver = client.spawn_driver();
+    /// std::thread::sleep(Duration::from_secs(5));
+    ///
+    /// drop(subscription);
+    /// drop(driver);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the subscription cannot be created, or the node does not exist.
+    pub fn create_data_change_subscription(
+        &self,
+        node_id: &ua::NodeId,
+        sampling_interval: Duration,
+        callback: impl FnMut(ua::DataValue) + Send + 'static,
+    ) -> Result<DataChangeSubscription> {
+        DataChangeSubscription::new(&self.0, node_id, sampling_interval, callback)
+    }
+
+    /// Creates subscription with monitored item for events.
+    ///
+    /// This subscribes to events emitted by the given node, e.g. the `Server` object. Each event is
+    /// passed to `callback` as list of event fields, holding the values selected by
+    /// `select_clauses` in the same order. The subscription (and the monitored item) is deleted
+    /// when the returned handle is dropped.
+    ///
+    /// See [`create_data_change_subscription()`](Self::create_data_change_subscription) for details
+    /// on how the callback is called.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER;
+    /// use std::time::Duration;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let select_clauses = ["Message", "Severity"].map(|field| {
+    ///     ua::SimpleAttributeOperand::default()
+    ///         .with_browse_path(&[ua::QualifiedName::new(0, field)])
+    /// });
+    ///
+    /// let subscription = client.create_event_subscription(
+    ///     &ua::NodeId::ns0(UA_NS0ID_SERVER),
+    ///     &select_clauses,
+    ///     |fields| println!("Received event: {fields:?}"),
+    /// )?;
+    ///
+    /// let driver = client.spawn_driver();
+    /// std::thread::sleep(Duration::from_secs(5));
+    ///
+    /// drop(subscription);
+    /// drop(driver);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the subscription cannot be created, or the node does not exist or does not
+    /// emit events.
+    pub fn create_event_subscription(
+        &self,
+        node_id: &ua::NodeId,
+        select_clauses: &[ua::SimpleAttributeOperand],
+        callback: impl FnMut(Vec<ua::Variant>) + Send + 'static,
+    ) -> Result<EventSubscription> {
+        EventSubscription::new(&self.0, node_id, select_clauses, callback)
+    }
+
+    /// Creates subscription.
+    ///
+    /// Monitored items can be added to the returned [`Subscription`]. The subscription is deleted
+    /// on the server, along with its monitored items, when the handle is dropped. This makes sure
+    /// that no subscriptions are leaked when setting them up fails halfway through.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result, ua};
+    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME;
+    /// use std::time::Duration;
+    ///
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let subscription = client.create_subscription(ua::SubscriptionParameters::default())?;
+    ///
+    /// let monitored_item = subscription.create_monitored_item(
+    ///     &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+    ///     Duration::from_millis(100),
+    ///     |value| println!("Received value: {value:?}"),
+    /// )?;
+    ///
+    /// let driver = client.spawn_driver();
+    /// std::thread::sleep(Duration::from_secs(5));
+    ///
+    /// drop(monitored_item);
+    /// drop(subscription);
+    /// drop(driver);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the subscription cannot be created.
+    pub fn create_subscription(
+        &self,
+        parameters: ua::SubscriptionParameters,
+    ) -> Result<Subscription> {
+        Subscription::new(&self.0, &parameters)
+    }
+
+    /// Sets monitoring mode of monitored items.
+    ///
+    /// This pauses or resumes monitored items without deleting them: with
+    /// [`ua::MonitoringMode::DISABLED`], the server neither samples the node nor sends any
+    /// notifications. With [`ua::MonitoringMode::SAMPLING`], the node is sampled but notifications
+    /// are not sent. [`ua::MonitoringMode::REPORTING`] (the initial mode of all monitored items)
+    /// resumes notifications.
+    ///
+    /// The monitored items must belong to the given subscription. Each of them has its own result,
+    /// in the same order as `monitored_item_ids`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, MonitoredItem, Result, Subscription, ua};
+    /// # fn example(
+    /// #     client: &Client,
+    /// #     subscription: &Subscription,
+    /// #     monitored_item: &MonitoredItem,
+    /// # ) -> Result<()> {
+    /// client.set_monitoring_mode(
+    ///     subscription.subscription_id(),
+    ///     &[monitored_item.monitored_item_id()],
+    ///     &ua::MonitoringMode::DISABLED,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails, e.g. when the subscription does not exist.
+    /// When a monitored item does not exist, an inner `Err` is returned.
+    pub fn set_monitoring_mode(
+        &self,
+        subscription_id: ua::SubscriptionId,
+        monitored_item_ids: &[ua::MonitoredItemId],
+        monitoring_mode: &ua::MonitoringMode,
+    ) -> Result<Vec<Result<()>>> {
+        let request = ua::SetMonitoringModeRequest::init()
+            .with_subscription_id(subscription_id)
+            .with_monitored_item_ids(monitored_item_ids)
+            .with_monitoring_mode(monitoring_mode);
+
+        let response = service_request(&self.0, &request)?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("set monitoring mode should return results"));
+        };
+
+        // The OPC UA specification state that the resulting list has the same number of elements as
+        // the request list. If not, we would not be able to match elements in the two lists anyway.
+        if results.len() != monitored_item_ids.len() {
+            return Err(Error::internal("unexpected number of results"));
+        }
+
+        Ok(results.iter().map(Error::verify_good).collect())
+    }
+
+    /// Connects to endpoint.
+    ///
+    /// This method is always called internally before passing new [`Client`] instances to the user:
+    /// our contract states that a `Client` should always be connected.
+    fn connect(&mut self, endpoint_url: &str) -> Result<()> {
+        log::info!("Connecting to endpoint {endpoint_url}");
+
+        let endpoint_url =
+            CString::new(endpoint_url).expect("endpoint URL does not contain NUL bytes");
+
+        // PANIC: We only connect freshly built (or disconnected) clients, before they can be shared
+        // with any driver.
+        let client = Arc::get_mut(&mut self.0).expect("client should not be shared yet");
+        client.resume_reconnect();
+
+        let status_code = ua::StatusCode::new(unsafe {
+            // SAFETY: The method does not take ownership of `client`.
+            UA_Client_connect(client.as_mut_ptr(), endpoint_url.as_ptr())
+        });
+        if status_code == ua::StatusCode::BADCERTIFICATEURIINVALID {
+            // This is a common source of confusion. The status code alone does not tell which URI
+            // is the culprit.
+            log::error!(
+                "Server rejected certificate: application URI of client description must match the \
+                 URI in the client certificate, see `ClientBuilder::application_uri()`"
+            );
+        }
+        Error::verify_good(&status_code)
+    }
+
+    /// Disconnects from endpoint.
+    ///
+    /// This consumes the client and handles the graceful shutdown of the connection. This should be
+    /// preferred over simply dropping the instance to give the server a chance to clean up and also
+    /// to avoid blocking unexpectedly when the client is being dropped without calling this method.
+    ///
+    /// Errors while disconnecting are logged but not returned: there is nothing the caller could
+    /// do about them, and the client is unusable afterwards in any case.
+    // Forward any result as-is to detect mismatching method signatures at compile time if the
+    // return type of the inner method should ever change.
+    #[allow(clippy::semicolon_if_nothing_returned)]
+    pub fn disconnect(self) {
+        self.0.disconnect()
+    }
+
+    /// Disconnects from endpoint, keeping the client for connecting again.
+    ///
+    /// This is like [`disconnect()`](Self::disconnect) but returns the disconnected client. It
+    /// keeps the configuration from [`ClientBuilder`] and can be connected again with
+    /// [`DisconnectedClient::connect()`]. No services can be run in the meantime.
+    ///
+    /// Subscriptions and monitored items do not survive: they are deleted along with the session,
+    /// and their handles become inert (as if the client had been dropped).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use open62541::{Client, Result};
+    /// # fn example(client: Client) -> Result<()> {
+    /// let client = client.into_disconnected().expect("client should not be driven");
+    /// // The client does not communicate with the server in the meantime.
+    /// let client = client.connect("opc.tcp://localhost:4840")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails while a [`ClientDriver`] is still alive: it would keep running the client's event
+    /// loop. The client is returned unchanged (and still connected) in this case. Drop the driver
+    /// first.
+    pub fn into_disconnected(self) -> std::result::Result<DisconnectedClient, Self> {
+        let Self(client, options) = self;
+
+        // Handles of subscriptions and monitored items only hold weak references. They can no
+        // longer reach the client once we have taken it out of the `Arc`, so they cannot reconnect
+        // it implicitly by running services on it.
+        let client = match Arc::try_unwrap(client) {
+            Ok(client) => client,
+            Err(client) => return Err(Self(client, options)),
+        };
+
+        client.disconnect();
+        Ok(DisconnectedClient(client, options))
+    }
+}
+
+/// Disconnected OPC UA client.
+///
+/// This is returned by [`Client::into_disconnected()`]. It keeps the configuration of the client
+/// but offers no services: use [`connect()`](Self::connect) to get a connected [`Client`] again.
+///
+/// Server state that is bound to the previous session does not survive: subscriptions and their
+/// monitored items are gone, and continuation points are invalid.
+#[derive(Debug)]
+pub struct DisconnectedClient(ua::Client, ClientOptions);
+
+impl DisconnectedClient {
+    /// Connects to endpoint again.
+    ///
+    /// The endpoint may differ from the one that the client has been connected to before.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the target server is not reachable, or when it rejects the user identity
+    /// token.
+    ///
+    /// # Panics
+    ///
+    /// The endpoint URL must not contain any NUL bytes.
+    pub fn connect(self, endpoint_url: &str) -> Result<Client> {
+        let mut client = Client(Arc::new(self.0), self.1);
+        client.connect(endpoint_url)?;
+        Ok(client)
+    }
+}
+
+/// Handle to driver of the event loop of [`Client`].
+///
+/// This is returned by [`Client::spawn_driver()`] and [`Client::spawn_driver_on()`]. When the
+/// handle is dropped, the event loop is stopped. If it runs in a thread of its own, the thread is
+/// joined.
+#[derive(Debug)]
+pub struct ClientDriver {
+    canceled: Arc<AtomicBool>,
+    handle: Option<DriverHandle>,
+}
+
+#[derive(Debug)]
+enum DriverHandle {
+    Thread(JoinHandle<()>),
+    #[cfg(feature = "tokio")]
+    Task(tokio::task::JoinHandle<()>),
+}
+
+impl ClientDriver {
+    /// Timeout for each iteration of the event loop.
+    ///
+    /// This is the maximum amount of time that we block in `drop()` until the thread notices that
+    /// it has been canceled.
+    const RUN_ITERATE_TIMEOUT: Duration = Duration::from_millis(200);
+
+    fn spawn(client: &Arc<ua::Client>) -> Self {
+        let canceled = Arc::new(AtomicBool::new(false));
+
+        // The thread holds its own reference to the client. This keeps `UA_Client_delete()` from
+        // running while `UA_Client_run_iterate()` is still active: if the thread is the last owner,
+        // the client is deleted by the thread itself after leaving the loop.
+        let handle = {
+            let client = Arc::clone(client);
+            let canceled = Arc::clone(&canceled);
+            thread::spawn(move || Self::drive(&client, &canceled))
+        };
+
+        Self {
+            canceled,
+            handle: Some(DriverHandle::Thread(handle)),
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    fn spawn_on(client: &Arc<ua::Client>, runtime: &tokio::runtime::Handle) -> Self {
+        let canceled = Arc::new(AtomicBool::new(false));
+
+        // `UA_Client_run_iterate()` blocks, so it must not run on one of the runtime's worker
+        // threads. The task holds its own reference to the client, see `spawn()` above.
+        let handle = {
+            let client = Arc::clone(client);
+            let canceled = Arc::clone(&canceled);
+            runtime.spawn_blocking(move || Self::drive(&client, &canceled))
+        };
+
+        Self {
+            canceled,
+            handle: Some(DriverHandle::Task(handle)),
+        }
+    }
+
+    /// Runs event loop until canceled.
+    fn drive(client: &ua::Client, canceled: &AtomicBool) {
+        log::info!("Starting client driver");
+
+        while !canceled.load(Ordering::Relaxed) {
+            if let Err(error) = run_iterate(client, Self::RUN_ITERATE_TIMEOUT) {
+                // Context-sensitive handling of bad status codes.
+                match error.status_code().into_raw() {
+                    UA_STATUSCODE_BADDISCONNECT | UA_STATUSCODE_BADCONNECTIONCLOSED => {
+                        // Not an error.
+                        log::info!("Terminating client driver after disconnect");
+                    }
+                    _ => {
+                        // Unexpected error.
+                        log::error!("Terminating client driver: run failed with {error}");
+                    }
+                }
+                return;
+            }
+        }
+
+        log::info!("Terminating canceled client driver");
+    }
+}
+
+impl Drop for ClientDriver {
+    fn drop(&mut self) {
+        let Some(handle) = self.handle.take() else {
+            return;
+        };
+
+        self.canceled.store(true, Ordering::Relaxed);
+
+        match handle {
+            DriverHandle::Thread(handle) => {
+                // This call blocks. We ignore the result because we do not care if the thread
+                // panicked (and there is nothing that we could do anyway in that case).
+                let _unused = handle.join();
+            }
+            #[cfg(feature = "tokio")]
+            DriverHandle::Task(handle) => {
+                // We must not block here: we may be called from within the runtime. The task stops
+                // by itself after its current iteration. Blocking tasks cannot be aborted.
+                drop(handle);
+            }
+        }
+    }
+}
+
+/// Converts duration to milliseconds as expected by open62541.
+///
+/// This saturates at `u32::MAX` instead of overflowing.
+fn duration_millis(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+/// Checks if history read returns values backwards in time.
+///
+/// This is the case when both bounds are given and `end` is before `start`, or when only `end` is
+/// given. Unspecified bounds are [`ua::DateTime::init()`].
+fn history_reads_backwards(start: &ua::DateTime, end: &ua::DateTime) -> bool {
+    let unspecified = ua::DateTime::init();
+    match (start == &unspecified, end == &unspecified) {
+        (false, false) => end.to_unix_nanos() < start.to_unix_nanos(),
+        (true, false) => true,
+        (_, true) => false,
+    }
+}
+
+/// Runs single iteration of event loop.
+fn run_iterate(client: &ua::Client, timeout: Duration) -> Result<()> {
+    // `UA_Client_run_iterate()` expects the timeout to be given in milliseconds.
+    let status_code = client.run_iterate(duration_millis(timeout));
+    Error::verify_good(&status_code)
+}
+
+/// Runs service request synchronously.
+///
+/// This blocks until the response has been received. Only the service result in the response header
+/// is verified: operation-level status codes must be checked by the caller.
+fn service_request<R: ServiceRequest>(client: &ua::Client, request: &R) -> Result<R::Response> {
+    log::debug!("Running {}", R::type_name());
+
+    let mut response = R::Response::init();
+    unsafe {
+        __UA_Client_Service(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            request.as_ptr().cast::<c_void>(),
+            R::data_type(),
+            response.as_mut_ptr().cast::<c_void>(),
+            R::Response::data_type(),
+        );
+    }
+
+    // Unlike its asynchronous counterpart, `__UA_Client_Service()` returns no status code. Failures
+    // to send the request (e.g. when not connected) are reported through the service result, too.
+    let status_code = response.service_result();
+    if !status_code.is_good() {
+        log::warn!("{} failed: {status_code:?}", R::type_name());
+        // SAFETY: All service responses start with `UA_ResponseHeader`, like requests do.
+        let response_header = unsafe { &*response.as_ptr().cast::<UA_ResponseHeader>() };
+        let response_header = ua::ResponseHeader::clone_raw(response_header);
+        return Err(Error::service_fault(response_header));
+    }
+
+    Ok(response)
+}
+
+/// Runs service request synchronously, with custom timeout.
+///
+/// This overrides the default timeout from the client config for this request only.
+fn service_request_with_timeout<R: ServiceRequest>(
+    client: &ua::Client,
+    request: &R,
+    timeout: Duration,
+) -> Result<R::Response> {
+    let mut request = request.clone();
+    // SAFETY: All service requests start with `UA_RequestHeader`. `open62541` relies on this, too.
+    let request_header = unsafe { &mut *request.as_mut_ptr().cast::<UA_RequestHeader>() };
+    // `__UA_Client_Service()` waits for the response as long as the timeout hint tells. It fills in
+    // the default timeout only when the hint is `0`, so we must not round down to that.
+    request_header.timeoutHint = duration_millis(timeout).max(1);
+
+    service_request(client, &request)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::TcpListener,
+        slice,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            mpsc, Arc,
+        },
+        thread,
+        time::{Duration, Instant, SystemTime},
+    };
+
+    use open62541_sys::{
+        UA_Client_getConfig, UA_NS0ID_BASEEVENTTYPE, UA_NS0ID_BASEOBJECTTYPE, UA_NS0ID_DOUBLE,
+        UA_NS0ID_HASPROPERTY, UA_NS0ID_INT32, UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_PROPERTYTYPE,
+        UA_NS0ID_SERVER, UA_NS0ID_SERVERSTATE, UA_NS0ID_SERVERSTATUSDATATYPE, UA_NS0ID_SERVERTYPE,
+        UA_NS0ID_SERVER_SERVERSTATUS, UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME,
+        UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME, UA_NS0ID_SERVER_SERVERSTATUS_STATE,
+        UA_NS0ID_STRING, UA_NS0ID_UTCTIME,
+    };
+
+    use crate::{
+        test_server::TestServer, ua, Client, ClientBuilder, DataSource, DataSourceFn,
+        DataSourceReadContext, DataSourceResult, DataSourceWriteContext, DataType as _, Error,
+        MethodCallbackFn, MethodNode, ObjectNode, ServerBuilder, VariableNode,
+    };
+
+    use super::{history_reads_backwards, service_request};
+
+    #[test]
+    fn user_identity_token() {
+        // By default, no user identity token is set and the client connects anonymously.
+        let mut builder = ClientBuilder::default();
+        let token = ua::ExtensionObject::raw_ref(&builder.config_mut().userIdentityToken);
+        assert_eq!(token, &ua::ExtensionObject::init());
+
+        let mut builder = builder.username_password("lorem", "ipsum");
+        let token = ua::ExtensionObject::raw_ref(&builder.config_mut().userIdentityToken);
+        assert_eq!(
+            token.decode::<ua::UserNameIdentityToken>(),
+            Some(ua::UserNameIdentityToken::new("lorem", "ipsum"))
+        );
+    }
+
+    #[test]
+    fn saturate_durations() {
+        let mut builder = ClientBuilder::default()
+            .timeout(Duration::from_millis(1500))
+            .requested_session_timeout(Duration::MAX)
+            .connectivity_check_interval(None);
+
+        let config = builder.config_mut();
+        assert_eq!(config.timeout, 1500);
+        assert_eq!(config.requestedSessionTimeout, u32::MAX);
+        assert_eq!(config.connectivityCheckInterval, 0);
+    }
+
+    #[test]
+    fn keep_alive_config() {
+        let mut builder = ClientBuilder::default()
+            .secure_channel_life_time(Duration::from_secs(60))
+            .connectivity_check_interval(Some(Duration::from_secs(5)));
+
+        let config = builder.config_mut();
+        assert_eq!(config.secureChannelLifeTime, 60_000);
+        assert_eq!(config.connectivityCheckInterval, 5000);
+    }
+
+    #[test]
+    fn custom_logger() {
+        let server = TestServer::start();
+
+        let (tx, rx) = mpsc::channel();
+        let _client = ClientBuilder::default()
+            .logger(move |_level, category, message| {
+                let _unused = tx.send((category, message.to_owned()));
+            })
+            .connect(&server.endpoint_url())
+            .expect("client should connect");
+
+        // Connecting logs at least one message.
+        let messages: Vec<_> = rx.try_iter().collect();
+        assert!(!messages.is_empty());
+        assert!(messages
+            .iter()
+            .any(|(category, _)| *category == ua::LogCategory::CLIENT));
+    }
+
+    #[test]
+    fn auto_reconnect() {
+        let mut builder = ClientBuilder::default();
+        assert!(!builder.config_mut().noReconnect);
+        assert!(!builder.config_mut().noNewSession);
+
+        let mut builder = builder.auto_reconnect(false);
+        assert!(builder.config_mut().noReconnect);
+        assert!(builder.config_mut().noNewSession);
+    }
+
+    #[test]
+    fn reconnect_backoff() {
+        let server = TestServer::start();
+        let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+
+        let (tx, rx) = mpsc::channel();
+        let client = ClientBuilder::default()
+            .reconnect_backoff(Duration::from_millis(100), Duration::from_secs(1))
+            .on_state_change(move |channel_state, session_state, _| {
+                let _unused = tx.send((channel_state, session_state));
+            })
+            .connect(&server.endpoint_url())
+            .expect("client should connect");
+
+        // Our backoff replaces the automatic reconnect of `open62541`.
+        let config = unsafe { &*UA_Client_getConfig(client.as_raw()) };
+        assert!(config.noReconnect);
+        assert!(!config.noNewSession);
+
+        let (value_tx, value_rx) = mpsc::channel();
+        let subscription = client
+            .create_subscription(
+                ua::SubscriptionParameters::default()
+                    .publishing_interval(Duration::from_millis(50)),
+            )
+            .expect("should create subscription");
+        let monitored_item = subscription
+            .create_monitored_item(&node_id, Duration::from_millis(50), move |value| {
+                let _unused = value_tx.send(value);
+            })
+            .expect("should create monitored item");
+
+        let driver = client.spawn_driver();
+        value_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("should receive value");
+        rx.try_iter().for_each(drop);
+
+        // The driver keeps running while the server is down and reconnects by itself, without any
+        // service requests that would reconnect implicitly.
+        let _server = server.restart();
+        let mut lost = false;
+        loop {
+            let (channel_state, session_state) = rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("client should reconnect");
+            if channel_state == ua::SecureChannelState::CLOSED {
+                lost = true;
+            } else if lost && session_state == ua::SessionState::ACTIVATED {
+                break;
+            }
+        }
+
+        client.read_value(&node_id).expect("should read value");
+
+        // The new session has been given the same subscription and monitored item, with the same
+        // callback. Skip values that have been received before the server went down.
+        let restarted = SystemTime::now();
+        loop {
+            let value = value_rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("monitored item should keep delivering values");
+            let value = value
+                .value()
+                .and_then(|value| value.to_scalar::<ua::DateTime>())
+                .and_then(|value| value.to_system_time())
+                .expect("value should be date time");
+            if value >= restarted {
+                break;
+            }
+        }
+
+        // The handle refers to the re-created monitored item.
+        monitored_item
+            .set_sampling_interval(Duration::from_millis(100))
+            .expect("should modify re-created monitored item");
+        drop(monitored_item);
+        drop(subscription);
+
+        drop(driver);
+        client.disconnect();
+    }
+
+    #[test]
+    fn reconnect() {
+        let server = TestServer::start();
+        let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+        let client = server.connect();
+        client.read_value(&node_id).expect("should read value");
+
+        let client = client
+            .into_disconnected()
+            .expect("should disconnect")
+            .connect(&server.endpoint_url())
+            .expect("should connect again");
+        let state = client.state();
+        assert_eq!(state.channel_state, ua::SecureChannelState::OPEN);
+        assert_eq!(state.session_state, ua::SessionState::ACTIVATED);
+        client.read_value(&node_id).expect("should read value");
+
+        // Client stays connected while driver is alive.
+        let driver = client.spawn_driver();
+        let client = client
+            .into_disconnected()
+            .expect_err("should not disconnect while driven");
+        client.read_value(&node_id).expect("should read value");
+        drop(driver);
+
+        // Subscriptions do not prevent disconnecting but do not survive.
+        let subscription = client
+            .create_data_change_subscription(&node_id, Duration::from_millis(100), |_| {})
+            .expect("should create subscription");
+        let client = client
+            .into_disconnected()
+            .expect("should disconnect")
+            .connect(&server.endpoint_url())
+            .expect("should connect again");
+        drop(subscription);
+        let _subscription = client
+            .create_data_change_subscription(&node_id, Duration::from_millis(100), |_| {})
+            .expect("should create subscription");
+    }
+
+    #[test]
+    fn on_state_change() {
+        let server = TestServer::start();
+        let (tx, rx) = mpsc::channel();
+        let client = ClientBuilder::default()
+            .on_state_change(move |channel_state, session_state, connect_status| {
+                let _unused = tx.send((channel_state, session_state, connect_status));
+            })
+            .connect(&server.endpoint_url())
+            .expect("client should connect");
+
+        let states: Vec<_> = rx.try_iter().collect();
+        assert!(states.iter().all(|(_, _, status)| status.is_good()));
+        assert_eq!(
+            states
+                .last()
+                .map(|(channel, session, _)| (channel, session)),
+            Some((&ua::SecureChannelState::OPEN, &ua::SessionState::ACTIVATED))
+        );
+        // The session is established only after the secure channel has been opened.
+        assert!(states.iter().any(|(channel, session, _)| {
+            channel == &ua::SecureChannelState::OPEN && session != &ua::SessionState::ACTIVATED
+        }));
+
+        client.disconnect();
+
+        let states: Vec<_> = rx.try_iter().collect();
+        assert_eq!(
+            states
+                .last()
+                .map(|(channel, session, _)| (channel, session)),
+            Some((&ua::SecureChannelState::CLOSED, &ua::SessionState::CLOSED))
+        );
+    }
+
+    #[test]
+    fn client_description() {
+        let mut builder = ClientBuilder::default()
+            .application_uri("urn:lorem:ipsum")
+            .product_uri("urn:lorem")
+            .application_name("en-US", "Lorem Ipsum");
+
+        let description =
+            ua::ApplicationDescription::raw_ref(&builder.config_mut().clientDescription);
+        assert_eq!(
+            description.application_uri().as_str(),
+            Some("urn:lorem:ipsum")
+        );
+        assert_eq!(description.product_uri().as_str(), Some("urn:lorem"));
+        assert_eq!(
+            description.application_name().locale().as_str(),
+            Some("en-US")
+        );
+        assert_eq!(
+            description.application_name().text().as_str(),
+            Some("Lorem Ipsum")
+        );
+    }
+
+    #[test]
+    fn get_endpoints() {
+        let server = TestServer::start();
+
+        let endpoints =
+            Client::get_endpoints(&server.endpoint_url()).expect("should get endpoints");
+
+        // Default server configuration offers unencrypted endpoint.
+        assert!(endpoints.iter().any(|endpoint| {
+            endpoint.security_mode() == &ua::MessageSecurityMode::NONE
+                && endpoint.security_policy_uri().as_str()
+                    == Some("http://opcfoundation.org/UA/SecurityPolicy#None")
+        }));
+    }
+
+    #[test]
+    fn find_servers() {
+        let server = TestServer::start();
+
+        let servers = Client::find_servers(&server.endpoint_url()).expect("should find servers");
+
+        // Regular server describes itself.
+        let [server] = servers.as_slice() else {
+            panic!("should find single server");
+        };
+        assert!(server
+            .application_uri()
+            .as_str()
+            .is_some_and(|uri| !uri.is_empty()));
+        assert_eq!(server.application_type(), &ua::ApplicationType::SERVER);
+        assert!(server.discovery_urls().is_some_and(|urls| !urls.is_empty()));
+    }
+
+    #[test]
+    fn custom_server_config() {
+        let server = TestServer::start_with(
+            ServerBuilder::default()
+                .application_name("en-US", "Boiler Controller")
+                .product_name("Boiler")
+                .software_version("1.2.3")
+                .max_sessions(1),
+        );
+        let client = server.connect();
+
+        let servers = Client::find_servers(&server.endpoint_url()).expect("should find servers");
+        let [description] = servers.as_slice() else {
+            panic!("should find single server");
+        };
+        assert_eq!(
+            description.application_name().text().as_str(),
+            Some("Boiler Controller")
+        );
+
+        let product_name = client
+            .read_value(&ua::NodeId::ns0(
+                UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME,
+            ))
+            .expect("should read product name");
+        assert_eq!(
+            product_name
+                .value()
+                .as_scalar::<ua::String>()
+                .and_then(ua::String::as_str),
+            Some("Boiler")
+        );
+
+        // The only session is taken by the first client.
+        let error = ClientBuilder::default()
+            .connect(&server.endpoint_url())
+            .expect_err("should not create second session");
+        assert_eq!(error.status_code(), ua::StatusCode::BADTOOMANYSESSIONS);
+    }
+
+    #[test]
+    fn delete_subscription_on_drop() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let subscription = client
+            .create_subscription(ua::SubscriptionParameters::default())
+            .expect("should create subscription");
+        let subscription_id = subscription.subscription_id();
+        let monitored_item = subscription
+            .create_monitored_item(
+                &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+                Duration::from_millis(100),
+                |_| {},
+            )
+            .expect("should create monitored item");
+        drop(subscription);
+
+        // Subscription is gone on the server.
+        let status_code = delete_subscription(&client, subscription_id);
+        assert_eq!(status_code, ua::StatusCode::BADSUBSCRIPTIONIDINVALID);
+
+        // Monitored item knows that it has been deleted along with the subscription.
+        drop(monitored_item);
+    }
+
+    #[test]
+    fn modify_monitored_item() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let subscription = client
+            .create_subscription(ua::SubscriptionParameters::default())
+            .expect("should create subscription");
+        let monitored_item = subscription
+            .create_monitored_item(
+                &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+                Duration::from_millis(100),
+                |_| {},
+            )
+            .expect("should create monitored item");
+
+        monitored_item
+            .set_sampling_interval(Duration::from_millis(500))
+            .expect("should modify sampling interval");
+        monitored_item
+            .set_queue_size(5)
+            .expect("should modify queue size");
+
+        // Monitored item is deleted when dropped.
+        let monitored_item_id = monitored_item.monitored_item_id();
+        drop(monitored_item);
+        let request = ua::DeleteMonitoredItemsRequest::init()
+            .with_subscription_id(subscription.subscription_id())
+            .with_monitored_item_ids(&[monitored_item_id]);
+        let status_code = service_request(&client.0, &request)
+            .expect("should send request")
+            .results()
+            .and_then(|results| results.iter().next().cloned())
+            .expect("should return result");
+        assert_eq!(status_code, ua::StatusCode::BADMONITOREDITEMIDINVALID);
+    }
+
+    #[test]
+    fn set_monitoring_mode() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let subscription = client
+            .create_subscription(
+                ua::SubscriptionParameters::default()
+                    .publishing_interval(Duration::from_millis(50)),
+            )
+            .expect("should create subscription");
+        let (tx, rx) = mpsc::channel();
+        let monitored_item = subscription
+            .create_monitored_item(
+                &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+                Duration::from_millis(50),
+                move |_| {
+                    let _unused = tx.send(());
+                },
+            )
+            .expect("should create monitored item");
+        let _driver = client.spawn_driver();
+
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("should receive value");
+
+        let set_monitoring_mode = |monitoring_mode: &ua::MonitoringMode| {
+            client
+                .set_monitoring_mode(
+                    subscription.subscription_id(),
+                    &[monitored_item.monitored_item_id()],
+                    monitoring_mode,
+                )
+                .expect("should set monitoring mode")
+        };
+
+        let results = set_monitoring_mode(&ua::MonitoringMode::DISABLED);
+        assert!(matches!(results.as_slice(), [Ok(())]));
+        // Skip notifications that were already on their way.
+        thread::sleep(Duration::from_millis(500));
+        let _unused = rx.try_iter().count();
+        rx.recv_timeout(Duration::from_millis(500))
+            .expect_err("should not receive value while disabled");
+
+        let results = set_monitoring_mode(&ua::MonitoringMode::REPORTING);
+        assert!(matches!(results.as_slice(), [Ok(())]));
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("should receive value again");
+
+        // Unknown monitored items get their own result.
+        let results = client
+            .set_monitoring_mode(
+                subscription.subscription_id(),
+                &[ua::MonitoredItemId::new(123_456)],
+                &ua::MonitoringMode::DISABLED,
+            )
+            .expect("should set monitoring mode");
+        assert!(matches!(
+            results.as_slice(),
+            [Err(error)] if error.status_code() == ua::StatusCode::BADMONITOREDITEMIDINVALID
+        ));
+    }
+
+    #[test]
+    fn deadband_filter() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Temperature"),
+                &ua::Variant::scalar(ua::Double::new(10.0)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let subscription = client
+            .create_subscription(
+                ua::SubscriptionParameters::default()
+                    .publishing_interval(Duration::from_millis(50)),
+            )
+            .expect("should create subscription");
+        let (tx, rx) = mpsc::channel();
+        let _monitored_item = subscription
+            .create_monitored_item_with_filter(
+                &variable_id,
+                Duration::from_millis(20),
+                &ua::DataChangeFilter::deadband(&ua::DeadbandType::ABSOLUTE, 1.0),
+                move |value| {
+                    let value = value
+                        .value()
+                        .and_then(ua::Variant::to_scalar::<ua::Double>)
+                        .map(|value| value.value());
+                    let _unused = tx.send(value);
+                },
+            )
+            .expect("should create monitored item");
+        let _driver = client.spawn_driver();
+
+        let receive = || rx.recv_timeout(Duration::from_millis(500));
+        let write = |value| {
+            server
+                .server()
+                .write_value(&variable_id, &ua::Variant::scalar(ua::Double::new(value)))
+                .expect("should write value");
+        };
+
+        // The initial value is always reported.
+        assert_eq!(receive(), Ok(Some(10.0)));
+
+        // Smaller changes are suppressed by the deadband.
+        write(10.5);
+        receive().expect_err("should not report small change");
+        write(9.5);
+        receive().expect_err("should not report small change");
+
+        // Larger changes are reported.
+        write(12.0);
+        assert_eq!(receive(), Ok(Some(12.0)));
+    }
+
+    #[test]
+    fn detach_subscription() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let subscription = client
+            .create_subscription(ua::SubscriptionParameters::default())
+            .expect("should create subscription");
+        let subscription_id = subscription.detach();
+
+        // Subscription is still known to the server.
+        let status_code = delete_subscription(&client, subscription_id);
+        Error::verify_good(&status_code).expect("should delete subscription");
+    }
+
+    #[test]
+    fn revised_subscription_parameters() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let subscription = client
+            .create_subscription(
+                ua::SubscriptionParameters::default()
+                    .publishing_interval(Duration::from_millis(500))
+                    .max_keep_alive_count(10)
+                    .lifetime_count(30),
+            )
+            .expect("should create subscription");
+        assert_eq!(
+            subscription.revised_publishing_interval(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(subscription.revised_max_keep_alive_count(), 10);
+        assert_eq!(subscription.revised_lifetime_count(), 30);
+
+        // Server clamps values to its limits: the publishing interval cannot be arbitrarily small,
+        // and the lifetime must cover at least three keep-alive intervals.
+        let subscription = client
+            .create_subscription(
+                ua::SubscriptionParameters::default()
+                    .publishing_interval(Duration::from_micros(1))
+                    .max_keep_alive_count(10)
+                    .lifetime_count(1),
+            )
+            .expect("should create subscription");
+        assert!(subscription.revised_publishing_interval() > Duration::from_micros(1));
+        assert!(subscription.revised_lifetime_count() >= 30);
+    }
+
+    #[test]
+    fn service_fault() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        // Server rejects request as a whole when there is nothing to read.
+        let error = client.read_values(&[]).expect_err("should reject request");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNOTHINGTODO);
+        let Error::ServiceFault(response_header) = &error else {
+            panic!("should return service fault");
+        };
+        assert_eq!(
+            response_header.service_result(),
+            &ua::StatusCode::BADNOTHINGTODO
+        );
+        // The server echoes the handle that the client has assigned to the request.
+        assert_ne!(response_header.request_handle(), 0);
+        assert_eq!(error.response_header(), Some(&**response_header));
+
+        // Errors of individual operations are no service faults.
+        let error = client
+            .read_value(&ua::NodeId::numeric(1, 12345))
+            .expect_err("should not read value");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODEIDUNKNOWN);
+        assert!(error.response_header().is_none());
+    }
+
+    #[test]
+    fn browse_with_node_class_mask() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let browse_description =
+            ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0(UA_NS0ID_SERVER));
+        let (references, _) = client.browse(&browse_description).expect("should browse");
+        // The `Server` object has both object and variable children.
+        assert!(references
+            .iter()
+            .any(|reference| reference.node_class() == &ua::NodeClass::VARIABLE));
+        assert!(references
+            .iter()
+            .any(|reference| reference.node_class() == &ua::NodeClass::OBJECT));
+
+        let (references, _) = client
+            .browse(&browse_description.with_node_class_mask(&ua::NodeClassMask::OBJECT))
+            .expect("should browse");
+        assert!(!references.is_empty());
+        assert!(references
+            .iter()
+            .all(|reference| reference.node_class() == &ua::NodeClass::OBJECT));
+    }
+
+    #[test]
+    fn browse_objects_folder() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let browse_description =
+            ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER));
+        let (references, _) = client.browse(&browse_description).expect("should browse");
+
+        // The `Server` object is always found in the objects folder.
+        let reference = references
+            .iter()
+            .find(|reference| reference.node_class() == &ua::NodeClass::OBJECT)
+            .expect("should have object reference");
+        assert!(reference.is_forward());
+        assert_eq!(
+            reference.node_id().node_id(),
+            &ua::NodeId::ns0(UA_NS0ID_SERVER)
+        );
+        assert_eq!(
+            reference.browse_name(),
+            &ua::QualifiedName::new(0, "Server")
+        );
+        assert_eq!(
+            reference.type_definition().node_id(),
+            &ua::NodeId::ns0(UA_NS0ID_SERVERTYPE)
+        );
+    }
+
+    #[test]
+    fn iterate_browse_result() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let request = ua::BrowseRequest::init()
+            .with_nodes_to_browse(&[ua::BrowseDescription::default()
+                .with_node_id(&ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER))]);
+        let response = service_request(&client.0, &request).expect("should browse");
+        let results = response.results().expect("should have results");
+        let result = results.as_slice().first().expect("should have result");
+
+        assert_eq!(result.status_code(), ua::StatusCode::GOOD);
+        assert!(result.continuation_point().is_none());
+        assert_eq!(
+            result.into_iter().count(),
+            result.references().map_or(0, <[_]>::len)
+        );
+        assert!(result
+            .into_iter()
+            .any(|reference| reference.browse_name() == &ua::QualifiedName::new(0, "Server")));
+    }
+
+    #[test]
+    fn browse_next() {
+        let server = TestServer::start();
+        let folder_id = server
+            .server()
+            .add_object_node(ObjectNode::folder(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Plant"),
+            ))
+            .expect("should add folder");
+        let browse_names = ["Pressure", "Temperature", "Level"].map(|name| {
+            let browse_name = ua::QualifiedName::new(1, name);
+            server
+                .server()
+                .add_variable_node(VariableNode::new(
+                    folder_id.clone(),
+                    browse_name.clone(),
+                    &ua::Variant::scalar(ua::Double::new(0.0)),
+                ))
+                .expect("should add variable");
+            browse_name
+        });
+        let client = server.connect();
+
+        // Ask for one reference at a time, so that all other references have to be fetched with
+        // continuation points.
+        let request = ua::BrowseRequest::init()
+            .with_nodes_to_browse(&[ua::BrowseDescription::default().with_node_id(&folder_id)])
+            .with_requested_max_references_per_node(1);
+        let response = service_request(&client.0, &request).expect("should browse");
+        let result = response
+            .results()
+            .and_then(|results| results.as_slice().first().cloned())
+            .expect("should have result");
+        let mut references = result
+            .references()
+            .expect("should have references")
+            .to_vec();
+        let mut continuation_point = result.continuation_point();
+        assert_eq!(references.len(), 1);
+        assert!(continuation_point.is_some());
+
+        let mut round_trips = 0;
+        while let Some(next) = continuation_point.take() {
+            let mut results = client.browse_next(&[next]).expect("should browse next");
+            assert_eq!(results.len(), 1);
+            let (more_references, next) = results
+                .pop()
+                .expect("should have result")
+                .expect("should follow continuation point");
+            assert_eq!(more_references.len(), 1);
+            references.extend(more_references);
+            continuation_point = next;
+            round_trips += 1;
+        }
+        assert_eq!(round_trips, browse_names.len() - 1);
+
+        let mut found: Vec<_> = references
+            .iter()
+            .map(|reference| reference.browse_name().clone())
+            .collect();
+        let mut expected = browse_names.to_vec();
+        found.sort_by_key(ToString::to_string);
+        expected.sort_by_key(ToString::to_string);
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn browse_recursive() {
+        let server = TestServer::start();
+        let folder_id = server
+            .server()
+            .add_object_node(ObjectNode::folder(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Plant"),
+            ))
+            .expect("should add folder");
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                folder_id.clone(),
+                ua::QualifiedName::new(1, "Temperature"),
+                &ua::Variant::scalar(ua::Double::new(21.5)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let browse_description =
+            ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER));
+        let find_variable = |results: &[(ua::NodeId, ua::ReferenceDescription)]| {
+            results
+                .iter()
+                .find(|(_, reference)| reference.node_id().node_id() == &variable_id)
+                .map(|(parent_id, _)| parent_id.clone())
+        };
+
+        // Variable is found on second level, in the folder.
+        let results = client
+            .browse_recursive(&browse_description, 2)
+            .expect("should browse recursively");
+        assert_eq!(find_variable(&results), Some(folder_id));
+
+        // Variable is not found when only browsing first level.
+        let results = client
+            .browse_recursive(&browse_description, 1)
+            .expect("should browse recursively");
+        assert_eq!(find_variable(&results), None);
+        assert!(results
+            .iter()
+            .all(|(parent_id, _)| parent_id == &ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER)));
+    }
+
+    #[test]
+    fn translate_browse_path() {
+        let server = TestServer::start();
+        let folder_id = server
+            .server()
+            .add_object_node(ObjectNode::folder(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Plant"),
+            ))
+            .expect("should add folder");
+        let object_id = server
+            .server()
+            .add_object_node(ObjectNode::new(
+                folder_id,
+                ua::QualifiedName::new(1, "Boiler"),
+                ua::NodeId::ns0(UA_NS0ID_BASEOBJECTTYPE),
+            ))
+            .expect("should add object");
+        let client = server.connect();
+
+        // Both segments are followed from the objects folder.
+        let node_ids = client
+            .translate_browse_path(
+                &ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                &[
+                    ua::QualifiedName::new(1, "Plant"),
+                    ua::QualifiedName::new(1, "Boiler"),
+                ],
+            )
+            .expect("should translate browse path");
+        assert_eq!(node_ids, [object_id]);
+
+        // Unknown segment does not lead to any node.
+        let error = client
+            .translate_browse_path(
+                &ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                &[
+                    ua::QualifiedName::new(1, "Plant"),
+                    ua::QualifiedName::new(1, "Turbine"),
+                ],
+            )
+            .expect_err("should not translate unknown browse path");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNOMATCH);
+    }
+
+    #[test]
+    fn method_arguments() {
+        let server = TestServer::start();
+        let argument = |name, data_type| {
+            ua::Argument::new(
+                name,
+                &ua::NodeId::ns0(data_type),
+                -1,
+                &ua::LocalizedText::new("", name).unwrap(),
+            )
+            .unwrap()
+        };
+        let (method_id, _) = server
+            .server()
+            .add_method_node(
+                MethodNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, "Scale"),
+                    &[
+                        argument("value", UA_NS0ID_DOUBLE),
+                        argument("factor", UA_NS0ID_INT32),
+                    ],
+                    &[argument("result", UA_NS0ID_DOUBLE)],
+                ),
+                MethodCallbackFn::new(|_| Ok(Vec::new())),
+            )
+            .expect("should add method");
+        let (reset_id, _) = server
+            .server()
+            .add_method_node(
+                MethodNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, "Reset"),
+                    &[],
+                    &[],
+                ),
+                MethodCallbackFn::new(|_| Ok(Vec::new())),
+            )
+            .expect("should add method");
+        let client = server.connect();
+
+        let (input_arguments, output_arguments) = client
+            .method_arguments(&method_id)
+            .expect("should read arguments");
+        let names = |arguments: &[ua::Argument]| {
+            arguments
+                .iter()
+                .map(|argument| argument.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&input_arguments), ["value", "factor"]);
+        assert_eq!(
+            input_arguments[1].data_type(),
+            &ua::NodeId::ns0(UA_NS0ID_INT32)
+        );
+        assert_eq!(names(&output_arguments), ["result"]);
+        assert_eq!(
+            output_arguments[0].data_type(),
+            &ua::NodeId::ns0(UA_NS0ID_DOUBLE)
+        );
+
+        // Methods without arguments have no argument properties.
+        let (input_arguments, output_arguments) = client
+            .method_arguments(&reset_id)
+            .expect("should read arguments");
+        assert!(input_arguments.is_empty());
+        assert!(output_arguments.is_empty());
+    }
+
+    #[test]
+    fn call_method() {
+        let server = TestServer::start();
+        let argument = |name| {
+            ua::Argument::new(
+                name,
+                &ua::NodeId::ns0(UA_NS0ID_STRING),
+                -1,
+                &ua::LocalizedText::new("", name).unwrap(),
+            )
+            .unwrap()
+        };
+        let object_id = ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER);
+        let (method_id, _) = server
+            .server()
+            .add_method_node(
+                MethodNode::new(
+                    object_id.clone(),
+                    ua::QualifiedName::new(1, "Echo"),
+                    &[argument("input")],
+                    &[argument("output")],
+                ),
+                MethodCallbackFn::new(|input_arguments| Ok(input_arguments.to_vec())),
+            )
+            .expect("should add method");
+        let client = server.connect();
+
+        let input = ua::Variant::scalar(ua::String::new("Lorem ipsum").unwrap());
+        let output_arguments = client
+            .call_method(&object_id, &method_id, slice::from_ref(&input))
+            .expect("should call method");
+        assert_eq!(output_arguments, [input]);
+
+        // Server checks input arguments before calling the method.
+        client
+            .call_method(&object_id, &method_id, &[])
+            .expect_err("should reject missing argument");
+    }
+
+    #[test]
+    fn outstanding_publish_requests() {
+        let server = TestServer::start();
+
+        let count_notifications = |outstanding_publish_requests| {
+            let client = ClientBuilder::default()
+                .outstanding_publish_requests(outstanding_publish_requests)
+                .connect(&server.endpoint_url())
+                .expect("client should connect");
+
+            // SAFETY: We only read from config, the client keeps it alive.
+            let config = unsafe { &*UA_Client_getConfig(client.as_raw()) };
+            assert_eq!(
+                config.outStandingPublishRequests,
+                outstanding_publish_requests
+            );
+
+            // Each publish response holds the notifications of a single subscription. With many
+            // busy subscriptions, the server has more notifications ready than can be delivered
+            // with a single outstanding request.
+            let count = Arc::new(AtomicU32::new(0));
+            let _subscriptions: Vec<_> = (0..20)
+                .map(|_| {
+                    let subscription = client
+                        .create_subscription(
+                            ua::SubscriptionParameters::default()
+                                .publishing_interval(Duration::from_millis(100)),
+                        )
+                        .expect("should create subscription");
+                    let count = Arc::clone(&count);
+                    let monitored_item = subscription
+                        .create_monitored_item(
+                            &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+                            Duration::from_millis(50),
+                            move |_| {
+                                count.fetch_add(1, Ordering::Relaxed);
+                            },
+                        )
+                        .expect("should create monitored item");
+                    (subscription, monitored_item)
+                })
+                .collect();
+            count.store(0, Ordering::Relaxed);
+
+            // Run the event loop at a fixed rate, like a client with high latency would: responses
+            // that arrive in between are only processed in the next iteration. Only then does the
+            // client send new publish requests to refill those that have been answered.
+            let started = Instant::now();
+            while started.elapsed() < Duration::from_secs(1) {
+                client
+                    .run_iterate(Duration::ZERO)
+                    .expect("should run iterate");
+                thread::sleep(Duration::from_millis(20));
+            }
+
+            count.load(Ordering::Relaxed)
+        };
+
+        // A single outstanding request delivers at most one publish response per iteration, while
+        // more requests let the server deliver the notifications of several subscriptions at once.
+        let single = count_notifications(1);
+        let multiple = count_notifications(10);
+        assert!(single > 0, "should receive notifications");
+        assert!(
+            multiple > 2 * single,
+            "more publish requests should deliver more notifications ({multiple} vs. {single})"
+        );
+    }
+
+    #[test]
+    fn read_value() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        // The server status is a structure, the variant holds the decoded value.
+        let value = client
+            .read_value(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS))
+            .expect("should read server status");
+        assert_eq!(
+            value.value().type_id(),
+            Some(&ua::NodeId::ns0(UA_NS0ID_SERVERSTATUSDATATYPE))
+        );
+        assert!(value.server_timestamp().is_some());
+
+        // Enumeration values keep their specific data type.
+        let value = client
+            .read_value(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_STATE))
+            .expect("should read server state");
+        assert_eq!(
+            value.value().type_id(),
+            Some(&ua::NodeId::ns0(UA_NS0ID_SERVERSTATE))
+        );
+
+        let value = client
+            .read_value(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME))
+            .expect("should read current time");
+        assert!(value.value().as_scalar::<ua::DateTime>().is_some());
+    }
+
+    #[test]
+    fn read_values() {
+        let server = TestServer::start();
+        let variable_ids: Vec<_> = (0..100)
+            .map(|index| {
+                server
+                    .server()
+                    .add_variable_node(VariableNode::new(
+                        ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                        ua::QualifiedName::new(1, &format!("Value{index}")),
+                        &ua::Variant::scalar(ua::Int32::new(index)),
+                    ))
+                    .expect("should add variable")
+            })
+            .collect();
+        let client = server.connect();
+
+        // Read in reverse order: the results must follow the order of the request, not the order
+        // in which the nodes have been added.
+        let node_ids: Vec<_> = variable_ids.into_iter().rev().collect();
+        let values: Vec<_> = client
+            .read_values(&node_ids)
+            .expect("should read values")
+            .into_iter()
+            .map(|value| {
+                value
+                    .expect("should read value")
+                    .into_value()
+                    .to_scalar::<ua::Int32>()
+            })
+            .collect();
+        assert_eq!(
+            values,
+            (0..100)
+                .rev()
+                .map(ua::Int32::new)
+                .map(Some)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn read_and_write_values_in_chunks() {
+        let server = TestServer::start_with(
+            ServerBuilder::default()
+                .max_nodes_per_read(3)
+                .max_nodes_per_write(3),
+        );
+        let variable_ids: Vec<_> = (0..7)
+            .map(|index| {
+                server
+                    .server()
+                    .add_variable_node(VariableNode::new(
+                        ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                        ua::QualifiedName::new(1, &format!("Value{index}")),
+                        &ua::Variant::scalar(ua::Int32::new(index)),
+                    ))
+                    .expect("should add variable")
+            })
+            .collect();
+
+        // Server rejects request that exceeds its limit.
+        let error = server
+            .connect()
+            .read_values(&variable_ids)
+            .expect_err("should reject request");
+        assert_eq!(error.status_code(), ua::StatusCode::BADTOOMANYOPERATIONS);
+
+        // This requires three requests for reading (and writing) all values.
+        let client = ClientBuilder::default()
+            .max_operations_per_request(3)
+            .connect(&server.endpoint_url())
+            .expect("client should connect");
+
+        let read_values = || -> Vec<_> {
+            client
+                .read_values(&variable_ids)
+                .expect("should read values")
+                .into_iter()
+                .map(|value| {
+                    value
+                        .expect("should read value")
+                        .into_value()
+                        .to_scalar::<ua::Int32>()
+                })
+                .collect()
+        };
+        assert_eq!(
+            read_values(),
+            (0..7).map(ua::Int32::new).map(Some).collect::<Vec<_>>()
+        );
+
+        let node_values: Vec<_> = variable_ids
+            .iter()
+            .zip(10..)
+            .map(|(node_id, value)| (node_id.clone(), ua::Variant::scalar(ua::Int32::new(value))))
+            .collect();
+        let results = client
+            .write_values(&node_values)
+            .expect("should write values");
+        assert_eq!(results.len(), 7);
+        assert!(results.iter().all(Result::is_ok));
+
+        assert_eq!(
+            read_values(),
+            (10..17).map(ua::Int32::new).map(Some).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn read_attribute() {
+        let server = TestServer::start();
+        let client = server.connect();
+        let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER);
+
+        // The attribute determines the type of the value.
+        let display_name: ua::LocalizedText = client
+            .read_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T)
+            .expect("should read display name")
+            .into_value();
+        assert_eq!(display_name.text().as_str(), Some("Server"));
+
+        let browse_name = client
+            .read_attribute(&node_id, ua::AttributeId::BROWSENAME_T)
+            .expect("should read browse name");
+        assert_eq!(browse_name.value(), &ua::QualifiedName::new(0, "Server"));
+
+        // Objects have no value attribute.
+        let error = client
+            .read_attribute(&node_id, ua::AttributeId::VALUE_T)
+            .expect_err("should not read value of object");
+        assert_eq!(error.status_code(), ua::StatusCode::BADATTRIBUTEIDINVALID);
+    }
+
+    #[test]
+    fn read_attributes() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Setpoint"),
+                &ua::Variant::scalar(ua::Int32::new(7)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let values = client
+            .read_attributes(
+                &variable_id,
+                &[
+                    ua::AttributeId::VALUE,
+                    ua::AttributeId::DATATYPE,
+                    ua::AttributeId::ACCESSLEVEL,
+                ],
+            )
+            .expect("should read attributes");
+
+        // Results are returned in order of requested attributes.
+        let values: Vec<_> = values
+            .into_iter()
+            .map(|value| value.expect("should read attribute").into_value())
+            .collect();
+        let [value, data_type, access_level] = values.as_slice() else {
+            panic!("should return three results");
+        };
+        assert_eq!(value.to_scalar::<ua::Int32>(), Some(ua::Int32::new(7)));
+        assert_eq!(
+            data_type.to_scalar::<ua::NodeId>(),
+            Some(ua::NodeId::ns0(UA_NS0ID_INT32))
+        );
+        let access_level = access_level
+            .to_scalar::<ua::Byte>()
+            .map(|access_level| ua::AccessLevel::from_u8(access_level.value()))
+            .expect("should return access level");
+        assert!(access_level.current_read());
+        assert!(access_level.current_write());
+    }
+
+    #[test]
+    fn read_eu_range() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Temperature"),
+                &ua::Variant::scalar(ua::Double::new(21.5)),
+            ))
+            .expect("should add variable");
+        // Attach metadata the way analog items do, as property below the variable.
+        let mut property = VariableNode::new(
+            variable_id.clone(),
+            ua::QualifiedName::new(0, "EURange"),
+            &ua::Variant::scalar(ua::Range::new(-10.0, 40.0)),
+        );
+        property.reference_type_id = ua::NodeId::ns0(UA_NS0ID_HASPROPERTY);
+        property.type_definition = ua::NodeId::ns0(UA_NS0ID_PROPERTYTYPE);
+        let property_id = server
+            .server()
+            .add_variable_node(property)
+            .expect("should add property");
+        let client = server.connect();
+
+        let value = client.read_value(&property_id).expect("should read value");
+        let range = value
+            .value()
+            .to_scalar::<ua::Range>()
+            .expect("should decode range");
+        assert_eq!(range, ua::Range::new(-10.0, 40.0));
+    }
+
+    #[test]
+    fn read_value_range() {
+        let server = TestServer::start();
+        let values: Vec<_> = [10, 20, 30, 40, 50]
+            .into_iter()
+            .map(ua::Int32::new)
+            .collect();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Values"),
+                &ua::Variant::array(ua::Array::from_slice(&values)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let index_range = "1:3".parse().expect("should parse range");
+        let value = client
+            .read_value_range(&variable_id, &index_range)
+            .expect("should read range");
+        let elements = value
+            .value()
+            .to_array::<ua::Int32>()
+            .expect("value should be Int32 array");
+        assert_eq!(elements.as_slice(), &values[1..=3]);
+
+        let index_range = "2".parse().expect("should parse range");
+        let value = client
+            .read_value_range(&variable_id, &index_range)
+            .expect("should read range");
+        let elements = value
+            .value()
+            .to_array::<ua::Int32>()
+            .expect("value should be Int32 array");
+        assert_eq!(elements.as_slice(), &values[2..=2]);
+
+        // Range outside of array is rejected by server.
+        let index_range = "7:9".parse().expect("should parse range");
+        let error = client
+            .read_value_range(&variable_id, &index_range)
+            .expect_err("should fail to read range");
+        assert_eq!(error.status_code(), ua::StatusCode::BADINDEXRANGENODATA);
+
+        // Malformed range is rejected before sending request.
+        let error = "3:1"
+            .parse::<ua::NumericRange>()
+            .expect_err("should fail to parse range");
+        assert_eq!(error.status_code(), ua::StatusCode::BADINDEXRANGEINVALID);
+    }
+
+    #[test]
+    fn read_value_with_timeout() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_data_source_variable_node(
+                VariableNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, "Slow"),
+                    &ua::Variant::scalar(ua::Int32::new(0)),
+                ),
+                DataSourceFn::new(|| {
+                    // Take longer than the per-request timeout but not the default timeout.
+                    thread::sleep(Duration::from_millis(200));
+                    ua::Variant::scalar(ua::Int32::new(42))
+                }),
+            )
+            .expect("should add variable");
+        let client = server.connect();
+
+        let error = client
+            .read_value_with_timeout(&variable_id, Duration::from_millis(50))
+            .expect_err("should time out");
+        assert!(error.is_timeout());
+        assert_eq!(error.status_code(), ua::StatusCode::BADTIMEOUT);
+
+        // Timeout applied to single request only, the default timeout is still in place.
+        let value = client.read_value(&variable_id).expect("should read value");
+        assert_eq!(
+            value.value().to_scalar::<ua::Int32>(),
+            Some(ua::Int32::new(42))
+        );
+    }
+
+    #[test]
+    fn write_value() {
+        /// Data source that keeps the written value and passes on what has been received.
+        struct Setpoint {
+            value: ua::Variant,
+            tx: mpsc::Sender<ua::DataValue>,
+        }
+
+        impl DataSource for Setpoint {
+            fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                context.set_variant(self.value.clone());
+                Ok(())
+            }
+
+            fn write(&mut self, context: &mut DataSourceWriteContext) -> DataSourceResult {
+                let value = context.value();
+                if let Some(variant) = value.value() {
+                    self.value = variant.clone();
+                }
+                let _unused = self.tx.send(value.clone());
+                Ok(())
+            }
+        }
+
+        let server = TestServer::start();
+        let (tx, rx) = mpsc::channel();
+        let variable_id = server
+            .server()
+            .add_data_source_variable_node(
+                VariableNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, "Setpoint"),
+                    &ua::Variant::scalar(ua::Double::new(20.0)),
+                ),
+                Setpoint {
+                    value: ua::Variant::scalar(ua::Double::new(20.0)),
+                    tx,
+                },
+            )
+            .expect("should add variable");
+        let client = server.connect();
+
+        client
+            .write_value(&variable_id, &ua::Variant::scalar(ua::Double::new(22.5)))
+            .expect("should write value");
+
+        // Only the value is sent, the server would reject a server timestamp.
+        let value = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("should receive write");
+        assert_eq!(
+            value.value().and_then(ua::Variant::to_scalar::<ua::Double>),
+            Some(ua::Double::new(22.5))
+        );
+        assert!(value.server_timestamp().is_none());
+
+        let value = client.read_value(&variable_id).expect("should read value");
+        assert_eq!(
+            value.value().to_scalar::<ua::Double>(),
+            Some(ua::Double::new(22.5))
+        );
+    }
+
+    #[test]
+    fn write_values() {
+        let server = TestServer::start();
+        let variable_ids: Vec<_> = (0..4)
+            .map(|index| {
+                let mut node = VariableNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, &format!("Value{index}")),
+                    &ua::Variant::scalar(ua::Int32::new(index)),
+                );
+                // Make exactly one of the variables read-only.
+                if index == 2 {
+                    node.attributes = node
+                        .attributes
+                        .with_access_level(&ua::AccessLevel::NONE.with_current_read(true));
+                }
+                server
+                    .server()
+                    .add_variable_node(node)
+                    .expect("should add variable")
+            })
+            .collect();
+        let client = server.connect();
+
+        let node_values: Vec<_> = variable_ids
+            .iter()
+            .zip(10..)
+            .map(|(node_id, value)| (node_id.clone(), ua::Variant::scalar(ua::Int32::new(value))))
+            .collect();
+        let results = client
+            .write_values(&node_values)
+            .expect("should write values");
+
+        // Only the read-only variable reports an error, the other writes succeed.
+        let status_codes: Vec<_> = results
+            .iter()
+            .map(|result| {
+                result
+                    .as_ref()
+                    .map_or_else(Error::status_code, |_| ua::StatusCode::GOOD)
+            })
+            .collect();
+        assert_eq!(
+            status_codes,
+            [
+                ua::StatusCode::GOOD,
+                ua::StatusCode::GOOD,
+                ua::StatusCode::BADNOTWRITABLE,
+                ua::StatusCode::GOOD,
+            ]
+        );
+
+        let values: Vec<_> = client
+            .read_values(&variable_ids)
+            .expect("should read values")
+            .into_iter()
+            .map(|value| {
+                value
+                    .expect("should read value")
+                    .into_value()
+                    .to_scalar::<ua::Int32>()
+            })
+            .collect();
+        assert_eq!(
+            values,
+            [10, 11, 2, 13].map(ua::Int32::new).map(Some).to_vec()
+        );
+    }
+
+    #[test]
+    fn write_data_value() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Sensor"),
+                &ua::Variant::scalar(ua::Double::new(0.0)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let value = ua::DataValue::new(ua::Variant::scalar(ua::Double::new(21.5)))
+            .with_status_code(&ua::StatusCode::UNCERTAIN);
+        client
+            .write_data_value(&variable_id, &value)
+            .expect("should write data value");
+
+        // Status code has been written along with the value. Read raw data value, `read_value()`
+        // would turn the non-good status code into an error.
+        let request = ua::ReadRequest::init().with_nodes_to_read(&[ua::ReadValueId::init()
+            .with_node_id(&variable_id)
+            .with_attribute_id(&ua::AttributeId::VALUE)]);
+        let response = service_request(&client.0, &request).expect("should read value");
+        let value = response
+            .results()
+            .and_then(|results| results.iter().next().cloned())
+            .expect("should return result");
+        assert_eq!(value.status(), ua::StatusCode::UNCERTAIN);
+        assert_eq!(
+            value.value().and_then(ua::Variant::to_scalar::<ua::Double>),
+            Some(ua::Double::new(21.5))
+        );
+    }
+
+    #[test]
+    fn write_value_range() {
+        let server = TestServer::start();
+        let values: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0]
+            .into_iter()
+            .map(ua::Double::new)
+            .collect();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Values"),
+                &ua::Variant::array(ua::Array::from_slice(&values)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let index_range = "2:3".parse().expect("should parse range");
+        let elements = ua::Array::from_slice(&[ua::Double::new(30.0), ua::Double::new(40.0)]);
+        client
+            .write_value_range(&variable_id, &index_range, &ua::Variant::array(elements))
+            .expect("should write range");
+
+        // Only the elements in the range have been replaced.
+        let value = client.read_value(&variable_id).expect("should read value");
+        let elements = value
+            .value()
+            .to_array::<ua::Double>()
+            .expect("value should be Double array");
+        let expected: Vec<_> = [1.0, 2.0, 30.0, 40.0, 5.0]
+            .into_iter()
+            .map(ua::Double::new)
+            .collect();
+        assert_eq!(elements.as_slice(), expected);
+
+        // Number of elements must match the range.
+        let elements = ua::Array::from_slice(&[ua::Double::new(30.0)]);
+        let error = client
+            .write_value_range(&variable_id, &index_range, &ua::Variant::array(elements))
+            .expect_err("should fail to write range");
+        assert_eq!(error.status_code(), ua::StatusCode::BADINDEXRANGEINVALID);
+        let error = client
+            .write_value_range(
+                &variable_id,
+                &index_range,
+                &ua::Variant::scalar(ua::Double::new(30.0)),
+            )
+            .expect_err("should fail to write range");
+        assert_eq!(error.status_code(), ua::StatusCode::BADINDEXRANGEINVALID);
+    }
+
+    #[test]
+    fn register_nodes() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let node_ids = [ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME)];
+        let registered_node_ids = client
+            .register_nodes(&node_ids)
+            .expect("should register nodes");
+        assert_eq!(registered_node_ids.len(), node_ids.len());
+
+        // Registered node IDs can be used in place of the original ones.
+        let values = client
+            .read_values(&registered_node_ids)
+            .expect("should read values");
+        let [value] = values.as_slice() else {
+            panic!("should read single value");
+        };
+        let value = value.as_ref().expect("should read registered node");
+        assert!(value.value().as_scalar::<ua::DateTime>().is_some());
+
+        client
+            .unregister_nodes(&registered_node_ids)
+            .expect("should unregister nodes");
+    }
+
+    #[test]
+    fn write_callback() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Setpoint"),
+                &ua::Variant::scalar(ua::Double::new(20.0)),
+            ))
+            .expect("should add variable");
+        let (tx, rx) = mpsc::channel();
+        server
+            .server()
+            .set_write_callback(&variable_id, move |value| {
+                let _unused = tx.send(value.value().and_then(ua::Variant::to_scalar::<ua::Double>));
+            })
+            .expect("should set write callback");
+        let client = server.connect();
+
+        client
+            .write_value(&variable_id, &ua::Variant::scalar(ua::Double::new(22.5)))
+            .expect("should write value");
+
+        let value = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("should call write callback");
+        assert_eq!(value, Some(ua::Double::new(22.5)));
+
+        // Only one callback may be set per node.
+        let error = server
+            .server()
+            .set_write_callback(&variable_id, |_| {})
+            .expect_err("should not set second write callback");
+        assert_eq!(error.status_code(), ua::StatusCode::BADINVALIDSTATE);
+
+        // Only variable nodes may be given a callback.
+        let error = server
+            .server()
+            .set_write_callback(&ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER), |_| {})
+            .expect_err("should not set write callback on object node");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODECLASSINVALID);
+    }
+
+    #[test]
+    fn periodic_value() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Counter"),
+                &ua::Variant::scalar(ua::UInt32::new(0)),
+            ))
+            .expect("should add variable");
+        let calls = Arc::new(AtomicU32::new(0));
+        server
+            .server()
+            .set_periodic_value(&variable_id, Duration::from_millis(20), {
+                let calls = Arc::clone(&calls);
+                move || {
+                    let counter = calls.fetch_add(1, Ordering::Relaxed) + 1;
+                    ua::Variant::scalar(ua::UInt32::new(counter))
+                }
+            })
+            .expect("should set periodic value");
+        let client = server.connect();
+
+        let (tx, rx) = mpsc::channel();
+        let _subscription = client
+            .create_data_change_subscription(
+                &variable_id,
+                Duration::from_millis(20),
+                move |value| {
+                    let _unused =
+                        tx.send(value.value().and_then(ua::Variant::to_scalar::<ua::UInt32>));
+                },
+            )
+            .expect("should create subscription");
+        let _driver = client.spawn_driver();
+
+        let mut values = Vec::new();
+        while values.len() < 3 {
+            let value = rx
+                .recv_timeout(Duration::from_secs(5))
+                .expect("should receive value")
+                .expect("should receive counter");
+            if value.value() > 0 && !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        // Deleting the node stops the callback.
+        server
+            .server()
+            .delete_node(&variable_id, true)
+            .expect("should delete node");
+        thread::sleep(Duration::from_millis(200));
+        let calls_after_delete = calls.load(Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(calls.load(Ordering::Relaxed), calls_after_delete);
+
+        // Only variable nodes may be updated.
+        let error = server
+            .server()
+            .set_periodic_value(
+                &ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                Duration::from_millis(20),
+                || ua::Variant::scalar(ua::UInt32::new(0)),
+            )
+            .expect_err("should not update object node");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODECLASSINVALID);
+    }
+
+    #[test]
+    fn read_deleted_node() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Temporary"),
+                &ua::Variant::scalar(ua::Int32::new(1)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        client.read_value(&variable_id).expect("should read value");
+
+        server
+            .server()
+            .delete_node(&variable_id, true)
+            .expect("should delete node");
+
+        let error = client
+            .read_value(&variable_id)
+            .expect_err("should fail to read deleted node");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODEIDUNKNOWN);
+
+        // The reference from the parent has been deleted along with the node.
+        let browse_description =
+            ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER));
+        let (references, _) = client.browse(&browse_description).expect("should browse");
+        assert!(references
+            .iter()
+            .all(|reference| reference.node_id().node_id() != &variable_id));
+    }
+
+    #[test]
+    fn connected_client_state() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let state = client.state();
+        assert_eq!(state.channel_state, ua::SecureChannelState::OPEN);
+        assert_eq!(state.session_state, ua::SessionState::ACTIVATED);
+        assert!(state.connect_status.is_good());
+    }
+
+    #[test]
+    fn connect_timeout() {
+        // The listener accepts the TCP connection (through the backlog) but never answers the
+        // client's `HEL` message.
+        let listener = TcpListener::bind("127.0.0.1:0").expect("should bind listener");
+        let port = listener.local_addr().expect("should get address").port();
+
+        let started = Instant::now();
+        let error = ClientBuilder::default()
+            .timeout(Duration::from_millis(500))
+            .connect(&format!("opc.tcp://127.0.0.1:{port}"))
+            .expect_err("should not connect to silent endpoint");
+        let elapsed = started.elapsed();
+
+        assert!(error.is_timeout(), "unexpected error: {error}");
+        assert!(elapsed < Duration::from_secs(2), "took {elapsed:?}");
+        drop(listener);
+    }
+
+    #[test]
+    fn concurrent_reads() {
+        const fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Client>();
+
+        let server = TestServer::start();
+        let client = server.connect();
+        // Run event loop in background as well, it must take turns with the service calls.
+        let driver = client.spawn_driver();
+
+        let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..50 {
+                        let value = client.read_value(&node_id).expect("should read value");
+                        assert!(value.value().to_scalar::<ua::DateTime>().is_some());
+                    }
+                });
+            }
+        });
+
+        drop(driver);
+    }
+
+    #[test]
+    fn read_unknown_node() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let error = client
+            .read_value(&ua::NodeId::string(1, "DoesNotExist"))
+            .expect_err("should fail to read unknown node");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODEIDUNKNOWN);
+    }
+
+    #[test]
+    fn read_node_metadata() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let metadata = client
+            .read_node_metadata(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME))
+            .expect("should read metadata");
+        assert_eq!(metadata.node_class(), &ua::NodeClass::VARIABLE);
+        assert_eq!(metadata.browse_name().name().as_str(), Some("CurrentTime"));
+        assert_eq!(metadata.display_name().text().as_str(), Some("CurrentTime"));
+        assert_eq!(
+            metadata.data_type(),
+            Some(&ua::NodeId::ns0(UA_NS0ID_UTCTIME))
+        );
+        assert_eq!(metadata.value_rank(), Some(-1));
+        let access_level = metadata.access_level().expect("should have access level");
+        assert!(access_level.current_read());
+        assert!(!access_level.current_write());
+
+        // Objects have no value, so they have no attributes that describe it.
+        let metadata = client
+            .read_node_metadata(&ua::NodeId::ns0(UA_NS0ID_SERVER))
+            .expect("should read metadata");
+        assert_eq!(metadata.node_class(), &ua::NodeClass::OBJECT);
+        assert_eq!(metadata.data_type(), None);
+        assert_eq!(metadata.value_rank(), None);
+        assert_eq!(metadata.access_level(), None);
+    }
+
+    #[test]
+    fn history_read_raw() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let now = SystemTime::now();
+        let result = client.history_read_raw(
+            &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+            ua::DateTime::try_from(now - Duration::from_secs(60)).unwrap(),
+            ua::DateTime::try_from(now).unwrap(),
+            10,
+        );
+
+        // The test server has no history backend. It rejects the request only after the request
+        // has been decoded and dispatched, which tells us that the request has been well-formed.
+        let Err(Error::Server(status_code)) = result else {
+            panic!("history read should fail");
+        };
+        assert_eq!(status_code, ua::StatusCode::BADNOTSUPPORTED);
+    }
+
+    #[test]
+    fn history_read_direction() {
+        let now = SystemTime::now();
+        let earlier = ua::DateTime::try_from(now - Duration::from_secs(60)).unwrap();
+        let later = ua::DateTime::try_from(now).unwrap();
+        let unspecified = ua::DateTime::init();
+
+        assert!(!history_reads_backwards(&earlier, &later));
+        assert!(history_reads_backwards(&later, &earlier));
+        // Without end, the server reads forwards from start.
+        assert!(!history_reads_backwards(&later, &unspecified));
+        // Without start, the server reads backwards from end.
+        assert!(history_reads_backwards(&unspecified, &later));
+    }
+
+    #[test]
+    fn data_change_notifications() {
+        let server = TestServer::start();
+        let variable_id = server
+            .server()
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Counter"),
+                &ua::Variant::scalar(ua::Int32::new(0)),
+            ))
+            .expect("should add variable");
+        let client = server.connect();
+
+        let (tx, rx) = mpsc::channel();
+        let _subscription = client
+            .create_data_change_subscription(
+                &variable_id,
+                Duration::from_millis(20),
+                move |value| {
+                    let value = value.value().and_then(ua::Variant::to_scalar::<ua::Int32>);
+                    let _unused = tx.send(value);
+                    // Panics must not prevent later notifications.
+                    assert_ne!(value, Some(ua::Int32::new(1)), "callback panics");
+                },
+            )
+            .expect("should create subscription");
+        let _driver = client.spawn_driver();
+
+        let mut values = Vec::new();
+        for counter in 0..=3 {
+            // The initial value is reported without writing.
+            if counter > 0 {
+                client
+                    .write_value(&variable_id, &ua::Variant::scalar(ua::Int32::new(counter)))
+                    .expect("should write value");
+            }
+            // Wait for the value to arrive before writing the next one, so that no value is
+            // skipped by sampling.
+            loop {
+                let value = rx
+                    .recv_timeout(Duration::from_secs(5))
+                    .expect("should receive value")
+                    .expect("should receive counter");
+                if !values.contains(&value) {
+                    values.push(value);
+                }
+                if value.value() == counter {
+                    break;
+                }
+            }
+        }
+        assert_eq!(values, (0..=3).map(ua::Int32::new).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn spawn_driver() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let (tx, rx) = mpsc::channel();
+        let _subscription = client
+            .create_data_change_subscription(
+                &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+                Duration::from_millis(50),
+                move |value| {
+                    let _unused = tx.send(value);
+                },
+            )
+            .expect("should create subscription");
+
+        // Nothing runs the event loop yet.
+        rx.recv_timeout(Duration::from_millis(500))
+            .expect_err("should not receive value without driver");
+
+        let driver = client.spawn_driver();
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("should receive value");
+        assert_eq!(Arc::strong_count(&client.0), 2);
+
+        // Dropping the handle joins the thread, which releases its reference to the client.
+        drop(driver);
+        assert_eq!(Arc::strong_count(&client.0), 1);
+
+        // Skip notifications that were processed before the driver stopped.
+        let _unused = rx.try_iter().count();
+        rx.recv_timeout(Duration::from_millis(500))
+            .expect_err("should not receive value after driver stopped");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn spawn_driver_on_runtime() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("should create runtime");
+
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let (tx, rx) = mpsc::channel();
+        let _subscription = client
+            .create_data_change_subscription(
+                &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
+                Duration::from_millis(100),
+                move |value| {
+                    let _unused = tx.send(value);
+                },
+            )
+            .expect("should create subscription");
+
+        let driver = client.spawn_driver_on(runtime.handle());
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("should receive value");
+
+        // The driver may be dropped from within the runtime without blocking it.
+        runtime.block_on(async move { drop(driver) });
+        runtime.shutdown_timeout(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn receive_events() {
+        let server = TestServer::start();
+        let client = server.connect();
+
+        let select_clauses = ["Message", "Severity"].map(|field| {
+            ua::SimpleAttributeOperand::default()
+                .with_browse_path(&[ua::QualifiedName::new(0, field)])
+        });
+        let (tx, rx) = mpsc::channel();
+        let _subscription = client
+            .create_event_subscription(
+                &ua::NodeId::ns0(UA_NS0ID_SERVER),
+                &select_clauses,
+                move |fields| {
+                    let _unused = tx.send(fields);
+                },
+            )
+            .expect("should create event subscription");
+        let _driver = client.spawn_driver();
+
+        // Keep triggering events until the first one arrives. This gives the server some time to
+        // start reporting events to the subscription.
+        let fields = (0..50)
+            .find_map(|_| {
+                let event_id = server
+                    .server()
+                    .create_event(&ua::NodeId::ns0(UA_NS0ID_BASEEVENTTYPE))
+                    .expect("should create event");
+                for (name, value) in [
+                    (
+                        "Message",
+                        ua::Variant::scalar(
+                            ua::LocalizedText::new("en-US", "Lorem ipsum").unwrap(),
+                        ),
+                    ),
+                    ("Severity", ua::Variant::scalar(ua::UInt16::new(100))),
+                ] {
+                    server
+                        .server()
+                        .write_object_property(&event_id, &ua::QualifiedName::new(0, name), &value)
+                        .expect("should write event field");
+                }
+                server
+                    .server()
+                    .trigger_event(&event_id, &ua::NodeId::ns0(UA_NS0ID_SERVER), true)
+                    .expect("should trigger event");
+
+                rx.recv_timeout(Duration::from_millis(100)).ok()
+            })
+            .expect("should receive event");
+
+        // Event fields match the select clauses.
+        let [message, severity] = fields.as_slice() else {
+            panic!("should receive two event fields");
+        };
+        assert_eq!(
+            message
+                .to_scalar::<ua::LocalizedText>()
+                .and_then(|message| message.text().as_str().map(ToOwned::to_owned))
+                .as_deref(),
+            Some("Lorem ipsum")
+        );
+        assert_eq!(
+            severity.to_scalar::<ua::UInt16>(),
+            Some(ua::UInt16::new(100))
+        );
+    }
+
+    /// Deletes subscription on the server, bypassing the client's own bookkeeping.
+    fn delete_subscription(client: &Client, subscription_id: ua::SubscriptionId) -> ua::StatusCode {
+        let request =
+            ua::DeleteSubscriptionsRequest::init().with_subscription_ids(&[subscription_id]);
+        service_request(&client.0, &request)
+            .expect("should send request")
+            .results()
+            .and_then(|results| results.iter().next().cloned())
+            .expect("should return result")
     }
 }
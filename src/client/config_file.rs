@@ -0,0 +1,268 @@
+//! Client configuration from TOML file.
+
+use std::{fs, io, path::Path, time::Duration};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{ua, ClientBuilder};
+
+/// Error when loading client configuration from file.
+///
+/// This is returned by [`ClientBuilder::from_config_file()`].
+#[derive(Debug, Error)]
+pub enum ConfigFileError {
+    /// File could not be read.
+    #[error("cannot read config file: {0}")]
+    Io(#[from] io::Error),
+
+    /// File is not valid TOML or does not match the schema (e.g. missing required fields).
+    #[error("invalid config file: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    /// File contains values that cannot be used.
+    #[error("invalid config file: {0}")]
+    Invalid(&'static str),
+}
+
+/// Schema of config file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    endpoint_url: String,
+    #[serde(default)]
+    security: Security,
+    user: Option<User>,
+    #[serde(default)]
+    timeouts: Timeouts,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Security {
+    mode: Option<SecurityMode>,
+    policy_uri: Option<String>,
+    certificate: Option<String>,
+    private_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SecurityMode {
+    None,
+    Sign,
+    SignAndEncrypt,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct User {
+    username: String,
+    password: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Timeouts {
+    request_ms: Option<u64>,
+    session_ms: Option<u64>,
+    secure_channel_life_time_ms: Option<u64>,
+}
+
+impl ClientBuilder {
+    /// Loads client configuration from TOML file.
+    ///
+    /// This returns the builder along with the endpoint URL to connect to. The builder may be
+    /// adjusted further before connecting. Only `endpoint_url` is required, all other settings
+    /// fall back to the defaults of [`ClientBuilder::default()`] when they are missing:
+    ///
+    /// ```toml
+    /// endpoint_url = "opc.tcp://localhost:4840"
+    ///
+    /// [security]
+    /// # One of "none", "sign", "sign_and_encrypt".
+    /// mode = "none"
+    /// policy_uri = "http://opcfoundation.org/UA/SecurityPolicy#None"
+    ///
+    /// # Leave out to connect anonymously.
+    /// [user]
+    /// username = "user"
+    /// password = "secret"
+    ///
+    /// [timeouts]
+    /// request_ms = 5000
+    /// session_ms = 60000
+    /// secure_channel_life_time_ms = 600000
+    /// ```
+    ///
+    /// The timeouts correspond to [`timeout()`](Self::timeout),
+    /// [`requested_session_timeout()`](Self::requested_session_timeout), and
+    /// [`secure_channel_life_time()`](Self::secure_channel_life_time).
+    ///
+    /// The `[security]` section also has the fields `certificate` and `private_key` for paths to
+    /// the client certificate and its key. These are rejected for now: the bundled build of
+    /// `open62541` does not include encryption support (see [`security_mode()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use open62541::ClientBuilder;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let (builder, endpoint_url) = ClientBuilder::from_config_file(Path::new("client.toml"))?;
+    /// let client = builder.connect(&endpoint_url)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the file cannot be read, when it does not match the schema above (including
+    /// unknown fields), or when it contains strings with NUL bytes.
+    ///
+    /// [`security_mode()`]: Self::security_mode
+    pub fn from_config_file(path: &Path) -> Result<(Self, String), ConfigFileError> {
+        let config: ConfigFile = toml::from_str(&fs::read_to_string(path)?)?;
+        config.into_builder()
+    }
+}
+
+impl ConfigFile {
+    fn into_builder(self) -> Result<(ClientBuilder, String), ConfigFileError> {
+        let Self {
+            endpoint_url,
+            security,
+            user,
+            timeouts,
+        } = self;
+
+        // Check strings up front: the builder panics on NUL bytes.
+        let mut strings = vec![&endpoint_url];
+        strings.extend(&security.policy_uri);
+        if let Some(user) = &user {
+            strings.extend([&user.username, &user.password]);
+        }
+        if strings.iter().any(|string| string.contains('\0')) {
+            return Err(ConfigFileError::Invalid(
+                "strings must not contain NUL bytes",
+            ));
+        }
+        if security.certificate.is_some() || security.private_key.is_some() {
+            return Err(ConfigFileError::Invalid(
+                "certificates are not supported without encryption",
+            ));
+        }
+
+        let mut builder = ClientBuilder::default();
+
+        if let Some(mode) = security.mode {
+            builder = builder.security_mode(&match mode {
+                SecurityMode::None => ua::MessageSecurityMode::NONE,
+                SecurityMode::Sign => ua::MessageSecurityMode::SIGN,
+                SecurityMode::SignAndEncrypt => ua::MessageSecurityMode::SIGNANDENCRYPT,
+            });
+        }
+        if let Some(policy_uri) = &security.policy_uri {
+            builder = builder.security_policy_uri(policy_uri);
+        }
+        if let Some(user) = &user {
+            builder = builder.username_password(&user.username, &user.password);
+        }
+        if let Some(request_ms) = timeouts.request_ms {
+            builder = builder.timeout(Duration::from_millis(request_ms));
+        }
+        if let Some(session_ms) = timeouts.session_ms {
+            builder = builder.requested_session_timeout(Duration::from_millis(session_ms));
+        }
+        if let Some(secure_channel_life_time_ms) = timeouts.secure_channel_life_time_ms {
+            builder = builder
+                .secure_channel_life_time(Duration::from_millis(secure_channel_life_time_ms));
+        }
+
+        Ok((builder, endpoint_url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use crate::{test_server::TestServer, ua, ClientBuilder};
+
+    use super::ConfigFileError;
+
+    /// Writes config file to temporary directory.
+    fn write_config_file(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("open62541-{}-{name}.toml", process::id()));
+        fs::write(&path, contents).expect("should write config file");
+        path
+    }
+
+    #[test]
+    fn connect_anonymously() {
+        let server = TestServer::start();
+        let path = write_config_file(
+            "anonymous",
+            &format!(
+                r#"
+endpoint_url = "{}"
+
+[security]
+mode = "none"
+
+[timeouts]
+request_ms = 2000
+"#,
+                server.endpoint_url()
+            ),
+        );
+
+        let result = ClientBuilder::from_config_file(&path);
+        let _unused = fs::remove_file(&path);
+        let (builder, endpoint_url) = result.expect("should load config file");
+        assert_eq!(endpoint_url, server.endpoint_url());
+
+        let client = builder
+            .connect(&endpoint_url)
+            .expect("client should connect");
+        let state = client.state();
+        assert_eq!(state.channel_state, ua::SecureChannelState::OPEN);
+        assert_eq!(state.session_state, ua::SessionState::ACTIVATED);
+    }
+
+    #[test]
+    fn reject_invalid_config() {
+        let load = |name, contents| {
+            let path = write_config_file(name, contents);
+            let result = ClientBuilder::from_config_file(&path);
+            let _unused = fs::remove_file(&path);
+            result.map(|_| ())
+        };
+
+        // Endpoint URL is required.
+        let result = load("missing", "[timeouts]\nrequest_ms = 2000\n");
+        assert!(matches!(result, Err(ConfigFileError::Parse(_))));
+
+        // Typos are not silently ignored.
+        let result = load(
+            "unknown",
+            "endpoint_url = \"opc.tcp://localhost:4840\"\ntimeout_ms = 2000\n",
+        );
+        assert!(matches!(result, Err(ConfigFileError::Parse(_))));
+
+        let result = load(
+            "certificate",
+            concat!(
+                "endpoint_url = \"opc.tcp://localhost:4840\"\n",
+                "[security]\n",
+                "certificate = \"client.der\"\n",
+            ),
+        );
+        assert!(matches!(result, Err(ConfigFileError::Invalid(_))));
+
+        let result =
+            ClientBuilder::from_config_file(&env::temp_dir().join("open62541-nonexistent.toml"));
+        assert!(matches!(result, Err(ConfigFileError::Io(_))));
+    }
+}
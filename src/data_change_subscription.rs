@@ -0,0 +1,275 @@
+use std::{
+    collections::VecDeque,
+    ffi::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError, Weak},
+    time::Duration,
+};
+
+use open62541_sys::{
+    UA_Client, UA_Client_MonitoredItems_createDataChange, UA_Client_Subscriptions_create,
+    UA_Client_Subscriptions_deleteSingle, UA_DataValue, UA_UInt32,
+};
+
+use crate::{
+    subscription_registry::{MonitoredItemEntry, SubscriptionEntry},
+    ua, DataType as _, Error, Result, ServiceResponse as _,
+};
+
+/// Subscription with single monitored item (with callback-based API).
+///
+/// This is returned by [`Client::create_data_change_subscription()`]. The callback is invoked for
+/// every data change notification received for the monitored node. When the subscription is
+/// dropped, it is deleted on the server and the callback is released.
+///
+/// Notifications are only received while the client's event loop is running. See
+/// [`Client::spawn_driver()`] for details.
+///
+/// [`Client::create_data_change_subscription()`]: crate::Client::create_data_change_subscription
+/// [`Client::spawn_driver()`]: crate::Client::spawn_driver
+#[derive(Debug)]
+pub struct DataChangeSubscription {
+    client: Weak<ua::Client>,
+    subscription: Arc<SubscriptionEntry>,
+    monitored_item: Arc<MonitoredItemEntry>,
+}
+
+impl DataChangeSubscription {
+    pub(crate) fn new(
+        client: &Arc<ua::Client>,
+        node_id: &ua::NodeId,
+        sampling_interval: Duration,
+        callback: impl FnMut(ua::DataValue) + Send + 'static,
+    ) -> Result<Self> {
+        let subscription_request = ua::CreateSubscriptionRequest::default();
+        let subscription_id = create_subscription(client, &subscription_request)?.subscription_id();
+
+        // `UA_MonitoredItemCreateRequest` expects the sampling interval given in milliseconds.
+        let sampling_interval = sampling_interval.as_secs_f64() * 1000.0;
+
+        let request = ua::MonitoredItemCreateRequest::default()
+            .with_node_id(node_id)
+            .with_sampling_interval(sampling_interval);
+
+        let notifier = Arc::new(Notifier::new(callback));
+
+        // Take down subscription before returning any error. The subscription is not yet owned by
+        // any `DataChangeSubscription` instance that would do so when being dropped.
+        let monitored_item_id = create_data_change(client, subscription_id, &request, &notifier)
+            .inspect_err(|_| delete_subscription(client, subscription_id))?
+            .monitored_item_id();
+
+        let subscription = SubscriptionEntry::new(client, subscription_request, subscription_id);
+        let monitored_item =
+            MonitoredItemEntry::data_change(request, notifier, subscription_id, monitored_item_id);
+        subscription.add_monitored_item(&monitored_item);
+        client.register_subscription(&subscription);
+
+        Ok(Self {
+            client: Arc::downgrade(client),
+            subscription,
+            monitored_item,
+        })
+    }
+
+    /// Gets subscription ID.
+    ///
+    /// The ID changes when the subscription is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn subscription_id(&self) -> ua::SubscriptionId {
+        self.subscription.subscription_id()
+    }
+
+    /// Gets monitored item ID.
+    ///
+    /// The ID changes when the subscription is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.monitored_item.monitored_item_id()
+    }
+}
+
+impl Drop for DataChangeSubscription {
+    fn drop(&mut self) {
+        // When the client has been dropped already, all subscriptions (and their monitored items)
+        // have been cleaned up by `UA_Client_delete()`, including our callback.
+        let Some(client) = self.client.upgrade() else {
+            return;
+        };
+
+        // This also deletes the monitored item, which releases the callback.
+        delete_subscription(&client, self.subscription.delete());
+    }
+}
+
+/// Boxed callback for notifications.
+type Callback<T> = Box<dyn FnMut(T) + Send>;
+
+/// Context of monitored item.
+///
+/// Notifications may be delivered re-entrantly: when the callback runs a blocking service request,
+/// open62541 processes incoming notifications while waiting for the response, thereby invoking our
+/// notification callback again. We must not call into the Rust callback at the same time. Instead,
+/// values are queued and delivered in order by the outermost invocation.
+pub(crate) struct Notifier<T> {
+    callback: Mutex<Callback<T>>,
+    pending: Mutex<VecDeque<T>>,
+}
+
+impl<T> Notifier<T> {
+    pub(crate) fn new(callback: impl FnMut(T) + Send + 'static) -> Self {
+        Self {
+            callback: Mutex::new(Box::new(callback)),
+            pending: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub(crate) fn notify(&self, value: T) {
+        self.pending_values().push_back(value);
+
+        loop {
+            let mut callback = match self.callback.try_lock() {
+                Ok(callback) => callback,
+                Err(TryLockError::Poisoned(error)) => error.into_inner(),
+                // Another invocation is delivering values right now. It will pick up our value too.
+                Err(TryLockError::WouldBlock) => return,
+            };
+
+            // Take values one by one to not hold the lock while running the callback.
+            while let Some(value) = self.pending_values().pop_front() {
+                let mut callback = AssertUnwindSafe(&mut *callback);
+                // Do not unwind into open62541. Later values are still delivered.
+                if let Err(err) = catch_unwind(move || callback(value)) {
+                    log::error!("Notification callback panicked: {err:?}");
+                }
+            }
+
+            drop(callback);
+
+            // A value may have been queued after we had emptied the queue but before releasing the
+            // lock on the callback. Deliver it now, unless another invocation has already taken it.
+            if self.pending_values().is_empty() {
+                return;
+            }
+        }
+    }
+
+    fn pending_values(&self) -> MutexGuard<'_, VecDeque<T>> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub(crate) fn create_subscription(
+    client: &ua::Client,
+    request: &ua::CreateSubscriptionRequest,
+) -> Result<ua::CreateSubscriptionResponse> {
+    log::debug!("Calling Subscriptions_create()");
+
+    let response = unsafe {
+        UA_Client_Subscriptions_create(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            // SAFETY: `UA_Client_Subscriptions_create()` expects the request passed by value but
+            // does not take ownership.
+            ua::CreateSubscriptionRequest::to_raw_copy(request),
+            ptr::null_mut(),
+            None,
+            None,
+        )
+    };
+    // SAFETY: We take ownership of the returned response.
+    let response = unsafe { ua::CreateSubscriptionResponse::from_raw(response) };
+    Error::verify_good(&response.service_result())?;
+
+    Ok(response)
+}
+
+pub(crate) fn create_data_change(
+    client: &ua::Client,
+    subscription_id: ua::SubscriptionId,
+    request: &ua::MonitoredItemCreateRequest,
+    notifier: &Arc<Notifier<ua::DataValue>>,
+) -> Result<ua::MonitoredItemCreateResult> {
+    unsafe extern "C" fn notification_callback_c(
+        _client: *mut UA_Client,
+        _sub_id: UA_UInt32,
+        _sub_context: *mut c_void,
+        _mon_id: UA_UInt32,
+        mon_context: *mut c_void,
+        value: *mut UA_DataValue,
+    ) {
+        log::debug!("DataChangeNotificationCallback() was called");
+
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when called.
+        let value = unsafe { value.as_ref() }.expect("value should be set");
+        let value = ua::DataValue::clone_raw(value);
+
+        // SAFETY: `mon_context` is the result of `Arc::into_raw()` below and only released in the
+        // delete callback. We only ever take shared references: the callback may run re-entrantly.
+        let notifier = unsafe { &*mon_context.cast::<Notifier<ua::DataValue>>() };
+        notifier.notify(value);
+    }
+
+    unsafe extern "C" fn delete_callback_c(
+        _client: *mut UA_Client,
+        _sub_id: UA_UInt32,
+        _sub_context: *mut c_void,
+        _mon_id: UA_UInt32,
+        mon_context: *mut c_void,
+    ) {
+        log::debug!("DeleteMonitoredItemCallback() was called");
+
+        // SAFETY: `mon_context` is the result of `Arc::into_raw()` below and is released only once.
+        drop(unsafe { Arc::from_raw(mon_context.cast::<Notifier<ua::DataValue>>()) });
+    }
+
+    log::debug!("Calling MonitoredItems_createDataChange()");
+
+    // From here on, open62541 owns a reference to the notifier: it calls the delete callback when
+    // the item cannot be created, when it is deleted, or when the client is deleted. We keep our
+    // own reference to be able to re-create the item with the same callback.
+    let result = unsafe {
+        UA_Client_MonitoredItems_createDataChange(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            subscription_id.as_u32(),
+            ua::TimestampsToReturn::BOTH.into_raw(),
+            // SAFETY: `UA_Client_MonitoredItems_createDataChange()` expects the request passed by
+            // value but does not take ownership.
+            ua::MonitoredItemCreateRequest::to_raw_copy(request),
+            Arc::into_raw(Arc::clone(notifier))
+                .cast_mut()
+                .cast::<c_void>(),
+            Some(notification_callback_c),
+            Some(delete_callback_c),
+        )
+    };
+    // SAFETY: We take ownership of the returned result.
+    let result = unsafe { ua::MonitoredItemCreateResult::from_raw(result) };
+    Error::verify_good(&result.status_code())?;
+
+    Ok(result)
+}
+
+pub(crate) fn delete_subscription(client: &ua::Client, subscription_id: ua::SubscriptionId) {
+    log::debug!("Calling Subscriptions_deleteSingle()");
+
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_Client_Subscriptions_deleteSingle(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            subscription_id.as_u32(),
+        )
+    });
+    if let Err(error) = Error::verify_good(&status_code) {
+        log::warn!("Error when deleting subscription: {error}");
+    }
+}
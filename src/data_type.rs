@@ -333,6 +333,13 @@ pub unsafe trait DataType: Debug + Clone {
 /// This provides the basic interface to convert from and back into the [`open62541_sys`] types. Use
 /// another `impl` block to add additional methods to each type if necessary.
 macro_rules! data_type {
+    // Use this variant when the type provides its own implementation of `Debug`.
+    (@custom_debug $name:ident) => {
+        paste::paste! {
+            $crate::data_type!(@base $name, [<UA_ $name>], [<UA_TYPES_ $name:upper>]);
+        }
+    };
+
     ($name:ident) => {
         paste::paste! {
             $crate::data_type!($name, [<UA_ $name>], [<UA_TYPES_ $name:upper>]);
@@ -346,6 +353,18 @@ macro_rules! data_type {
     };
 
     ($name:ident, $inner:ident, $index:ident) => {
+        $crate::data_type!(@base $name, $inner, $index);
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let output = <Self as $crate::DataType>::print(self);
+                let string = output.as_ref().and_then(|output| output.as_str());
+                f.write_str(string.unwrap_or(stringify!($name)))
+            }
+        }
+    };
+
+    (@base $name:ident, $inner:ident, $index:ident) => {
         /// Wrapper for
         #[doc = concat!("[`", stringify!($inner), "`](open62541_sys::", stringify!($inner), ")")]
         /// from [`open62541_sys`].
@@ -419,14 +438,6 @@ macro_rules! data_type {
             }
         }
 
-        impl std::fmt::Debug for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                let output = <Self as $crate::DataType>::print(self);
-                let string = output.as_ref().and_then(|output| output.as_str());
-                f.write_str(string.unwrap_or(stringify!($name)))
-            }
-        }
-
         impl std::cmp::PartialEq for $name {
             fn eq(&self, other: &Self) -> bool {
                 <Self as std::cmp::Ord>::cmp(self, other) == std::cmp::Ordering::Equal
@@ -534,3 +545,30 @@ macro_rules! bitmask_ops {
 }
 
 pub(crate) use bitmask_ops;
+
+/// Implements conversions for wrappers of well-known node IDs in namespace 0.
+macro_rules! ns0_id {
+    ($name:ident) => {
+        impl $name {
+            /// Gets numeric identifier.
+            #[must_use]
+            pub const fn as_u32(self) -> u32 {
+                self.0
+            }
+
+            /// Gets node ID in namespace 0.
+            #[must_use]
+            pub fn to_node_id(self) -> $crate::ua::NodeId {
+                $crate::ua::NodeId::ns0(self.0)
+            }
+        }
+
+        impl From<$name> for $crate::ua::NodeId {
+            fn from(value: $name) -> Self {
+                value.to_node_id()
+            }
+        }
+    };
+}
+
+pub(crate) use ns0_id;
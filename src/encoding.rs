@@ -0,0 +1,202 @@
+use std::{ffi::c_void, ptr};
+
+use open62541_sys::{
+    UA_ByteString, UA_calcSizeBinary, UA_decodeBinary, UA_decodeJson, UA_encodeBinary,
+    UA_encodeJson,
+};
+
+use crate::{ua, DataType, Error, Result};
+
+/// Encodes value with OPC UA binary encoding.
+///
+/// This is the encoding that is used when transmitting values. Use [`decode_binary()`] to get the
+/// value back.
+///
+/// ```
+/// use open62541::{decode_binary, encode_binary, ua};
+///
+/// let node_id = ua::NodeId::numeric(1, 1234);
+/// let bytes = encode_binary(&node_id).unwrap();
+/// assert_eq!(bytes, [0x01, 0x01, 0xd2, 0x04]);
+/// assert_eq!(decode_binary::<ua::NodeId>(&bytes).unwrap(), node_id);
+/// ```
+///
+/// # Errors
+///
+/// This fails when the value cannot be encoded.
+pub fn encode_binary<T: DataType>(value: &T) -> Result<Vec<u8>> {
+    let size = unsafe { UA_calcSizeBinary(value.as_ptr().cast::<c_void>(), T::data_type()) };
+    // Every value takes up at least one byte. The size is `0` only when the value is invalid.
+    if size == 0 {
+        return Err(Error::internal("value should be encodable"));
+    }
+
+    let mut bytes = vec![0; size];
+    // Do not use `ua::ByteString` here: the buffer belongs to `bytes` and must not be released by
+    // `open62541`. Passing a non-empty buffer tells `UA_encodeBinary()` to not allocate its own.
+    let mut output = UA_ByteString {
+        length: bytes.len(),
+        data: bytes.as_mut_ptr(),
+    };
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_encodeBinary(
+            value.as_ptr().cast::<c_void>(),
+            T::data_type(),
+            ptr::addr_of_mut!(output),
+        )
+    });
+    Error::verify_good(&status_code)?;
+
+    // The encoder stores the number of bytes actually written.
+    debug_assert_eq!(output.length, size);
+    bytes.truncate(output.length);
+
+    Ok(bytes)
+}
+
+/// Decodes value from OPC UA binary encoding.
+///
+/// The input must hold exactly one encoded value of the given data type, in the canonical form that
+/// [`encode_binary()`] produces. See there for an example.
+///
+/// # Errors
+///
+/// This fails when the input cannot be decoded as value of the given data type, or when there are
+/// bytes left over after decoding. Input in non-canonical form is rejected as well, e.g. numeric
+/// node IDs in full form that have a shorter encoding: the decoder does not tell us how many bytes
+/// it has consumed, so we compare the input length against the size of the decoded value instead.
+pub fn decode_binary<T: DataType>(bytes: &[u8]) -> Result<T> {
+    // Do not use `ua::ByteString` here: the buffer belongs to `bytes` and must not be released by
+    // `open62541`. The decoder does not mutate the buffer.
+    let input = UA_ByteString {
+        length: bytes.len(),
+        data: bytes.as_ptr().cast_mut(),
+    };
+    let mut value = T::init();
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_decodeBinary(
+            ptr::addr_of!(input),
+            value.as_mut_ptr().cast::<c_void>(),
+            T::data_type(),
+            // Use default options: only built-in data types are known.
+            ptr::null(),
+        )
+    });
+    Error::verify_good(&status_code)?;
+
+    let size = unsafe { UA_calcSizeBinary(value.as_ptr().cast::<c_void>(), T::data_type()) };
+    if size != bytes.len() {
+        return Err(Error::internal(
+            "binary encoding should be fully consumed and canonical",
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Encodes value with OPC UA JSON encoding.
+///
+/// This uses the reversible form of the encoding which includes the data types of values, e.g. in
+/// [`ua::Variant`]. Thus, the value can be decoded again by [`decode_json()`].
+pub(crate) fn encode_json<T: DataType>(value: &T) -> Result<String> {
+    let mut output = ua::ByteString::init();
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_encodeJson(
+            value.as_ptr().cast::<c_void>(),
+            T::data_type(),
+            output.as_mut_ptr(),
+            // Default options use the reversible encoding.
+            ptr::null(),
+        )
+    });
+    Error::verify_good(&status_code)?;
+
+    let Some(bytes) = output.as_bytes() else {
+        return Err(Error::internal("JSON encoding should return bytes"));
+    };
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| Error::internal("JSON encoding should be valid UTF-8"))
+}
+
+/// Decodes value from OPC UA JSON encoding.
+pub(crate) fn decode_json<T: DataType>(json: &str) -> Result<T> {
+    let input = ua::ByteString::new(json.as_bytes());
+    let mut value = T::init();
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_decodeJson(
+            input.as_ptr(),
+            value.as_mut_ptr().cast::<c_void>(),
+            T::data_type(),
+            // Default options know only about built-in data types.
+            ptr::null(),
+        )
+    });
+    Error::verify_good(&status_code)?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    use super::{decode_binary, encode_binary};
+
+    #[test]
+    fn binary_node_id() {
+        let node_id = ua::NodeId::string(1, "Lorem ipsum");
+        let bytes = encode_binary(&node_id).expect("should encode node ID");
+        assert_eq!(
+            decode_binary::<ua::NodeId>(&bytes).expect("should decode node ID"),
+            node_id
+        );
+    }
+
+    #[test]
+    fn binary_variant() {
+        let variant = ua::Variant::array(ua::Array::from_slice(&[1, 2, 3].map(ua::Int32::new)));
+        let bytes = encode_binary(&variant).expect("should encode variant");
+        // Encoding mask `Int32 | IsArray`, array length, and elements.
+        assert_eq!(
+            bytes,
+            [0x86, 3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]
+        );
+
+        let decoded = decode_binary::<ua::Variant>(&bytes).expect("should decode variant");
+        assert_eq!(decoded, variant);
+    }
+
+    #[test]
+    fn binary_incomplete_input() {
+        let bytes = encode_binary(&ua::NodeId::string(1, "Lorem ipsum")).unwrap();
+
+        // Input is too short.
+        let (truncated, _) = bytes.split_at(bytes.len() - 1);
+        assert!(decode_binary::<ua::NodeId>(truncated).is_err());
+        assert!(decode_binary::<ua::NodeId>(&[]).is_err());
+
+        // Input has trailing bytes.
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(decode_binary::<ua::NodeId>(&extended).is_err());
+
+        // Input has wrong data type: `Byte` decodes the first byte only.
+        assert!(decode_binary::<ua::Byte>(&bytes).is_err());
+    }
+
+    #[test]
+    fn binary_non_canonical_input() {
+        // Numeric node ID `ns=0;i=5` in full numeric form, instead of the two-byte form that the
+        // encoder would use.
+        let bytes = [0x02, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00];
+        assert!(decode_binary::<ua::NodeId>(&bytes).is_err());
+
+        // The canonical form is accepted.
+        let bytes = encode_binary(&ua::NodeId::ns0(5)).expect("should encode node ID");
+        assert_eq!(bytes, [0x00, 0x05]);
+        assert_eq!(
+            decode_binary::<ua::NodeId>(&bytes).expect("should decode node ID"),
+            ua::NodeId::ns0(5)
+        );
+    }
+}
@@ -13,11 +13,19 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// [`is_good()`]: crate::ua::StatusCode::is_good
 #[derive(Debug, Error)]
 #[allow(clippy::error_impl_error)] // The main error type of our crate may be named `Error`.
+#[non_exhaustive]
 pub enum Error {
     /// Error from server.
     #[error("{0}")]
     Server(ua::StatusCode),
 
+    /// Error from server, for service request as a whole.
+    ///
+    /// This holds the response header with the service result, the request handle and, when the
+    /// server provides it, diagnostic info. See [`response_header()`](Self::response_header).
+    #[error("{}", .0.service_result())]
+    ServiceFault(Box<ua::ResponseHeader>),
+
     /// Internal error.
     #[error("{0}")]
     Internal(&'static str),
@@ -34,6 +42,13 @@ impl Error {
         Self::Server(status_code)
     }
 
+    /// Creates error from response header of failed service request.
+    #[must_use]
+    pub(crate) fn service_fault(response_header: ua::ResponseHeader) -> Self {
+        debug_assert!(!response_header.service_result().is_good());
+        Self::ServiceFault(Box::new(response_header))
+    }
+
     pub(crate) fn verify_good(status_code: &ua::StatusCode) -> Result<()> {
         if status_code.is_good() {
             Ok(())
@@ -51,13 +66,79 @@ impl Error {
         match self {
             // TODO: Avoid clone and make `ua::StatusCode` derive `Copy`.
             Error::Server(status_code) => status_code.clone(),
+            Error::ServiceFault(response_header) => response_header.service_result().clone(),
             Error::Internal(_) => ua::StatusCode::BAD,
         }
     }
 
+    /// Gets originating OPC UA status code.
+    ///
+    /// Unlike [`status_code()`](Self::status_code), this returns `None` for internal errors that
+    /// have not been derived from a status code (such as invalid arguments or unexpected responses).
+    #[must_use]
+    pub fn as_status_code(&self) -> Option<&ua::StatusCode> {
+        match self {
+            Error::Server(status_code) => Some(status_code),
+            Error::ServiceFault(response_header) => Some(response_header.service_result()),
+            Error::Internal(_) => None,
+        }
+    }
+
+    /// Gets response header of failed service request.
+    ///
+    /// This is set when the service request has failed as a whole (a service fault), e.g. when the
+    /// server found the request malformed or was too busy to process it. Use it to inspect the
+    /// request handle and diagnostic info. Failures to send the request (e.g. when not connected)
+    /// are reported the same way by `open62541`.
+    ///
+    /// This returns `None` for other errors, such as errors of individual operations within the
+    /// request.
+    #[must_use]
+    pub fn response_header(&self) -> Option<&ua::ResponseHeader> {
+        match self {
+            Error::ServiceFault(response_header) => Some(response_header),
+            Error::Server(_) | Error::Internal(_) => None,
+        }
+    }
+
+    /// Checks if error is a timeout.
+    ///
+    /// This is the case when the request or the operation timed out, as indicated by the status code
+    /// [`ua::StatusCode::BADTIMEOUT`]. Timeouts are often transient, so it may make sense to retry.
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        self.as_status_code() == Some(&ua::StatusCode::BADTIMEOUT)
+    }
+
     #[allow(dead_code)] // --no-default-features
     #[must_use]
     pub(crate) const fn internal(message: &'static str) -> Self {
         Self::Internal(message)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, Error};
+
+    #[test]
+    fn originating_status_code() {
+        let error = Error::new(ua::StatusCode::BADTIMEOUT);
+        assert_eq!(error.status_code(), ua::StatusCode::BADTIMEOUT);
+        assert_eq!(error.as_status_code(), Some(&ua::StatusCode::BADTIMEOUT));
+        assert!(error.is_timeout());
+
+        let error = Error::new(ua::StatusCode::BADNODEIDUNKNOWN);
+        assert_eq!(
+            error.as_status_code(),
+            Some(&ua::StatusCode::BADNODEIDUNKNOWN)
+        );
+        assert!(!error.is_timeout());
+
+        // Internal errors have no originating status code.
+        let error = Error::internal("lorem ipsum");
+        assert_eq!(error.status_code(), ua::StatusCode::BAD);
+        assert_eq!(error.as_status_code(), None);
+        assert!(!error.is_timeout());
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    ffi::c_void,
+    slice,
+    sync::{Arc, Weak},
+};
+
+use open62541_sys::{UA_Client, UA_Client_MonitoredItems_createEvent, UA_UInt32, UA_Variant};
+
+use crate::{
+    data_change_subscription::{create_subscription, delete_subscription, Notifier},
+    subscription_registry::{MonitoredItemEntry, SubscriptionEntry},
+    ua, DataType as _, Error, Result,
+};
+
+/// Subscription with single monitored item for events (with callback-based API).
+///
+/// This is returned by [`Client::create_event_subscription()`]. The callback is invoked for every
+/// event received from the monitored node. When the subscription is dropped, it is deleted on the
+/// server and the callback is released.
+///
+/// Notifications are only received while the client's event loop is running. See
+/// [`Client::spawn_driver()`] for details.
+///
+/// [`Client::create_event_subscription()`]: crate::Client::create_event_subscription
+/// [`Client::spawn_driver()`]: crate::Client::spawn_driver
+#[derive(Debug)]
+pub struct EventSubscription {
+    client: Weak<ua::Client>,
+    subscription: Arc<SubscriptionEntry>,
+    monitored_item: Arc<MonitoredItemEntry>,
+}
+
+impl EventSubscription {
+    pub(crate) fn new(
+        client: &Arc<ua::Client>,
+        node_id: &ua::NodeId,
+        select_clauses: &[ua::SimpleAttributeOperand],
+        callback: impl FnMut(Vec<ua::Variant>) + Send + 'static,
+    ) -> Result<Self> {
+        let subscription_request = ua::CreateSubscriptionRequest::default();
+        let subscription_id = create_subscription(client, &subscription_request)?.subscription_id();
+
+        let filter = ua::EventFilter::init().with_select_clauses(select_clauses);
+
+        // Events are not sampled: the sampling interval of `0.0` makes the server report them as
+        // soon as they occur.
+        let request = ua::MonitoredItemCreateRequest::default()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::EVENTNOTIFIER)
+            .with_sampling_interval(0.0)
+            .with_filter(&ua::ExtensionObject::new(&filter));
+
+        let notifier = Arc::new(Notifier::new(callback));
+
+        // Take down subscription before returning any error. The subscription is not yet owned by
+        // any `EventSubscription` instance that would do so when being dropped.
+        let monitored_item_id = create_event(client, subscription_id, &request, &notifier)
+            .inspect_err(|_| delete_subscription(client, subscription_id))?;
+
+        let subscription = SubscriptionEntry::new(client, subscription_request, subscription_id);
+        let monitored_item =
+            MonitoredItemEntry::event(request, notifier, subscription_id, monitored_item_id);
+        subscription.add_monitored_item(&monitored_item);
+        client.register_subscription(&subscription);
+
+        Ok(Self {
+            client: Arc::downgrade(client),
+            subscription,
+            monitored_item,
+        })
+    }
+
+    /// Gets subscription ID.
+    ///
+    /// The ID changes when the subscription is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn subscription_id(&self) -> ua::SubscriptionId {
+        self.subscription.subscription_id()
+    }
+
+    /// Gets monitored item ID.
+    ///
+    /// The ID changes when the subscription is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.monitored_item.monitored_item_id()
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        // When the client has been dropped already, all subscriptions (and their monitored items)
+        // have been cleaned up by `UA_Client_delete()`, including our callback.
+        let Some(client) = self.client.upgrade() else {
+            return;
+        };
+
+        // This also deletes the monitored item, which releases the callback.
+        delete_subscription(&client, self.subscription.delete());
+    }
+}
+
+pub(crate) fn create_event(
+    client: &ua::Client,
+    subscription_id: ua::SubscriptionId,
+    request: &ua::MonitoredItemCreateRequest,
+    notifier: &Arc<Notifier<Vec<ua::Variant>>>,
+) -> Result<ua::MonitoredItemId> {
+    unsafe extern "C" fn notification_callback_c(
+        _client: *mut UA_Client,
+        _sub_id: UA_UInt32,
+        _sub_context: *mut c_void,
+        _mon_id: UA_UInt32,
+        mon_context: *mut c_void,
+        n_event_fields: usize,
+        event_fields: *mut UA_Variant,
+    ) {
+        log::debug!("EventNotificationCallback() was called");
+
+        let event_fields = if n_event_fields == 0 {
+            // The pointer may be null (or the sentinel value for empty arrays).
+            Vec::new()
+        } else {
+            // SAFETY: Incoming pointer is valid for access, with the given number of fields.
+            let event_fields = unsafe { slice::from_raw_parts(event_fields, n_event_fields) };
+            event_fields.iter().map(ua::Variant::clone_raw).collect()
+        };
+
+        // SAFETY: `mon_context` is the result of `Arc::into_raw()` below and only released in the
+        // delete callback. We only ever take shared references: the callback may run re-entrantly.
+        let notifier = unsafe { &*mon_context.cast::<Notifier<Vec<ua::Variant>>>() };
+        notifier.notify(event_fields);
+    }
+
+    unsafe extern "C" fn delete_callback_c(
+        _client: *mut UA_Client,
+        _sub_id: UA_UInt32,
+        _sub_context: *mut c_void,
+        _mon_id: UA_UInt32,
+        mon_context: *mut c_void,
+    ) {
+        log::debug!("DeleteMonitoredItemCallback() was called");
+
+        // SAFETY: `mon_context` is the result of `Arc::into_raw()` below and is released only once.
+        drop(unsafe { Arc::from_raw(mon_context.cast::<Notifier<Vec<ua::Variant>>>()) });
+    }
+
+    log::debug!("Calling MonitoredItems_createEvent()");
+
+    // From here on, open62541 owns a reference to the notifier: it calls the delete callback when
+    // the item cannot be created, when it is deleted, or when the client is deleted. We keep our
+    // own reference to be able to re-create the item with the same callback.
+    let result = unsafe {
+        UA_Client_MonitoredItems_createEvent(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            subscription_id.as_u32(),
+            ua::TimestampsToReturn::BOTH.into_raw(),
+            // SAFETY: `UA_Client_MonitoredItems_createEvent()` expects the request passed by value
+            // but does not take ownership.
+            ua::MonitoredItemCreateRequest::to_raw_copy(request),
+            Arc::into_raw(Arc::clone(notifier))
+                .cast_mut()
+                .cast::<c_void>(),
+            Some(notification_callback_c),
+            Some(delete_callback_c),
+        )
+    };
+    // SAFETY: We take ownership of the returned result.
+    let result = unsafe { ua::MonitoredItemCreateResult::from_raw(result) };
+    Error::verify_good(&result.status_code())?;
+
+    Ok(result.monitored_item_id())
+}
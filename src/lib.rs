@@ -96,7 +96,9 @@
 //! ```
 //!
 //! By default, [`ServerRunner::run()`] runs on the current thread. Use
-//! [`thread::spawn()`](std::thread::spawn) to run it in a different thread.
+//! [`thread::spawn()`](std::thread::spawn) to run it in a different thread. To stop the server
+//! from within the program instead of waiting for `SIGINT`, use
+//! [`ServerRunner::run_until_cancelled()`].
 //!
 //! ## Server: Define object and managed variable nodes
 //!
@@ -225,35 +227,50 @@ mod attributes;
 mod browse_result;
 #[cfg(feature = "tokio")]
 mod callback;
+mod data_change_subscription;
 mod data_value;
+mod encoding;
+mod event_subscription;
 mod logger;
+mod monitored_item;
+mod subscription;
+mod subscription_registry;
+#[cfg(test)]
+mod test_server;
 mod traits;
 mod userdata;
 mod value;
 
 pub use self::{
     browse_result::BrowseResult,
-    client::{Client, ClientBuilder},
+    client::{Client, ClientBuilder, ClientDriver, DisconnectedClient},
+    data_change_subscription::DataChangeSubscription,
     data_type::DataType,
     data_value::DataValue,
+    encoding::{decode_binary, encode_binary},
     error::{Error, Result},
+    event_subscription::EventSubscription,
+    monitored_item::MonitoredItem,
     server::{
-        DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
+        DataSource, DataSourceError, DataSourceFn, DataSourceReadContext, DataSourceResult,
         DataSourceWriteContext, MethodCallback, MethodCallbackContext, MethodCallbackError,
-        MethodCallbackResult, MethodNode, Node, ObjectNode, Server, ServerBuilder, ServerRunner,
-        VariableNode,
+        MethodCallbackFn, MethodCallbackResult, MethodNode, Node, ObjectNode, Server,
+        ServerBuilder, ServerRunner, VariableNode,
     },
+    subscription::Subscription,
     traits::{Attribute, Attributes},
     userdata::Userdata,
     value::{ScalarValue, ValueType, VariantValue},
 };
 pub(crate) use self::{
-    data_type::{bitmask_ops, data_type, enum_variants},
-    logger::logger,
+    data_type::{bitmask_ops, data_type, enum_variants, ns0_id},
+    logger::{logger, set_log_handler},
     service::{ServiceRequest, ServiceResponse},
     value::{ArrayValue, NonScalarValue},
 };
 
+#[cfg(feature = "config")]
+pub use self::client::ConfigFileError;
 #[cfg(feature = "tokio")]
 pub use self::{
     async_client::AsyncClient,
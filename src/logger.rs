@@ -1,86 +1,160 @@
 use std::{
     ffi::{c_char, c_void, CStr},
-    ptr,
+    panic::{catch_unwind, AssertUnwindSafe},
 };
 
 use open62541_sys::{vsnprintf_va_copy, vsnprintf_va_end, UA_LogCategory, UA_LogLevel, UA_Logger};
 
+use crate::ua;
+
 const LOG_TARGET: &str = "open62541_sys";
 
+/// Custom handler for log messages.
+pub(crate) type LogHandler = Box<dyn Fn(ua::LogLevel, ua::LogCategory, &str) + Send + Sync>;
+
+/// Context attached to our `UA_Logger` instances.
+#[derive(Default)]
+struct LoggerContext {
+    /// Custom handler. When unset, log messages are forwarded to the `log` crate.
+    handler: Option<LogHandler>,
+}
+
 /// Creates logger that forwards to the `log` crate.
 ///
+/// Messages are logged with target `open62541_sys::<category>`, e.g. `open62541_sys::client`.
+///
 /// We can use this to prevent `open62541` from installing its own default logger (which outputs any
-/// logs to stdout/stderr directly).
+/// logs to stdout/stderr directly). Use [`set_log_handler()`] to redirect messages elsewhere.
 ///
 /// Note that this leaks memory unless the returned pointer is assigned to `UA_ClientConfig` (and/or
 /// `UA_Client` in turn), eventually calling `UA_Logger::clear()` with this `UA_Logger` instance, or
 /// to `UA_ServerConfig` (and/or `UA_Server` in turn), respectively.
 pub(crate) fn logger() -> *mut UA_Logger {
-    unsafe extern "C" fn log_c(
-        _log_context: *mut c_void,
-        level: UA_LogLevel,
-        _category: UA_LogCategory,
-        msg: *const c_char,
-        args: open62541_sys::va_list_,
-    ) {
-        let Some(msg) = format_message(msg, args) else {
-            log::error!(target: LOG_TARGET, "Unknown log message");
-            return;
-        };
+    log::debug!("Creating `log` logger");
 
-        let msg = CStr::from_bytes_with_nul(&msg)
-            .unwrap_or(c"Invalid log message")
-            .to_string_lossy();
-
-        if level == UA_LogLevel::UA_LOGLEVEL_FATAL {
-            // Without fatal level in `log`, fall back to error.
-            log::error!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_ERROR {
-            log::error!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_WARNING {
-            log::warn!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_INFO {
-            log::info!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_DEBUG {
-            log::debug!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_TRACE {
-            log::trace!(target: LOG_TARGET, "{msg}");
-        } else {
-            // Handle unexpected level by escalating to error.
-            log::error!(target: LOG_TARGET, "{msg}");
+    let context = Box::<LoggerContext>::default();
+
+    // Create logger configuration. We leak the memory which is cleaned up eventually when `clear()`
+    // is called (which is `clear_c()` below).
+    Box::leak(Box::new(UA_Logger {
+        log: Some(log_c),
+        context: Box::into_raw(context).cast::<c_void>(),
+        clear: Some(clear_c),
+    }))
+}
+
+/// Sets custom handler for log messages.
+///
+/// This replaces any previous handler. Messages are passed to the handler instead of the `log`
+/// crate.
+///
+/// # Safety
+///
+/// The logger must have been created by [`logger()`] and not been cleared yet. There must be no
+/// concurrent calls into the logger, i.e. the configuration that owns it must not be in use.
+pub(crate) unsafe fn set_log_handler(logger: *mut UA_Logger, handler: LogHandler) {
+    // SAFETY: Pointer is valid, and we have exclusive access.
+    let logger = unsafe { &mut *logger };
+    // PANIC: We only ever install our own logger.
+    assert!(logger.log == Some(log_c), "logger should be created by us");
+    // SAFETY: Context is the result of `Box::into_raw()` in `logger()`.
+    let context = unsafe { &mut *logger.context.cast::<LoggerContext>() };
+    context.handler = Some(handler);
+}
+
+unsafe extern "C" fn log_c(
+    log_context: *mut c_void,
+    level: UA_LogLevel,
+    category: UA_LogCategory,
+    msg: *const c_char,
+    args: open62541_sys::va_list_,
+) {
+    let Some(msg) = format_message(msg, args) else {
+        log::error!(target: LOG_TARGET, "Unknown log message");
+        return;
+    };
+
+    let msg = CStr::from_bytes_with_nul(&msg)
+        .unwrap_or(c"Invalid log message")
+        .to_string_lossy();
+
+    // SAFETY: Context is the result of `Box::into_raw()` in `logger()` and only released when the
+    // logger is cleared. We only ever take shared references here: messages may be logged from
+    // multiple threads.
+    let context = unsafe { &*log_context.cast::<LoggerContext>() };
+
+    if let Some(handler) = &context.handler {
+        let level = ua::LogLevel::from_raw(level);
+        let category = ua::LogCategory::from_raw(category);
+        // We must not unwind into the C code that has called us.
+        if let Err(err) = catch_unwind(AssertUnwindSafe(|| handler(level, category, &msg))) {
+            log::error!("Log handler panicked: {err:?}");
         }
+        return;
     }
 
-    unsafe extern "C" fn clear_c(logger: *mut UA_Logger) {
-        log::debug!("Clearing `log` logger");
+    // Forward to the `log` crate, with the category as target.
 
-        // This consumes the `UA_Logger` structure itself, invalidating the pointer `config.logging`
-        // and thereby releasing all allocated resources.
-        //
-        // This is in line with the contract that `config.logging` may not be used anymore after its
-        // `clear()` method has been called.
-        let logger = unsafe { Box::from_raw(logger) };
+    let level = if level == UA_LogLevel::UA_LOGLEVEL_FATAL {
+        // Without fatal level in `log`, fall back to error.
+        log::Level::Error
+    } else if level == UA_LogLevel::UA_LOGLEVEL_ERROR {
+        log::Level::Error
+    } else if level == UA_LogLevel::UA_LOGLEVEL_WARNING {
+        log::Level::Warn
+    } else if level == UA_LogLevel::UA_LOGLEVEL_INFO {
+        log::Level::Info
+    } else if level == UA_LogLevel::UA_LOGLEVEL_DEBUG {
+        log::Level::Debug
+    } else if level == UA_LogLevel::UA_LOGLEVEL_TRACE {
+        log::Level::Trace
+    } else {
+        // Handle unexpected level by escalating to error.
+        log::Level::Error
+    };
 
-        // Run some sanity checks. We should only ever be called on our own data structure.
-        debug_assert!(logger.log == Some(log_c));
-        debug_assert!(logger.clear == Some(clear_c));
-
-        // As long as we do not carry data, there is nothing to clean up here.
-        debug_assert!(logger.context.is_null());
+    log::log!(target: log_target(&category), level, "{msg}");
+}
 
-        // Dropping the boxed logger cleans up allocated memory.
-        drop(logger);
+/// Gets target for log messages of category.
+///
+/// Targets are nested below [`LOG_TARGET`], so that filters on the latter apply to all categories.
+fn log_target(category: &UA_LogCategory) -> &'static str {
+    match *category {
+        UA_LogCategory::UA_LOGCATEGORY_NETWORK => "open62541_sys::network",
+        UA_LogCategory::UA_LOGCATEGORY_SECURECHANNEL => "open62541_sys::securechannel",
+        UA_LogCategory::UA_LOGCATEGORY_SESSION => "open62541_sys::session",
+        UA_LogCategory::UA_LOGCATEGORY_SERVER => "open62541_sys::server",
+        UA_LogCategory::UA_LOGCATEGORY_CLIENT => "open62541_sys::client",
+        UA_LogCategory::UA_LOGCATEGORY_USERLAND => "open62541_sys::userland",
+        UA_LogCategory::UA_LOGCATEGORY_SECURITYPOLICY => "open62541_sys::securitypolicy",
+        UA_LogCategory::UA_LOGCATEGORY_EVENTLOOP => "open62541_sys::eventloop",
+        UA_LogCategory::UA_LOGCATEGORY_PUBSUB => "open62541_sys::pubsub",
+        UA_LogCategory::UA_LOGCATEGORY_DISCOVERY => "open62541_sys::discovery",
+        // Handle unexpected category by using common target.
+        _ => LOG_TARGET,
     }
+}
 
-    log::debug!("Creating `log` logger");
+unsafe extern "C" fn clear_c(logger: *mut UA_Logger) {
+    log::debug!("Clearing `log` logger");
 
-    // Create logger configuration. We leak the memory which is cleaned up eventually when `clear()`
-    // is called (which is `clear_c()` above).
-    Box::leak(Box::new(UA_Logger {
-        log: Some(log_c),
-        context: ptr::null_mut(),
-        clear: Some(clear_c),
-    }))
+    // This consumes the `UA_Logger` structure itself, invalidating the pointer `config.logging`
+    // and thereby releasing all allocated resources.
+    //
+    // This is in line with the contract that `config.logging` may not be used anymore after its
+    // `clear()` method has been called.
+    let logger = unsafe { Box::from_raw(logger) };
+
+    // Run some sanity checks. We should only ever be called on our own data structure.
+    debug_assert!(logger.log == Some(log_c));
+    debug_assert!(logger.clear == Some(clear_c));
+
+    // SAFETY: Context is the result of `Box::into_raw()` in `logger()` and is released only once.
+    drop(unsafe { Box::from_raw(logger.context.cast::<LoggerContext>()) });
+
+    // Dropping the boxed logger cleans up allocated memory.
+    drop(logger);
 }
 
 /// Initial buffer size when formatting messages.
@@ -150,3 +224,48 @@ fn format_message(msg: *const c_char, args: open62541_sys::va_list_) -> Option<V
 
     Some(msg_buffer)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, PoisonError};
+
+    use crate::test_server::TestServer;
+
+    /// Records forwarded by the `log` crate, as pairs of target and message.
+    static RECORDS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+    struct CapturingLogger;
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.target().starts_with("open62541_sys")
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            if self.enabled(record.metadata()) {
+                RECORDS
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push((record.target().to_owned(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn forward_to_log() {
+        // This is the only test to set the global logger.
+        log::set_logger(&CapturingLogger).expect("logger should not be set yet");
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let server = TestServer::start();
+        let _client = server.connect();
+
+        // Connecting logs messages, with their category as target.
+        let records = RECORDS.lock().unwrap_or_else(PoisonError::into_inner);
+        assert!(records
+            .iter()
+            .any(|(target, _)| target == "open62541_sys::client"));
+    }
+}
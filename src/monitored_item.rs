@@ -0,0 +1,230 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError, Weak,
+    },
+    time::Duration,
+};
+
+use open62541_sys::{UA_Client_MonitoredItems_delete, UA_Client_MonitoredItems_modify};
+
+use crate::{
+    subscription_registry::MonitoredItemEntry, ua, DataType as _, Error, Result,
+    ServiceResponse as _,
+};
+
+/// Monitored item (with callback-based API).
+///
+/// This is returned by [`Subscription::create_monitored_item()`]. The parameters of the monitored
+/// item may be changed while it is active. When the monitored item is dropped, it is deleted on the
+/// server and its callback is released.
+///
+/// [`Subscription::create_monitored_item()`]: crate::Subscription::create_monitored_item
+#[derive(Debug)]
+pub struct MonitoredItem {
+    client: Weak<ua::Client>,
+    /// Set when the subscription has been deleted, along with all its monitored items.
+    subscription_deleted: Arc<AtomicBool>,
+    monitored_item: Arc<MonitoredItemEntry>,
+    /// Current parameters, as revised by the server.
+    parameters: Mutex<ua::MonitoringParameters>,
+}
+
+impl MonitoredItem {
+    pub(crate) fn new(
+        client: &Arc<ua::Client>,
+        subscription_deleted: &Arc<AtomicBool>,
+        monitored_item: Arc<MonitoredItemEntry>,
+        request: &ua::MonitoredItemCreateRequest,
+        result: &ua::MonitoredItemCreateResult,
+    ) -> Self {
+        let parameters = request
+            .requested_parameters()
+            .clone()
+            .with_sampling_interval(result.revised_sampling_interval())
+            .with_queue_size(result.revised_queue_size());
+
+        Self {
+            client: Arc::downgrade(client),
+            subscription_deleted: Arc::clone(subscription_deleted),
+            monitored_item,
+            parameters: Mutex::new(parameters),
+        }
+    }
+
+    /// Gets subscription ID.
+    ///
+    /// The ID changes when the subscription is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn subscription_id(&self) -> ua::SubscriptionId {
+        self.monitored_item.subscription_id()
+    }
+
+    /// Gets monitored item ID.
+    ///
+    /// The ID changes when the monitored item is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.monitored_item.monitored_item_id()
+    }
+
+    /// Sets sampling interval.
+    ///
+    /// The server may revise the requested interval, e.g. to match the rates it supports.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client has been dropped, or the server rejects the change.
+    pub fn set_sampling_interval(&self, sampling_interval: Duration) -> Result<()> {
+        // `UA_MonitoringParameters` expects the sampling interval given in milliseconds.
+        let sampling_interval = sampling_interval.as_secs_f64() * 1000.0;
+
+        self.modify(|parameters| parameters.with_sampling_interval(sampling_interval))
+    }
+
+    /// Sets size of queue for notifications.
+    ///
+    /// The server may revise the requested size.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client has been dropped, or the server rejects the change.
+    pub fn set_queue_size(&self, queue_size: u32) -> Result<()> {
+        self.modify(|parameters| parameters.with_queue_size(queue_size))
+    }
+
+    fn modify(
+        &self,
+        f: impl FnOnce(ua::MonitoringParameters) -> ua::MonitoringParameters,
+    ) -> Result<()> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        // Hold lock until we are done to not lose concurrent modifications.
+        let mut parameters = self
+            .parameters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // Modifying replaces all parameters, so we must pass the unchanged values as well.
+        let requested_parameters = f(parameters.clone());
+
+        let request = ua::ModifyMonitoredItemsRequest::init()
+            .with_subscription_id(self.monitored_item.subscription_id())
+            .with_timestamps_to_return(&ua::TimestampsToReturn::BOTH)
+            .with_items_to_modify(&[ua::MonitoredItemModifyRequest::init()
+                .with_monitored_item_id(self.monitored_item.monitored_item_id())
+                .with_requested_parameters(&requested_parameters)]);
+
+        let response = modify_monitored_items(&client, &request)?;
+
+        // We expect exactly one result for the monitored item we requested above.
+        let result = response
+            .results()
+            .and_then(|results| results.iter().next().cloned())
+            .ok_or(Error::internal("modify should return result"))?;
+        Error::verify_good(&result.status_code())?;
+
+        // Re-create the monitored item with the requested parameters when reconnecting.
+        self.monitored_item
+            .set_requested_parameters(&requested_parameters);
+
+        *parameters = requested_parameters
+            .with_sampling_interval(result.revised_sampling_interval())
+            .with_queue_size(result.revised_queue_size());
+
+        Ok(())
+    }
+}
+
+impl Drop for MonitoredItem {
+    fn drop(&mut self) {
+        // When the client has been dropped already, all subscriptions (and their monitored items)
+        // have been cleaned up by `UA_Client_delete()`, including our callback.
+        let Some(client) = self.client.upgrade() else {
+            return;
+        };
+        // Do not re-create the monitored item when reconnecting.
+        let (subscription_id, monitored_item_id) = self.monitored_item.delete();
+        // When the subscription has been deleted, the monitored item is gone as well.
+        if self.subscription_deleted.load(Ordering::Acquire) {
+            return;
+        }
+
+        // This also releases the callback.
+        delete_monitored_item(&client, subscription_id, monitored_item_id);
+    }
+}
+
+pub(crate) fn delete_monitored_item(
+    client: &ua::Client,
+    subscription_id: ua::SubscriptionId,
+    monitored_item_id: ua::MonitoredItemId,
+) {
+    let request = ua::DeleteMonitoredItemsRequest::init()
+        .with_subscription_id(subscription_id)
+        .with_monitored_item_ids(&[monitored_item_id]);
+
+    let result = delete_monitored_items(client, &request).and_then(|response| {
+        // We expect exactly one result for the monitored item we requested above.
+        let status_code = response
+            .results()
+            .and_then(|results| results.iter().next().cloned())
+            .ok_or(Error::internal("delete should return result"))?;
+        Error::verify_good(&status_code)
+    });
+    if let Err(error) = result {
+        log::warn!("Error when deleting monitored item: {error}");
+    }
+}
+
+fn modify_monitored_items(
+    client: &ua::Client,
+    request: &ua::ModifyMonitoredItemsRequest,
+) -> Result<ua::ModifyMonitoredItemsResponse> {
+    log::debug!("Calling MonitoredItems_modify()");
+
+    let response = unsafe {
+        UA_Client_MonitoredItems_modify(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            // SAFETY: `UA_Client_MonitoredItems_modify()` expects the request passed by value but
+            // does not take ownership.
+            ua::ModifyMonitoredItemsRequest::to_raw_copy(request),
+        )
+    };
+    // SAFETY: We take ownership of the returned response.
+    let response = unsafe { ua::ModifyMonitoredItemsResponse::from_raw(response) };
+    Error::verify_good(&response.service_result())?;
+
+    Ok(response)
+}
+
+fn delete_monitored_items(
+    client: &ua::Client,
+    request: &ua::DeleteMonitoredItemsRequest,
+) -> Result<ua::DeleteMonitoredItemsResponse> {
+    log::debug!("Calling MonitoredItems_delete()");
+
+    let response = unsafe {
+        UA_Client_MonitoredItems_delete(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            // SAFETY: `UA_Client_MonitoredItems_delete()` expects the request passed by value but
+            // does not take ownership.
+            ua::DeleteMonitoredItemsRequest::to_raw_copy(request),
+        )
+    };
+    // SAFETY: We take ownership of the returned response.
+    let response = unsafe { ua::DeleteMonitoredItemsResponse::from_raw(response) };
+    Error::verify_good(&response.service_result())?;
+
+    Ok(response)
+}
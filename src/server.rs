@@ -2,22 +2,27 @@ mod data_source;
 mod method_callback;
 mod node_context;
 mod node_types;
+mod periodic_value;
+mod value_callback;
 
 use std::{
     ffi::{c_void, CString},
     ptr,
     sync::Arc,
+    time::Duration,
 };
 
 use open62541_sys::{
-    UA_NodeId, UA_Server, UA_ServerConfig, UA_Server_addDataSourceVariableNode,
+    UA_DataTypeArray, UA_NodeId, UA_Server, UA_ServerConfig, UA_Server_addDataSourceVariableNode,
     UA_Server_addMethodNodeEx, UA_Server_addNamespace, UA_Server_addReference, UA_Server_browse,
     UA_Server_browseNext, UA_Server_browseRecursive, UA_Server_browseSimplifiedBrowsePath,
     UA_Server_createEvent, UA_Server_deleteNode, UA_Server_deleteReference,
-    UA_Server_getNamespaceByIndex, UA_Server_getNamespaceByName, UA_Server_read,
-    UA_Server_readObjectProperty, UA_Server_runUntilInterrupt,
-    UA_Server_translateBrowsePathToNodeIds, UA_Server_triggerEvent, UA_Server_writeObjectProperty,
-    __UA_Server_addNode, __UA_Server_write, UA_STATUSCODE_BADNOTFOUND,
+    UA_Server_getNamespaceByIndex, UA_Server_getNamespaceByName, UA_Server_getNodeContext,
+    UA_Server_read, UA_Server_readObjectProperty, UA_Server_runUntilInterrupt,
+    UA_Server_run_iterate, UA_Server_run_shutdown, UA_Server_run_startup, UA_Server_setNodeContext,
+    UA_Server_setVariableNode_valueCallback, UA_Server_translateBrowsePathToNodeIds,
+    UA_Server_triggerEvent, UA_Server_writeObjectProperty, __UA_Server_addNode, __UA_Server_write,
+    UA_STATUSCODE_BADNOTFOUND,
 };
 
 use crate::{ua, Attribute, Attributes, BrowseResult, DataType, DataValue, Error, Result};
@@ -25,11 +30,12 @@ use crate::{ua, Attribute, Attributes, BrowseResult, DataType, DataValue, Error,
 pub(crate) use self::node_context::NodeContext;
 pub use self::{
     data_source::{
-        DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
+        DataSource, DataSourceError, DataSourceFn, DataSourceReadContext, DataSourceResult,
         DataSourceWriteContext,
     },
     method_callback::{
-        MethodCallback, MethodCallbackContext, MethodCallbackError, MethodCallbackResult,
+        MethodCallback, MethodCallbackContext, MethodCallbackError, MethodCallbackFn,
+        MethodCallbackResult,
     },
     node_types::{MethodNode, Node, ObjectNode, VariableNode},
 };
@@ -82,6 +88,126 @@ impl ServerBuilder {
         self
     }
 
+    /// Sets application URI of server.
+    ///
+    /// This is also the URI of namespace 1. When the server uses a certificate, the URI must match
+    /// the URI in the subject alternative name of the certificate.
+    ///
+    /// Note: The bundled build of open62541 does not include encryption support. This means that the
+    /// server cannot be configured with certificates, and offers only endpoints with
+    /// [`ua::MessageSecurityMode::NONE`] at the moment.
+    ///
+    /// # Panics
+    ///
+    /// The URI must not contain any NUL bytes.
+    #[must_use]
+    pub fn application_uri(mut self, application_uri: &str) -> Self {
+        ua::String::new(application_uri)
+            .expect("application URI should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().applicationDescription.applicationUri);
+        self
+    }
+
+    /// Sets application name of server (with locale).
+    ///
+    /// Clients see this name in the application description of the server, e.g. when calling
+    /// [`Client::find_servers()`](crate::Client::find_servers).
+    ///
+    /// # Panics
+    ///
+    /// The strings must not contain any NUL bytes.
+    #[must_use]
+    pub fn application_name(mut self, locale: &str, application_name: &str) -> Self {
+        ua::LocalizedText::new(locale, application_name)
+            .expect("application name should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().applicationDescription.applicationName);
+        self
+    }
+
+    /// Sets product name in build info of server.
+    ///
+    /// # Panics
+    ///
+    /// The name must not contain any NUL bytes.
+    #[must_use]
+    pub fn product_name(mut self, product_name: &str) -> Self {
+        ua::String::new(product_name)
+            .expect("product name should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().buildInfo.productName);
+        self
+    }
+
+    /// Sets software version in build info of server.
+    ///
+    /// # Panics
+    ///
+    /// The version must not contain any NUL bytes.
+    #[must_use]
+    pub fn software_version(mut self, software_version: &str) -> Self {
+        ua::String::new(software_version)
+            .expect("software version should not contain NUL bytes")
+            .move_into_raw(&mut self.config_mut().buildInfo.softwareVersion);
+        self
+    }
+
+    /// Sets maximum number of sessions.
+    ///
+    /// Clients that attempt to create more sessions receive the status code
+    /// [`ua::StatusCode::BADTOOMANYSESSIONS`].
+    #[must_use]
+    pub fn max_sessions(mut self, max_sessions: u16) -> Self {
+        self.config_mut().maxSessions = max_sessions;
+        self
+    }
+
+    /// Sets maximum number of subscriptions.
+    ///
+    /// This limits the number of subscriptions across all sessions. Clients that attempt to create
+    /// more subscriptions receive the status code [`ua::StatusCode::BADTOOMANYSUBSCRIPTIONS`].
+    #[must_use]
+    pub fn max_subscriptions(mut self, max_subscriptions: u32) -> Self {
+        self.config_mut().maxSubscriptions = max_subscriptions;
+        self
+    }
+
+    /// Sets custom data types.
+    ///
+    /// The server uses these to encode and decode values of data types that are not defined in
+    /// namespace 0. This replaces any custom data types set before.
+    ///
+    /// # Safety
+    ///
+    /// The data type definitions must be valid, e.g. as generated by the `nodeset_compiler` of
+    /// `open62541`. The `cleanup` flag of the array must not be set.
+    #[must_use]
+    pub unsafe fn custom_data_types(
+        mut self,
+        custom_data_types: &'static UA_DataTypeArray,
+    ) -> Self {
+        self.config_mut().customDataTypes = custom_data_types;
+        self
+    }
+
+    /// Sets maximum number of nodes per read request.
+    ///
+    /// Clients that read more nodes in a single request receive the status code
+    /// [`ua::StatusCode::BADTOOMANYOPERATIONS`]. Use `0` to not limit the number of nodes.
+    #[must_use]
+    pub fn max_nodes_per_read(mut self, max_nodes_per_read: u32) -> Self {
+        self.config_mut().maxNodesPerRead = max_nodes_per_read;
+        self
+    }
+
+    /// Sets maximum number of nodes per write request.
+    ///
+    /// Clients that write more nodes in a single request receive the status code
+    /// [`ua::StatusCode::BADTOOMANYOPERATIONS`]. Use `0` to not limit the number of nodes.
+    #[must_use]
+    pub fn max_nodes_per_write(mut self, max_nodes_per_write: u32) -> Self {
+        self.config_mut().maxNodesPerWrite = max_nodes_per_write;
+        self
+    }
+
     /// Builds OPC UA server.
     #[must_use]
     pub fn build(mut self) -> (Server, ServerRunner) {
@@ -166,6 +292,38 @@ impl Server {
         ServerBuilder::default().build()
     }
 
+    /// Gets raw pointer to underlying `UA_Server`.
+    ///
+    /// This allows calling functions from [`open62541_sys`] that are not (yet) wrapped by this
+    /// crate. The server remains owned by `self` and the pointer is valid only as long as `self`, any
+    /// of its clones, or the [`ServerRunner`] is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::ServerBuilder;
+    /// use open62541_sys::UA_Server_getConfig;
+    ///
+    /// let (server, _runner) = ServerBuilder::default().build();
+    /// // The runner has not been started yet, so the configuration may be inspected.
+    /// let config = unsafe { &*UA_Server_getConfig(server.as_raw()) };
+    /// println!("Maximum number of sessions: {}", config.maxSessions);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The server must not be freed, e.g. with `UA_Server_delete()`, and its configuration must not
+    /// be cleared, or changed while the server is running. Do not run or shut down the server behind
+    /// the wrapper's back, use [`ServerRunner`] instead. Node contexts are managed by this crate: do
+    /// not replace them with `UA_Server_setNodeContext()`, and leave the node lifecycle in the
+    /// configuration untouched. Functions that are not marked `UA_THREADSAFE` must not be called
+    /// while the server may be running, e.g. while [`ServerRunner`] runs in another thread.
+    #[must_use]
+    pub unsafe fn as_raw(&self) -> *mut UA_Server {
+        // SAFETY: Caller upholds invariants of `ua::Server`.
+        unsafe { self.0.as_ptr() }.cast_mut()
+    }
+
     /// Adds a new namespace to the server. Returns the index of the new namespace.
     ///
     /// If the namespace already exists, it is not re-created but its index is returned.
@@ -305,6 +463,37 @@ impl Server {
         Some(found_uri)
     }
 
+    /// Gets all namespaces.
+    ///
+    /// This returns the namespace URIs, ordered by their namespace index. This matches the value of
+    /// the `NamespaceArray` variable of the server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ServerBuilder, ua};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let ns_index = server.add_namespace("http://hmi-project.com/UA/");
+    ///
+    /// let namespaces = server.namespace_array();
+    /// let ns_uri = ua::String::new("http://hmi-project.com/UA/").unwrap();
+    /// assert_eq!(namespaces.get(usize::from(ns_index)), Some(&ns_uri));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn namespace_array(&self) -> Vec<ua::String> {
+        // Namespace indices are assigned without gaps, the first unknown index ends the list.
+        (0..=u16::MAX)
+            .map_while(|namespace_index| self.get_namespace_by_index(namespace_index))
+            .collect()
+    }
+
     /// Adds node to address space.
     ///
     /// This returns the node ID that was actually inserted (when no explicit requested new node ID
@@ -407,7 +596,8 @@ impl Server {
     ///
     /// # Errors
     ///
-    /// This fails when the node cannot be added.
+    /// This fails when the node cannot be added. When the initial value does not match the value
+    /// rank of the node, this fails with [`ua::StatusCode::BADTYPEMISMATCH`].
     pub fn add_variable_node(&self, variable_node: VariableNode) -> Result<ua::NodeId> {
         let VariableNode {
             requested_new_node_id,
@@ -418,6 +608,10 @@ impl Server {
             attributes,
         } = variable_node;
 
+        if !value_matches_rank(attributes.value(), attributes.value_rank()) {
+            return Err(Error::Server(ua::StatusCode::BADTYPEMISMATCH));
+        }
+
         let requested_new_node_id = requested_new_node_id.unwrap_or(ua::NodeId::null());
 
         // This out variable must be initialized without memory allocation because the call below
@@ -506,6 +700,173 @@ impl Server {
         Ok(out_new_node_id)
     }
 
+    /// Sets callback for writes to variable node.
+    ///
+    /// The callback is called with the new value after it has been written to the variable, e.g.
+    /// when a client writes the value. This can be used to forward writes to the underlying
+    /// hardware. It lives as long as the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ServerBuilder, VariableNode, ua};
+    /// use open62541_sys::UA_NS0ID_OBJECTSFOLDER;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// let node_id = server.add_variable_node(VariableNode::new(
+    ///     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+    ///     ua::QualifiedName::new(1, "Setpoint"),
+    ///     &ua::Variant::scalar(ua::Double::new(20.0)),
+    /// ))?;
+    ///
+    /// server.set_write_callback(&node_id, |value| {
+    ///     println!("New setpoint: {:?}", value.value());
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or is not a variable node (with status code
+    /// [`ua::StatusCode::BADNODECLASSINVALID`]). Only variables added with
+    /// [`add_variable_node()`](Self::add_variable_node) (or [`add_node()`](Self::add_node)) may be
+    /// given a callback, and only once: nodes that carry a data source or a callback already are
+    /// rejected (with status code [`ua::StatusCode::BADINVALIDSTATE`]).
+    pub fn set_write_callback(
+        &self,
+        node_id: &ua::NodeId,
+        callback: impl FnMut(&ua::DataValue) + Send + 'static,
+    ) -> Result<()> {
+        let node_class = self
+            .read_attribute(node_id, ua::AttributeId::NODECLASS_T)?
+            .into_value();
+        if node_class != ua::NodeClass::VARIABLE {
+            return Err(Error::Server(ua::StatusCode::BADNODECLASSINVALID));
+        }
+
+        let mut existing_context = ptr::null_mut();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_getNodeContext(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: `UA_Server_getNodeContext()` expects the node ID passed by value but does
+                // not take ownership.
+                ua::NodeId::to_raw_copy(node_id),
+                &mut existing_context,
+            )
+        });
+        Error::verify_good(&status_code)?;
+        if !existing_context.is_null() {
+            // We must not overwrite (and thereby leak) the context of data sources or others.
+            return Err(Error::Server(ua::StatusCode::BADINVALIDSTATE));
+        }
+
+        // SAFETY: We store `node_context` inside the node to keep `callback` alive.
+        let (value_callback, node_context) =
+            unsafe { value_callback::wrap_write_callback(callback) };
+        let node_context = node_context.leak();
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_setNodeContext(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: `UA_Server_setNodeContext()` expects the node ID passed by value but does
+                // not take ownership.
+                ua::NodeId::to_raw_copy(node_id),
+                node_context,
+            )
+        });
+        if let Err(error) = Error::verify_good(&status_code) {
+            // SAFETY: The node context has not been stored, we still own it.
+            drop(unsafe { NodeContext::consume(node_context) });
+            return Err(error);
+        }
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_setVariableNode_valueCallback(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: `UA_Server_setVariableNode_valueCallback()` expects the node ID passed by
+                // value but does not take ownership.
+                ua::NodeId::to_raw_copy(node_id),
+                value_callback,
+            )
+        });
+        if let Err(error) = Error::verify_good(&status_code) {
+            // Remove node context again before releasing it. The callback has not been set, so it
+            // will never be called.
+            unsafe {
+                UA_Server_setNodeContext(
+                    // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                    self.0.as_ptr().cast_mut(),
+                    ua::NodeId::to_raw_copy(node_id),
+                    ptr::null_mut(),
+                );
+            }
+            // SAFETY: The node context is no longer referenced by the node.
+            drop(unsafe { NodeContext::consume(node_context) });
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Updates node value periodically.
+    ///
+    /// This registers a repeated callback with the server's event loop: every `interval`, the value
+    /// returned by `generator` is written to the variable node. Clients that subscribe to the node
+    /// are notified of the new values as usual. The generator is run on the thread that runs the
+    /// server (see [`ServerRunner`]), it should not block.
+    ///
+    /// The callback is removed when the node is deleted, or when the server is dropped. Resources
+    /// held by `generator` are only released along with the server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ServerBuilder, VariableNode, ua};
+    /// use open62541_sys::UA_NS0ID_OBJECTSFOLDER;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// let node_id = server.add_variable_node(VariableNode::new(
+    ///     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+    ///     ua::QualifiedName::new(1, "Counter"),
+    ///     &ua::Variant::scalar(ua::UInt32::new(0)),
+    /// ))?;
+    ///
+    /// let mut counter = 0;
+    /// server.set_periodic_value(&node_id, Duration::from_millis(100), move || {
+    ///     counter += 1;
+    ///     ua::Variant::scalar(ua::UInt32::new(counter))
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or is not a variable node (with status code
+    /// [`ua::StatusCode::BADNODECLASSINVALID`]), or when the interval is zero.
+    pub fn set_periodic_value(
+        &self,
+        node_id: &ua::NodeId,
+        interval: Duration,
+        generator: impl FnMut() -> ua::Variant + Send + 'static,
+    ) -> Result<()> {
+        let node_class = self
+            .read_attribute(node_id, ua::AttributeId::NODECLASS_T)?
+            .into_value();
+        if node_class != ua::NodeClass::VARIABLE {
+            return Err(Error::Server(ua::StatusCode::BADNODECLASSINVALID));
+        }
+
+        periodic_value::add_periodic_value(&self.0, node_id, interval, generator)
+    }
+
     /// Adds method node to address space.
     ///
     /// This returns the node ID that was actually inserted (when no explicit requested new node ID
@@ -592,12 +953,15 @@ impl Server {
 
     /// Deletes node from address space.
     ///
-    /// This also deletes all references leading to the node.
+    /// The node's own references are always removed along with it. When `delete_references` is
+    /// set, the references that point to the node from other nodes are deleted too. Otherwise they
+    /// remain in place and dangle: browsing skips them, but they lead to any node that is added
+    /// later with the same node ID.
     ///
     /// # Errors
     ///
     /// This fails when the node cannot be deleted.
-    pub fn delete_node(&self, node_id: &ua::NodeId) -> Result<()> {
+    pub fn delete_node(&self, node_id: &ua::NodeId, delete_references: bool) -> Result<()> {
         let status_code = ua::StatusCode::new(unsafe {
             UA_Server_deleteNode(
                 // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
@@ -605,8 +969,7 @@ impl Server {
                 // SAFETY: `UA_Server_deleteNode()` expects the node ID passed by value but does not
                 // take ownership.
                 ua::NodeId::to_raw_copy(node_id),
-                // Delete all references to this node.
-                true,
+                delete_references,
             )
         });
         Error::verify_good(&status_code)
@@ -1320,6 +1683,92 @@ impl ServerRunner {
         });
         Error::verify_good(&status_code)
     }
+
+    /// Runs the server until cancelled.
+    ///
+    /// This runs the server's event loop on the current thread. The function `is_cancelled` is
+    /// called before each iteration of the loop: when it returns `true`, the server is shut down
+    /// cleanly and the method returns. Iterations wait at most 200 ms for network activity, so the
+    /// cancellation is noticed within that time. Unlike [`run()`](Self::run), this does not install
+    /// any signal handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use open62541::ServerBuilder;
+    /// use std::{
+    ///     sync::{
+    ///         atomic::{AtomicBool, Ordering},
+    ///         Arc,
+    ///     },
+    ///     thread,
+    /// };
+    ///
+    /// # fn main() -> open62541::Result<()> {
+    /// let (server, runner) = ServerBuilder::default().port(4840).build();
+    ///
+    /// let cancelled = Arc::new(AtomicBool::new(false));
+    /// let handle = thread::spawn({
+    ///     let cancelled = Arc::clone(&cancelled);
+    ///     move || runner.run_until_cancelled(|| cancelled.load(Ordering::Relaxed))
+    /// });
+    ///
+    /// // Interact with `server` here.
+    ///
+    /// cancelled.store(true, Ordering::Relaxed);
+    /// handle.join().expect("server thread should not panic")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the server cannot be started or shut down.
+    pub fn run_until_cancelled(self, mut is_cancelled: impl FnMut() -> bool) -> Result<()> {
+        // SAFETY: Cast to `mut` pointer. Functions are not marked `UA_THREADSAFE` but we make sure
+        // that they can only be invoked from a single thread (ownership of `ServerRunner`), as in
+        // `run()` above.
+        let server = unsafe { self.0.as_ptr().cast_mut() };
+
+        let status_code = ua::StatusCode::new(unsafe { UA_Server_run_startup(server) });
+        Error::verify_good(&status_code)?;
+
+        while !is_cancelled() {
+            // The returned value is the time until the next scheduled callback. We do not need it:
+            // when waiting internally, the event loop already takes this into account.
+            let _unused = unsafe { UA_Server_run_iterate(server, true) };
+        }
+
+        let status_code = ua::StatusCode::new(unsafe { UA_Server_run_shutdown(server) });
+        Error::verify_good(&status_code)
+    }
+}
+
+/// Checks if value matches value rank of variable.
+///
+/// Empty values match every value rank.
+fn value_matches_rank(value: &ua::Variant, value_rank: i32) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+    // One-dimensional arrays usually have no explicit array dimensions.
+    let dimensions = if value.is_scalar() {
+        0
+    } else {
+        value.array_dimensions().len().max(1)
+    };
+    match value_rank {
+        // Scalar or one dimension.
+        -3 => dimensions <= 1,
+        // Any.
+        -2 => true,
+        // Scalar.
+        -1 => dimensions == 0,
+        // One or more dimensions.
+        0 => dimensions >= 1,
+        // Exactly this number of dimensions.
+        _ => usize::try_from(value_rank).is_ok_and(|value_rank| value_rank == dimensions),
+    }
 }
 
 /// Converts [`ua::BrowseResult`] to our public result type.
@@ -1332,5 +1781,336 @@ fn to_browse_result(result: &ua::BrowseResult) -> BrowseResult {
         return Err(Error::internal("browse should return references"));
     };
 
-    Ok((references.into_vec(), result.continuation_point()))
+    Ok((references.to_vec(), result.continuation_point()))
+}
+
+#[cfg(test)]
+mod tests {
+    use open62541_sys::{UA_NS0ID_BASEOBJECTTYPE, UA_NS0ID_OBJECTSFOLDER};
+
+    use crate::{
+        test_server::TestServer, ua, DataSourceFn, Error, ObjectNode, ServerBuilder, VariableNode,
+    };
+
+    #[test]
+    fn run_until_cancelled() {
+        // Port 0 lets the operating system choose an unused (ephemeral) port.
+        let (server, runner) = ServerBuilder::default().port(0).build();
+
+        let mut iterations = 0;
+        runner
+            .run_until_cancelled(|| {
+                iterations += 1;
+                iterations > 3
+            })
+            .expect("server should start and shut down");
+        assert_eq!(iterations, 4);
+
+        // Server stays usable after it has been shut down.
+        assert!(server.add_namespace("urn:lorem") >= 2);
+    }
+
+    #[test]
+    fn application_uri() {
+        let (server, runner) = ServerBuilder::default()
+            .port(0)
+            .application_uri("urn:example:server")
+            .build();
+
+        let mut iterations = 0;
+        runner
+            .run_until_cancelled(|| {
+                iterations += 1;
+                iterations > 1
+            })
+            .expect("server should start and shut down");
+
+        // The application URI is used for namespace 1.
+        assert_eq!(
+            server
+                .get_namespace_by_index(1)
+                .as_ref()
+                .and_then(ua::String::as_str),
+            Some("urn:example:server")
+        );
+    }
+
+    #[test]
+    fn add_variable_node() {
+        let test_server = TestServer::start();
+        let server = test_server.server();
+
+        let node_id = server
+            .add_variable_node(VariableNode::new(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Temperature"),
+                &ua::Variant::scalar(ua::Double::new(21.5)),
+            ))
+            .expect("should add variable node");
+        // Server has assigned node ID.
+        assert_ne!(node_id, ua::NodeId::null());
+
+        // Value is visible to clients.
+        let value = test_server
+            .connect()
+            .read_value(&node_id)
+            .expect("should read value");
+        assert_eq!(
+            value.value().to_scalar::<ua::Double>(),
+            Some(ua::Double::new(21.5))
+        );
+
+        let display_name = server
+            .read_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T)
+            .expect("should read display name")
+            .into_value();
+        assert_eq!(display_name.text().as_str(), Some("Temperature"));
+
+        // Requested node ID is used as-is.
+        let requested_node_id = ua::NodeId::string(1, "Pressure");
+        let node_id = server
+            .add_variable_node(
+                VariableNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, "Pressure"),
+                    &ua::Variant::scalar(ua::Double::new(1.013)),
+                )
+                .with_requested_new_node_id(requested_node_id.clone()),
+            )
+            .expect("should add variable node");
+        assert_eq!(node_id, requested_node_id);
+    }
+
+    #[test]
+    fn add_variable_node_value_rank() {
+        let (server, _) = ServerBuilder::default().build();
+        let objects_folder = || ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER);
+        let value_rank = |node_id: &ua::NodeId| {
+            server
+                .read_attribute(node_id, ua::AttributeId::VALUERANK_T)
+                .expect("should read value rank")
+                .into_value()
+                .value()
+        };
+
+        let scalar = ua::Variant::scalar(ua::Int32::new(1));
+        let node_id = server
+            .add_variable_node(VariableNode::new(
+                objects_folder(),
+                ua::QualifiedName::new(1, "Scalar"),
+                &scalar,
+            ))
+            .expect("should add scalar variable node");
+        assert_eq!(value_rank(&node_id), -1);
+
+        let array = ua::Variant::array(ua::Array::from_slice(&[
+            ua::Int32::new(1),
+            ua::Int32::new(2),
+            ua::Int32::new(3),
+        ]));
+        let node_id = server
+            .add_variable_node(VariableNode::new(
+                objects_folder(),
+                ua::QualifiedName::new(1, "Array"),
+                &array,
+            ))
+            .expect("should add array variable node");
+        assert_eq!(value_rank(&node_id), 1);
+
+        // Initial value must match declared value rank.
+        for (name, value, declared_value_rank) in
+            [("ScalarArray", &scalar, 1), ("ArrayScalar", &array, -1)]
+        {
+            let mut variable_node =
+                VariableNode::new(objects_folder(), ua::QualifiedName::new(1, name), value);
+            variable_node.attributes = variable_node
+                .attributes
+                .with_value_rank(declared_value_rank);
+            let result = server.add_variable_node(variable_node);
+            assert!(matches!(
+                result,
+                Err(Error::Server(status_code)) if status_code == ua::StatusCode::BADTYPEMISMATCH
+            ));
+        }
+    }
+
+    #[test]
+    fn add_object_hierarchy() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let folder_id = server
+            .add_object_node(ObjectNode::folder(
+                ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                ua::QualifiedName::new(1, "Plant"),
+            ))
+            .expect("should add folder node");
+
+        let requested_object_id = ua::NodeId::string(1, "Plant/Boiler");
+        let object_id = server
+            .add_object_node(
+                ObjectNode::new(
+                    folder_id,
+                    ua::QualifiedName::new(1, "Boiler"),
+                    ua::NodeId::ns0(UA_NS0ID_BASEOBJECTTYPE),
+                )
+                .with_requested_new_node_id(requested_object_id.clone()),
+            )
+            .expect("should add object node");
+        assert_eq!(object_id, requested_object_id);
+
+        // Both levels of the hierarchy can be found from the objects folder.
+        let targets = server
+            .browse_simplified_browse_path(
+                &ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                &[
+                    ua::QualifiedName::new(1, "Plant"),
+                    ua::QualifiedName::new(1, "Boiler"),
+                ],
+            )
+            .expect("should find object node");
+        assert_eq!(targets.len(), 1);
+        assert_eq!(
+            targets
+                .as_slice()
+                .first()
+                .map(ua::BrowsePathTarget::target_id),
+            Some(&object_id.into_expanded_node_id())
+        );
+
+        let display_name = server
+            .read_attribute(&requested_object_id, ua::AttributeId::DISPLAYNAME_T)
+            .expect("should read display name")
+            .into_value();
+        assert_eq!(display_name.text().as_str(), Some("Boiler"));
+    }
+
+    #[test]
+    fn add_data_source_variable_node() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let mut counter = 0;
+        let node_id = server
+            .add_data_source_variable_node(
+                VariableNode::new(
+                    ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+                    ua::QualifiedName::new(1, "Counter"),
+                    &ua::Variant::scalar(ua::UInt32::new(0)),
+                ),
+                DataSourceFn::new(move || {
+                    counter += 1;
+                    ua::Variant::scalar(ua::UInt32::new(counter))
+                }),
+            )
+            .expect("should add data source variable node");
+
+        let read_value = || {
+            server
+                .read_attribute(&node_id, ua::AttributeId::VALUE_T)
+                .expect("should read value")
+                .into_value()
+                .to_scalar::<ua::UInt32>()
+        };
+
+        // Value is computed anew on each read.
+        assert_eq!(read_value(), Some(ua::UInt32::new(1)));
+        assert_eq!(read_value(), Some(ua::UInt32::new(2)));
+
+        // Data source without write callback is read-only.
+        let error = server
+            .write_value(&node_id, &ua::Variant::scalar(ua::UInt32::new(10)))
+            .expect_err("should not write value");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNOTSUPPORTED);
+    }
+
+    #[test]
+    fn delete_node_keeps_references() {
+        let (server, _) = ServerBuilder::default().build();
+        let objects_folder = ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER);
+        let node_id = ua::NodeId::string(1, "Dangling");
+        let new_variable_node = |parent_node_id: &ua::NodeId| {
+            VariableNode::new(
+                parent_node_id.clone(),
+                ua::QualifiedName::new(1, "Dangling"),
+                &ua::Variant::scalar(ua::Int32::new(1)),
+            )
+            .with_requested_new_node_id(node_id.clone())
+        };
+        let browse_targets = || {
+            let browse_description = ua::BrowseDescription::default().with_node_id(&objects_folder);
+            let (references, _) = server
+                .browse(1000, &browse_description)
+                .expect("should browse");
+            references
+                .iter()
+                .map(|reference| reference.node_id().node_id().clone())
+                .collect::<Vec<_>>()
+        };
+
+        let variable_node = new_variable_node(&objects_folder);
+        let reference_type_id = variable_node.reference_type_id.clone();
+        server
+            .add_variable_node(variable_node)
+            .expect("should add variable node");
+        assert!(browse_targets().contains(&node_id));
+
+        server
+            .delete_node(&node_id, false)
+            .expect("should delete node");
+        // Browsing skips the reference, its target does not exist anymore.
+        assert!(!browse_targets().contains(&node_id));
+
+        // The reference remains in the parent: it leads to the node again when the node ID is
+        // reused, even when the new node has been added elsewhere.
+        let folder_id = server
+            .add_object_node(ObjectNode::folder(
+                objects_folder.clone(),
+                ua::QualifiedName::new(1, "Elsewhere"),
+            ))
+            .expect("should add folder node");
+        server
+            .add_variable_node(new_variable_node(&folder_id))
+            .expect("should add variable node again");
+        assert!(browse_targets().contains(&node_id));
+
+        // Only now does the reference go away.
+        server
+            .delete_reference(
+                &objects_folder,
+                &reference_type_id,
+                &node_id.clone().into_expanded_node_id(),
+                true,
+                false,
+            )
+            .expect("should delete dangling reference");
+        assert!(!browse_targets().contains(&node_id));
+    }
+
+    #[test]
+    fn add_namespace() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let ns_index = server.add_namespace("urn:lorem");
+        // Adding the same namespace again returns the existing index.
+        assert_eq!(server.add_namespace("urn:lorem"), ns_index);
+        assert_ne!(server.add_namespace("urn:ipsum"), ns_index);
+
+        let namespaces = server.namespace_array();
+        assert_eq!(
+            namespaces.first().and_then(ua::String::as_str),
+            Some("http://opcfoundation.org/UA/")
+        );
+        assert_eq!(
+            namespaces
+                .get(usize::from(ns_index))
+                .and_then(ua::String::as_str),
+            Some("urn:lorem")
+        );
+        assert_eq!(
+            namespaces
+                .iter()
+                .filter(|ns| ns.as_str() == Some("urn:lorem"))
+                .count(),
+            1
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use std::{
     ffi::c_void,
+    fmt,
     panic::{catch_unwind, AssertUnwindSafe},
     ptr::NonNull,
 };
@@ -87,6 +88,95 @@ pub trait DataSource {
     }
 }
 
+/// Data source from closures.
+///
+/// This implements [`DataSource`] by calling `read` whenever a client reads the variable, and
+/// `write` (when given) whenever a client writes to it. Without `write`, the variable is read-only
+/// and writes are rejected with [`ua::StatusCode::BADNOTSUPPORTED`].
+///
+/// # Examples
+///
+/// ```
+/// # use open62541::{DataSourceFn, ServerBuilder, VariableNode, ua};
+/// use open62541_sys::UA_NS0ID_OBJECTSFOLDER;
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let (server, _) = ServerBuilder::default().build();
+/// #
+/// let mut counter = 0;
+/// let data_source = DataSourceFn::new(move || {
+///     counter += 1;
+///     ua::Variant::scalar(ua::UInt32::new(counter))
+/// });
+///
+/// let node_id = server.add_data_source_variable_node(
+///     VariableNode::new(
+///         ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+///         ua::QualifiedName::new(1, "Counter"),
+///         &ua::Variant::scalar(ua::UInt32::new(0)),
+///     ),
+///     data_source,
+/// )?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct DataSourceFn {
+    read: Box<dyn FnMut() -> ua::Variant>,
+    write: Option<Box<dyn FnMut(ua::Variant)>>,
+}
+
+impl DataSourceFn {
+    /// Creates read-only data source.
+    #[must_use]
+    pub fn new(read: impl FnMut() -> ua::Variant + 'static) -> Self {
+        Self {
+            read: Box::new(read),
+            write: None,
+        }
+    }
+
+    /// Sets callback for writes.
+    ///
+    /// This makes the data source writable.
+    #[must_use]
+    pub fn with_write(mut self, write: impl FnMut(ua::Variant) + 'static) -> Self {
+        self.write = Some(Box::new(write));
+        self
+    }
+}
+
+impl fmt::Debug for DataSourceFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataSourceFn")
+            .field("writable", &self.write.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DataSource for DataSourceFn {
+    fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+        context.set_variant((self.read)());
+        Ok(())
+    }
+
+    fn write(&mut self, context: &mut DataSourceWriteContext) -> DataSourceResult {
+        let Some(write) = self.write.as_mut() else {
+            return Err(DataSourceError::from_status_code(
+                ua::StatusCode::BADNOTSUPPORTED,
+            ));
+        };
+        let Some(value) = context.value().value() else {
+            return Err(DataSourceError::from_status_code(
+                ua::StatusCode::BADTYPEMISMATCH,
+            ));
+        };
+        write(value.clone());
+        Ok(())
+    }
+}
+
 /// Context when [`DataSource`] is being read from.
 #[derive(Debug)]
 pub struct DataSourceReadContext {
@@ -1,5 +1,6 @@
 use ::core::ffi::c_void;
 use std::{
+    fmt,
     panic::{catch_unwind, AssertUnwindSafe},
     ptr::NonNull,
 };
@@ -67,6 +68,73 @@ pub trait MethodCallback {
     fn call(&mut self, context: &mut MethodCallbackContext) -> MethodCallbackResult;
 }
 
+/// Boxed closure for [`MethodCallbackFn`].
+type CallFn = Box<dyn FnMut(&[ua::Variant]) -> Result<Vec<ua::Variant>, ua::StatusCode>>;
+
+/// Method callback from closure.
+///
+/// This implements [`MethodCallback`] by calling `call` with the input arguments whenever a client
+/// calls the method. The closure returns the output arguments, which must match the number of
+/// output arguments declared for the method node.
+///
+/// # Examples
+///
+/// ```
+/// # use open62541::{MethodCallbackFn, ua};
+/// let callback = MethodCallbackFn::new(|input_arguments| {
+///     let [a, b] = input_arguments else {
+///         return Err(ua::StatusCode::BADARGUMENTSMISSING);
+///     };
+///     let (Some(a), Some(b)) = (a.as_scalar::<ua::Int32>(), b.as_scalar::<ua::Int32>()) else {
+///         return Err(ua::StatusCode::BADTYPEMISMATCH);
+///     };
+///     let sum = a.value().wrapping_add(b.value());
+///     Ok(vec![ua::Variant::scalar(ua::Int32::new(sum))])
+/// });
+/// ```
+pub struct MethodCallbackFn(CallFn);
+
+impl MethodCallbackFn {
+    /// Creates method callback.
+    #[must_use]
+    pub fn new(
+        call: impl FnMut(&[ua::Variant]) -> Result<Vec<ua::Variant>, ua::StatusCode> + 'static,
+    ) -> Self {
+        Self(Box::new(call))
+    }
+}
+
+impl fmt::Debug for MethodCallbackFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MethodCallbackFn").finish_non_exhaustive()
+    }
+}
+
+impl MethodCallback for MethodCallbackFn {
+    fn call(&mut self, context: &mut MethodCallbackContext) -> MethodCallbackResult {
+        let output_arguments =
+            (self.0)(context.input_arguments()).map_err(MethodCallbackError::from_status_code)?;
+
+        let output_targets = context.output_arguments_mut();
+        if output_arguments.len() != output_targets.len() {
+            log::error!(
+                "Method callback returned {} output arguments, expected {}",
+                output_arguments.len(),
+                output_targets.len()
+            );
+            return Err(MethodCallbackError::from_status_code(
+                ua::StatusCode::BADINTERNALERROR,
+            ));
+        }
+
+        for (target, value) in output_targets.iter_mut().zip(output_arguments) {
+            *target = value;
+        }
+
+        Ok(())
+    }
+}
+
 /// Context when [`MethodCallback`] is being called.
 #[derive(Debug)]
 pub struct MethodCallbackContext {
@@ -204,3 +272,80 @@ pub(crate) unsafe fn wrap_method_callback(
 
     (Some(callback_c), node_context)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    use super::{
+        MethodCallback as _, MethodCallbackContext, MethodCallbackError, MethodCallbackFn,
+    };
+
+    fn sum(input_arguments: &[ua::Variant]) -> Result<Vec<ua::Variant>, ua::StatusCode> {
+        let [a, b] = input_arguments else {
+            return Err(ua::StatusCode::BADARGUMENTSMISSING);
+        };
+        let (Some(a), Some(b)) = (a.as_scalar::<ua::Int32>(), b.as_scalar::<ua::Int32>()) else {
+            return Err(ua::StatusCode::BADTYPEMISMATCH);
+        };
+        Ok(vec![ua::Variant::scalar(ua::Int32::new(
+            a.value() + b.value(),
+        ))])
+    }
+
+    fn call(
+        callback: &mut MethodCallbackFn,
+        input_arguments: &[ua::Variant],
+        output_size: usize,
+    ) -> Result<Vec<ua::Variant>, ua::StatusCode> {
+        let object_id = ua::NodeId::ns0(0);
+        let mut output_arguments = vec![ua::Variant::init(); output_size];
+
+        let mut context = MethodCallbackContext::new(
+            object_id.as_ptr(),
+            input_arguments.len(),
+            input_arguments.as_ptr().cast(),
+            output_arguments.len(),
+            output_arguments.as_mut_ptr().cast(),
+        )
+        .expect("should create context");
+
+        callback
+            .call(&mut context)
+            .map_err(MethodCallbackError::into_status_code)?;
+
+        Ok(output_arguments)
+    }
+
+    #[test]
+    fn call_closure() {
+        let mut callback = MethodCallbackFn::new(sum);
+
+        let input_arguments = [
+            ua::Variant::scalar(ua::Int32::new(2)),
+            ua::Variant::scalar(ua::Int32::new(3)),
+        ];
+        let output_arguments = call(&mut callback, &input_arguments, 1).expect("should call");
+        assert_eq!(
+            output_arguments.first().and_then(ua::Variant::as_scalar),
+            Some(&ua::Int32::new(5))
+        );
+
+        // Errors from closure are forwarded.
+        let input_arguments = [ua::Variant::scalar(ua::Int32::new(2))];
+        assert_eq!(
+            call(&mut callback, &input_arguments, 1),
+            Err(ua::StatusCode::BADARGUMENTSMISSING)
+        );
+
+        // Mismatching number of output arguments is rejected.
+        let input_arguments = [
+            ua::Variant::scalar(ua::Int32::new(2)),
+            ua::Variant::scalar(ua::Int32::new(3)),
+        ];
+        assert_eq!(
+            call(&mut callback, &input_arguments, 2),
+            Err(ua::StatusCode::BADINTERNALERROR)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::ffi::c_void;
 
 use crate::{
-    server::{DataSource, MethodCallback},
+    server::{value_callback::WriteCallback, DataSource, MethodCallback},
     Userdata,
 };
 
@@ -12,6 +12,7 @@ use crate::{
 pub(crate) enum NodeContext {
     DataSource(Box<dyn DataSource>),
     MethodCallback(Box<dyn MethodCallback>),
+    WriteCallback(WriteCallback),
 }
 
 #[allow(dead_code)] // We will use the methods soon.
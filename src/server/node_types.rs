@@ -1,5 +1,9 @@
 use std::fmt;
 
+use open62541_sys::{
+    UA_NS0ID_BASEDATAVARIABLETYPE, UA_NS0ID_FOLDERTYPE, UA_NS0ID_HASCOMPONENT, UA_NS0ID_ORGANIZES,
+};
+
 use crate::{ua, Attributes, DataType};
 
 use crate::server::NodeContext;
@@ -122,6 +126,80 @@ pub struct ObjectNode {
     pub attributes: ua::ObjectAttributes,
 }
 
+impl ObjectNode {
+    /// Creates object node.
+    ///
+    /// The node is referenced from its parent with `Organizes` and its display name is taken from
+    /// the browse name. Use [`folder()`](Self::folder) to create folder nodes.
+    ///
+    /// Use [`with_requested_new_node_id()`](Self::with_requested_new_node_id) to choose the node
+    /// ID, otherwise the server assigns one when adding the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ObjectNode, ServerBuilder, ua};
+    /// use open62541_sys::{UA_NS0ID_BASEOBJECTTYPE, UA_NS0ID_OBJECTSFOLDER};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let folder_id = server.add_object_node(ObjectNode::folder(
+    ///     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+    ///     ua::QualifiedName::new(1, "Plant"),
+    /// ))?;
+    ///
+    /// let object_id = server.add_object_node(ObjectNode::new(
+    ///     folder_id,
+    ///     ua::QualifiedName::new(1, "Boiler"),
+    ///     ua::NodeId::ns0(UA_NS0ID_BASEOBJECTTYPE),
+    /// ))?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new(
+        parent_node_id: ua::NodeId,
+        browse_name: ua::QualifiedName,
+        type_definition: ua::NodeId,
+    ) -> Self {
+        let mut attributes = ua::ObjectAttributes::default();
+        if let Some(display_name) = display_name(&browse_name) {
+            attributes = attributes.with_display_name(&display_name);
+        }
+
+        Self {
+            requested_new_node_id: None,
+            parent_node_id,
+            reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+            browse_name,
+            type_definition,
+            attributes,
+        }
+    }
+
+    /// Creates folder node.
+    ///
+    /// This is an object node with the type definition `FolderType`. See [`new()`](Self::new) for
+    /// details.
+    #[must_use]
+    pub fn folder(parent_node_id: ua::NodeId, browse_name: ua::QualifiedName) -> Self {
+        Self::new(
+            parent_node_id,
+            browse_name,
+            ua::NodeId::ns0(UA_NS0ID_FOLDERTYPE),
+        )
+    }
+
+    #[must_use]
+    pub fn with_requested_new_node_id(mut self, requested_new_node_id: ua::NodeId) -> Self {
+        self.requested_new_node_id = Some(requested_new_node_id);
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VariableNode {
     pub requested_new_node_id: Option<ua::NodeId>,
@@ -132,6 +210,86 @@ pub struct VariableNode {
     pub attributes: ua::VariableAttributes,
 }
 
+impl VariableNode {
+    /// Creates variable node with initial value.
+    ///
+    /// This uses sensible defaults for the remaining fields: the node is referenced from its parent
+    /// with `Organizes` and has the type definition `BaseDataVariableType`. Its display name is
+    /// taken from the browse name, its data type and value rank (scalar or number of array
+    /// dimensions) are taken from `value`, and it can be read and written by clients.
+    ///
+    /// Adjust [`attributes`](Self::attributes) to declare another value rank, e.g. with
+    /// [`ua::VariableAttributes::with_value_rank()`].
+    ///
+    /// Use [`with_requested_new_node_id()`](Self::with_requested_new_node_id) to choose the node
+    /// ID, otherwise the server assigns one when adding the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ServerBuilder, VariableNode, ua};
+    /// use open62541_sys::UA_NS0ID_OBJECTSFOLDER;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let node_id = server.add_variable_node(VariableNode::new(
+    ///     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+    ///     ua::QualifiedName::new(1, "Temperature"),
+    ///     &ua::Variant::scalar(ua::Double::new(21.5)),
+    /// ))?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new(
+        parent_node_id: ua::NodeId,
+        browse_name: ua::QualifiedName,
+        value: &ua::Variant,
+    ) -> Self {
+        let mut attributes = ua::VariableAttributes::default()
+            .with_value(value)
+            .with_access_level(
+                &ua::AccessLevel::NONE
+                    .with_current_read(true)
+                    .with_current_write(true),
+            );
+        if let Some(data_type) = value.type_id() {
+            attributes = attributes.with_data_type(data_type);
+        }
+        if value.is_scalar() {
+            attributes = attributes.with_value_rank(-1);
+        } else if value.is_array() {
+            // Declare only the number of dimensions. Their maximum lengths are unknown (`0`), so the
+            // value may grow later on.
+            let dimensions = value.array_dimensions().len().max(1);
+            attributes = attributes
+                .with_value_rank(i32::try_from(dimensions).unwrap_or(i32::MAX))
+                .with_array_dimensions(&vec![0; dimensions]);
+        }
+        if let Some(display_name) = display_name(&browse_name) {
+            attributes = attributes.with_display_name(&display_name);
+        }
+
+        Self {
+            requested_new_node_id: None,
+            parent_node_id,
+            reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+            browse_name,
+            type_definition: ua::NodeId::ns0(UA_NS0ID_BASEDATAVARIABLETYPE),
+            attributes,
+        }
+    }
+
+    #[must_use]
+    pub fn with_requested_new_node_id(mut self, requested_new_node_id: ua::NodeId) -> Self {
+        self.requested_new_node_id = Some(requested_new_node_id);
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodNode {
     pub requested_new_node_id: Option<ua::NodeId>,
@@ -144,3 +302,91 @@ pub struct MethodNode {
     pub output_arguments: ua::Array<ua::Argument>,
     pub output_arguments_requested_new_node_id: Option<ua::NodeId>,
 }
+
+impl MethodNode {
+    /// Creates method node.
+    ///
+    /// The node is referenced from its parent (the object the method belongs to) with
+    /// `HasComponent`, its display name is taken from the browse name, and it can be executed by
+    /// clients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{MethodCallbackFn, MethodNode, ServerBuilder, ua};
+    /// use open62541_sys::{UA_NS0ID_INT32, UA_NS0ID_OBJECTSFOLDER};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let argument = |name| {
+    ///     ua::Argument::init()
+    ///         .with_name(&ua::String::new(name).unwrap())
+    ///         .with_data_type(&ua::NodeId::ns0(UA_NS0ID_INT32))
+    ///         .with_value_rank(-1)
+    /// };
+    ///
+    /// let (method_id, _) = server.add_method_node(
+    ///     MethodNode::new(
+    ///         ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
+    ///         ua::QualifiedName::new(1, "Sum"),
+    ///         &[argument("a"), argument("b")],
+    ///         &[argument("sum")],
+    ///     ),
+    ///     MethodCallbackFn::new(|input_arguments| {
+    ///         let sum = input_arguments
+    ///             .iter()
+    ///             .filter_map(ua::Variant::as_scalar::<ua::Int32>)
+    ///             .map(ua::Int32::value)
+    ///             .sum();
+    ///         Ok(vec![ua::Variant::scalar(ua::Int32::new(sum))])
+    ///     }),
+    /// )?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn new(
+        parent_node_id: ua::NodeId,
+        browse_name: ua::QualifiedName,
+        input_arguments: &[ua::Argument],
+        output_arguments: &[ua::Argument],
+    ) -> Self {
+        let mut attributes = ua::MethodAttributes::default()
+            .with_executable(true)
+            .with_user_executable(true);
+        if let Some(display_name) = display_name(&browse_name) {
+            attributes = attributes.with_display_name(&display_name);
+        }
+
+        Self {
+            requested_new_node_id: None,
+            parent_node_id,
+            reference_type_id: ua::NodeId::ns0(UA_NS0ID_HASCOMPONENT),
+            browse_name,
+            attributes,
+            input_arguments: ua::Array::from_slice(input_arguments),
+            input_arguments_requested_new_node_id: None,
+            output_arguments: ua::Array::from_slice(output_arguments),
+            output_arguments_requested_new_node_id: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_requested_new_node_id(mut self, requested_new_node_id: ua::NodeId) -> Self {
+        self.requested_new_node_id = Some(requested_new_node_id);
+        self
+    }
+}
+
+/// Derives display name from browse name.
+///
+/// This returns `None` when the browse name is not valid UTF-8 or contains NUL bytes.
+fn display_name(browse_name: &ua::QualifiedName) -> Option<ua::LocalizedText> {
+    browse_name
+        .name()
+        .as_str()
+        .and_then(|name| ua::LocalizedText::new("", name).ok())
+}
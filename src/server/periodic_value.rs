@@ -0,0 +1,119 @@
+use std::{
+    cell::UnsafeCell,
+    ffi::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use open62541_sys::{
+    UA_Server, UA_Server_addRepeatedCallback, UA_Server_removeCallback, __UA_Server_write,
+};
+
+use crate::{ua, DataType as _, Error, Result};
+
+/// Generator of values for [`Server::set_periodic_value()`](crate::Server::set_periodic_value).
+type Generator = Box<dyn FnMut() -> ua::Variant + Send>;
+
+/// Data of repeated callback that writes generated values to node.
+struct PeriodicValue {
+    node_id: ua::NodeId,
+    generator: Mutex<Generator>,
+    /// Written by `UA_Server_addRepeatedCallback()` when registering the callback, before it may run
+    /// for the first time. Never modified afterwards.
+    callback_id: UnsafeCell<u64>,
+}
+
+/// Adds repeated callback to server.
+///
+/// The callback writes the values returned by `generator` to the given node, until the node is
+/// deleted or the server is dropped.
+pub(crate) fn add_periodic_value(
+    server: &ua::Server,
+    node_id: &ua::NodeId,
+    interval: Duration,
+    generator: impl FnMut() -> ua::Variant + Send + 'static,
+) -> Result<()> {
+    unsafe extern "C" fn callback_c(server: *mut UA_Server, data: *mut c_void) {
+        // SAFETY: `data` is the result of `Box::into_raw()` below and only released after the
+        // server has been deleted. We only ever take shared references.
+        let periodic_value = unsafe { &*data.cast::<PeriodicValue>() };
+
+        let value = {
+            let mut generator = periodic_value
+                .generator
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let mut generator = AssertUnwindSafe(&mut *generator);
+            match catch_unwind(move || generator()) {
+                Ok(value) => value,
+                Err(err) => {
+                    log::error!("Periodic value generator panicked: {err:?}");
+                    return;
+                }
+            }
+        };
+
+        let status_code = ua::StatusCode::new(unsafe {
+            __UA_Server_write(
+                server,
+                periodic_value.node_id.as_ptr(),
+                // Passing ownership is trivial with primitive value (`u32`).
+                ua::AttributeId::VALUE.into_raw(),
+                ua::Variant::data_type(),
+                value.as_ptr().cast::<c_void>(),
+            )
+        });
+
+        if status_code == ua::StatusCode::BADNODEIDUNKNOWN {
+            // The node has been deleted. Stop writing to it. The data itself is still released only
+            // along with the server: we may not free it while the callback is running.
+            log::debug!(
+                "Removing periodic value of deleted node {}",
+                periodic_value.node_id
+            );
+            // SAFETY: The ID is written while holding the lock of the server's timer, the callback
+            // is only dispatched under the same lock afterwards.
+            let callback_id = unsafe { *periodic_value.callback_id.get() };
+            unsafe { UA_Server_removeCallback(server, callback_id) };
+        } else if let Err(error) = Error::verify_good(&status_code) {
+            log::warn!("Error when writing periodic value: {error}");
+        }
+    }
+
+    unsafe fn release(data: *mut c_void) {
+        // SAFETY: `data` is the result of `Box::into_raw()` below and is released only once.
+        drop(unsafe { Box::from_raw(data.cast::<PeriodicValue>()) });
+    }
+
+    let data = Box::into_raw(Box::new(PeriodicValue {
+        node_id: node_id.clone(),
+        generator: Mutex::new(Box::new(generator)),
+        callback_id: UnsafeCell::new(0),
+    }));
+
+    log::debug!("Calling Server_addRepeatedCallback()");
+
+    let status_code = ua::StatusCode::new(unsafe {
+        UA_Server_addRepeatedCallback(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            server.as_ptr().cast_mut(),
+            Some(callback_c),
+            data.cast::<c_void>(),
+            // `UA_Server_addRepeatedCallback()` expects the interval given in milliseconds.
+            interval.as_secs_f64() * 1000.0,
+            // The callback reads the ID from its data, so it must be stored there right away.
+            (*data).callback_id.get(),
+        )
+    });
+    if let Err(error) = Error::verify_good(&status_code) {
+        // SAFETY: The callback has not been added, we still own the data.
+        unsafe { release(data.cast::<c_void>()) };
+        return Err(error);
+    }
+
+    // SAFETY: The data is not released anywhere else.
+    unsafe { server.release_on_delete(data.cast::<c_void>(), release) };
+
+    Ok(())
+}
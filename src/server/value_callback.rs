@@ -0,0 +1,62 @@
+use std::{
+    ffi::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use open62541_sys::{UA_DataValue, UA_NodeId, UA_NumericRange, UA_Server, UA_ValueCallback};
+
+use crate::{server::NodeContext, ua, DataType as _};
+
+/// Callback for writes to variable.
+pub(crate) type WriteCallback = Box<dyn FnMut(&ua::DataValue) + Send>;
+
+/// Transforms into raw value.
+///
+/// # Safety
+///
+/// The returned [`UA_ValueCallback`] is only valid for as long as [`NodeContext`] is alive. The
+/// lifetime can be extended by using [`NodeContext::leak()`] to save this value inside the
+/// corresponding server node, to be eventually cleaned up when the node is destroyed.
+pub(crate) unsafe fn wrap_write_callback(
+    callback: impl FnMut(&ua::DataValue) + Send + 'static,
+) -> (UA_ValueCallback, NodeContext) {
+    unsafe extern "C" fn on_write_c(
+        _server: *mut UA_Server,
+        _session_id: *const UA_NodeId,
+        _session_context: *mut c_void,
+        _node_id: *const UA_NodeId,
+        node_context: *mut c_void,
+        _range: *const UA_NumericRange,
+        data: *const UA_DataValue,
+    ) {
+        let node_context = unsafe { NodeContext::peek_at(node_context) };
+        let NodeContext::WriteCallback(callback) = node_context else {
+            // We expect to always find this node context type.
+            log::error!("Write callback called for node without write callback");
+            return;
+        };
+
+        let Some(data) = (unsafe { data.as_ref() }) else {
+            // The server should always pass the written value.
+            log::error!("Write callback called without value");
+            return;
+        };
+        let value = ua::DataValue::raw_ref(data);
+        let mut callback = AssertUnwindSafe(callback);
+
+        // The write has already happened, there is no status code to report back to the client.
+        if let Err(err) = catch_unwind(move || callback(value)) {
+            log::error!("Write callback panicked: {err:?}");
+        }
+    }
+
+    let raw_value_callback = UA_ValueCallback {
+        // The read callback is not needed, the value is read from the node as usual.
+        onRead: None,
+        onWrite: Some(on_write_c),
+    };
+
+    let node_context = NodeContext::WriteCallback(Box::new(callback));
+
+    (raw_value_callback, node_context)
+}
@@ -0,0 +1,264 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Weak,
+    },
+    time::Duration,
+};
+
+use open62541_sys::UA_Client_Subscriptions_delete;
+
+use crate::{
+    data_change_subscription::{create_data_change, create_subscription, Notifier},
+    subscription_registry::{MonitoredItemEntry, SubscriptionEntry},
+    ua, DataType as _, Error, MonitoredItem, Result, ServiceResponse as _,
+};
+
+/// Subscription (with callback-based API).
+///
+/// This is returned by [`Client::create_subscription()`]. Monitored items may be added with
+/// [`create_monitored_item()`](Self::create_monitored_item). When the subscription is dropped, it
+/// is deleted on the server, along with all monitored items created under it (even when their
+/// handles are still around). Use [`detach()`](Self::detach) to keep the subscription alive
+/// instead.
+///
+/// Notifications are only received while the client's event loop is running. See
+/// [`Client::spawn_driver()`] for details.
+///
+/// [`Client::create_subscription()`]: crate::Client::create_subscription
+/// [`Client::spawn_driver()`]: crate::Client::spawn_driver
+#[derive(Debug)]
+pub struct Subscription {
+    client: Weak<ua::Client>,
+    /// Shared with monitored items, to let them know when they have been deleted.
+    deleted: Arc<AtomicBool>,
+    subscription: Arc<SubscriptionEntry>,
+    /// Parameters as revised by the server.
+    revised_publishing_interval: f64,
+    revised_lifetime_count: u32,
+    revised_max_keep_alive_count: u32,
+}
+
+impl Subscription {
+    pub(crate) fn new(
+        client: &Arc<ua::Client>,
+        parameters: &ua::SubscriptionParameters,
+    ) -> Result<Self> {
+        let request = parameters.as_request();
+        let response = create_subscription(client, request)?;
+
+        let subscription =
+            SubscriptionEntry::new(client, request.clone(), response.subscription_id());
+        client.register_subscription(&subscription);
+
+        Ok(Self {
+            client: Arc::downgrade(client),
+            deleted: Arc::new(AtomicBool::new(false)),
+            subscription,
+            revised_publishing_interval: response.revised_publishing_interval(),
+            revised_lifetime_count: response.revised_lifetime_count(),
+            revised_max_keep_alive_count: response.revised_max_keep_alive_count(),
+        })
+    }
+
+    /// Gets subscription ID.
+    ///
+    /// The ID changes when the subscription is re-created after reconnecting. See
+    /// [`ClientBuilder::reconnect_backoff()`] for details.
+    ///
+    /// [`ClientBuilder::reconnect_backoff()`]: crate::ClientBuilder::reconnect_backoff
+    #[must_use]
+    pub fn subscription_id(&self) -> ua::SubscriptionId {
+        self.subscription.subscription_id()
+    }
+
+    /// Gets publishing interval.
+    ///
+    /// This is the interval that the server has chosen, it may differ from the requested interval
+    /// in [`ua::SubscriptionParameters`].
+    #[must_use]
+    pub fn revised_publishing_interval(&self) -> Duration {
+        // `UA_CreateSubscriptionResponse` holds the publishing interval given in milliseconds. The
+        // server should not send negative or non-finite values, fall back to zero if it does.
+        Duration::try_from_secs_f64(self.revised_publishing_interval / 1000.0).unwrap_or_default()
+    }
+
+    /// Gets lifetime count, as revised by the server.
+    #[must_use]
+    pub const fn revised_lifetime_count(&self) -> u32 {
+        self.revised_lifetime_count
+    }
+
+    /// Gets maximum keep-alive count, as revised by the server.
+    #[must_use]
+    pub const fn revised_max_keep_alive_count(&self) -> u32 {
+        self.revised_max_keep_alive_count
+    }
+
+    /// Creates monitored item for data changes.
+    ///
+    /// This monitors the given node's value attribute. The server samples the value at the given
+    /// `sampling_interval` and `callback` is called with each new value received. The monitored
+    /// item is deleted (and the callback released) when the returned handle is dropped, or along
+    /// with the subscription, whichever happens first.
+    ///
+    /// See [`Client::create_data_change_subscription()`] for details on how the callback is called.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client has been dropped, or the node does not exist.
+    ///
+    /// [`Client::create_data_change_subscription()`]: crate::Client::create_data_change_subscription
+    pub fn create_monitored_item(
+        &self,
+        node_id: &ua::NodeId,
+        sampling_interval: Duration,
+        callback: impl FnMut(ua::DataValue) + Send + 'static,
+    ) -> Result<MonitoredItem> {
+        // `UA_MonitoredItemCreateRequest` expects the sampling interval given in milliseconds.
+        let sampling_interval = sampling_interval.as_secs_f64() * 1000.0;
+
+        let request = ua::MonitoredItemCreateRequest::default()
+            .with_node_id(node_id)
+            .with_sampling_interval(sampling_interval);
+
+        self.create_monitored_item_from_request(request, callback)
+    }
+
+    /// Creates monitored item for data changes, with filter.
+    ///
+    /// This is like [`create_monitored_item()`](Self::create_monitored_item) but the server reports
+    /// only changes that pass the given filter. Use a deadband filter to suppress notifications for
+    /// small fluctuations of numeric values:
+    ///
+    /// ```no_run
+    /// # use open62541::{Result, Subscription, ua};
+    /// # use std::time::Duration;
+    /// # fn example(subscription: &Subscription, node_id: &ua::NodeId) -> Result<()> {
+    /// // Only changes by more than 0.5 are reported.
+    /// let filter = ua::DataChangeFilter::deadband(&ua::DeadbandType::ABSOLUTE, 0.5);
+    ///
+    /// let monitored_item = subscription.create_monitored_item_with_filter(
+    ///     node_id,
+    ///     Duration::from_millis(100),
+    ///     &filter,
+    ///     |value| println!("Received value: {value:?}"),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client has been dropped, the node does not exist, or the server rejects
+    /// the filter (e.g. a percent deadband for a variable without `EURange` property).
+    pub fn create_monitored_item_with_filter(
+        &self,
+        node_id: &ua::NodeId,
+        sampling_interval: Duration,
+        filter: &ua::DataChangeFilter,
+        callback: impl FnMut(ua::DataValue) + Send + 'static,
+    ) -> Result<MonitoredItem> {
+        // `UA_MonitoredItemCreateRequest` expects the sampling interval given in milliseconds.
+        let sampling_interval = sampling_interval.as_secs_f64() * 1000.0;
+
+        let request = ua::MonitoredItemCreateRequest::default()
+            .with_node_id(node_id)
+            .with_sampling_interval(sampling_interval)
+            .with_filter(&ua::ExtensionObject::new(filter));
+
+        self.create_monitored_item_from_request(request, callback)
+    }
+
+    fn create_monitored_item_from_request(
+        &self,
+        request: ua::MonitoredItemCreateRequest,
+        callback: impl FnMut(ua::DataValue) + Send + 'static,
+    ) -> Result<MonitoredItem> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let subscription_id = self.subscription.subscription_id();
+        let notifier = Arc::new(Notifier::new(callback));
+        let result = create_data_change(&client, subscription_id, &request, &notifier)?;
+
+        let monitored_item_entry = MonitoredItemEntry::data_change(
+            request.clone(),
+            notifier,
+            subscription_id,
+            result.monitored_item_id(),
+        );
+        self.subscription.add_monitored_item(&monitored_item_entry);
+
+        Ok(MonitoredItem::new(
+            &client,
+            &self.deleted,
+            monitored_item_entry,
+            &request,
+            &result,
+        ))
+    }
+
+    /// Detaches subscription from handle.
+    ///
+    /// The subscription is not deleted when the handle goes away. It stays active on the server
+    /// (along with its monitored items) until the client is dropped or the server deletes it. A
+    /// detached subscription is not re-created after reconnecting.
+    #[must_use]
+    pub fn detach(mut self) -> ua::SubscriptionId {
+        // Without client, `drop()` below has nothing left to do.
+        self.client = Weak::new();
+        self.subscription.subscription_id()
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        // When the client has been dropped already, all subscriptions (and their monitored items)
+        // have been cleaned up by `UA_Client_delete()`, including our callbacks.
+        let Some(client) = self.client.upgrade() else {
+            return;
+        };
+
+        // This also deletes the monitored items, which releases their callbacks. Tell any handles
+        // that remain to not attempt to delete them again.
+        self.deleted.store(true, Ordering::Release);
+        let subscription_id = self.subscription.delete();
+        let result = delete_subscriptions(&client, &[subscription_id]).and_then(|response| {
+            // We expect exactly one result for the subscription we requested above.
+            let status_code = response
+                .results()
+                .and_then(|results| results.iter().next().cloned())
+                .ok_or(Error::internal("delete should return result"))?;
+            Error::verify_good(&status_code)
+        });
+        if let Err(error) = result {
+            log::warn!("Error when deleting subscription: {error}");
+        }
+    }
+}
+
+fn delete_subscriptions(
+    client: &ua::Client,
+    subscription_ids: &[ua::SubscriptionId],
+) -> Result<ua::DeleteSubscriptionsResponse> {
+    log::debug!("Calling Subscriptions_delete()");
+
+    let request = ua::DeleteSubscriptionsRequest::init().with_subscription_ids(subscription_ids);
+
+    let response = unsafe {
+        UA_Client_Subscriptions_delete(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            // SAFETY: `UA_Client_Subscriptions_delete()` expects the request passed by value but
+            // does not take ownership.
+            ua::DeleteSubscriptionsRequest::to_raw_copy(&request),
+        )
+    };
+    // SAFETY: We take ownership of the returned response.
+    let response = unsafe { ua::DeleteSubscriptionsResponse::from_raw(response) };
+    Error::verify_good(&response.service_result())?;
+
+    Ok(response)
+}
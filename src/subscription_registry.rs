@@ -0,0 +1,324 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, Weak},
+};
+
+use crate::{
+    data_change_subscription::{
+        create_data_change, create_subscription, delete_subscription, Notifier,
+    },
+    event_subscription::create_event,
+    monitored_item::delete_monitored_item,
+    ua, Result,
+};
+
+/// Subscriptions to re-create after reconnecting.
+///
+/// When the client reconnects after the server has lost its session (e.g. because it has been
+/// restarted), a new session must be created. This loses all subscriptions: open62541 removes them
+/// locally and releases the callbacks of their monitored items. We keep track of the requests and
+/// callbacks to re-create them once the new session has been activated.
+///
+/// This only holds weak references. Subscriptions are no longer re-created when their handles have
+/// been dropped or detached.
+#[derive(Debug, Default)]
+pub(crate) struct SubscriptionRegistry {
+    subscriptions: Mutex<Vec<Weak<SubscriptionEntry>>>,
+}
+
+impl SubscriptionRegistry {
+    pub(crate) fn register(&self, subscription: &Arc<SubscriptionEntry>) {
+        let mut subscriptions = self.subscriptions();
+        // Forget about subscriptions that have been dropped in the meantime.
+        subscriptions.retain(|subscription| subscription.strong_count() > 0);
+        subscriptions.push(Arc::downgrade(subscription));
+    }
+
+    /// Re-creates subscriptions that belong to a different session.
+    ///
+    /// This must be called after the session has been activated. It runs blocking service requests,
+    /// so it must not be called from within callbacks.
+    pub(crate) fn recreate(&self, client: &ua::Client) {
+        let Some(session) = client.session_authentication_token() else {
+            return;
+        };
+
+        // Do not hold the lock while re-creating: callbacks may create new subscriptions.
+        let subscriptions: Vec<_> = self
+            .subscriptions()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect();
+
+        for subscription in subscriptions {
+            subscription.recreate(client, &session);
+        }
+    }
+
+    fn subscriptions(&self) -> MutexGuard<'_, Vec<Weak<SubscriptionEntry>>> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Subscription that can be re-created.
+///
+/// This is owned by the subscription handle and holds the current subscription ID.
+#[derive(Debug)]
+pub(crate) struct SubscriptionEntry {
+    request: ua::CreateSubscriptionRequest,
+    state: Mutex<SubscriptionState>,
+}
+
+#[derive(Debug)]
+struct SubscriptionState {
+    subscription_id: ua::SubscriptionId,
+    /// Authentication token of the session that the subscription has been created in.
+    session: Option<ua::NodeId>,
+    /// Set when the handle has deleted the subscription.
+    deleted: bool,
+    monitored_items: Vec<Weak<MonitoredItemEntry>>,
+}
+
+impl SubscriptionEntry {
+    /// Creates entry for subscription that has just been created in the current session.
+    pub(crate) fn new(
+        client: &ua::Client,
+        request: ua::CreateSubscriptionRequest,
+        subscription_id: ua::SubscriptionId,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            request,
+            state: Mutex::new(SubscriptionState {
+                subscription_id,
+                session: client.session_authentication_token(),
+                deleted: false,
+                monitored_items: Vec::new(),
+            }),
+        })
+    }
+
+    pub(crate) fn subscription_id(&self) -> ua::SubscriptionId {
+        self.state().subscription_id
+    }
+
+    pub(crate) fn add_monitored_item(&self, monitored_item: &Arc<MonitoredItemEntry>) {
+        let mut state = self.state();
+        // Forget about monitored items that have been dropped in the meantime.
+        state
+            .monitored_items
+            .retain(|monitored_item| monitored_item.strong_count() > 0);
+        state.monitored_items.push(Arc::downgrade(monitored_item));
+    }
+
+    /// Marks subscription as deleted.
+    ///
+    /// This returns the current subscription ID. The subscription is not re-created afterwards.
+    pub(crate) fn delete(&self) -> ua::SubscriptionId {
+        let mut state = self.state();
+        state.deleted = true;
+        state.subscription_id
+    }
+
+    fn recreate(&self, client: &ua::Client, session: &ua::NodeId) {
+        let monitored_items: Vec<_> = {
+            let state = self.state();
+            if state.deleted || state.session.as_ref() == Some(session) {
+                return;
+            }
+            state
+                .monitored_items
+                .iter()
+                .filter_map(Weak::upgrade)
+                .collect()
+        };
+
+        log::info!("Re-creating subscription after reconnect");
+
+        let subscription_id = match create_subscription(client, &self.request) {
+            Ok(response) => response.subscription_id(),
+            Err(error) => {
+                log::warn!("Error when re-creating subscription: {error}");
+                return;
+            }
+        };
+
+        {
+            let mut state = self.state();
+            if state.deleted {
+                // The handle has been dropped while we were busy, with the previous ID.
+                drop(state);
+                delete_subscription(client, subscription_id);
+                return;
+            }
+            state.subscription_id = subscription_id;
+            state.session = Some(session.clone());
+        }
+
+        for monitored_item in monitored_items {
+            monitored_item.recreate(client, subscription_id);
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, SubscriptionState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Monitored item that can be re-created.
+///
+/// This holds the current IDs and a reference to the callback, so that open62541 releasing its own
+/// reference when the subscription is lost does not release the callback.
+pub(crate) struct MonitoredItemEntry {
+    notifier: MonitoredItemNotifier,
+    state: Mutex<MonitoredItemState>,
+}
+
+enum MonitoredItemNotifier {
+    DataChange(Arc<Notifier<ua::DataValue>>),
+    Event(Arc<Notifier<Vec<ua::Variant>>>),
+}
+
+#[derive(Debug)]
+struct MonitoredItemState {
+    /// Request to create the monitored item with, including any modifications since.
+    request: ua::MonitoredItemCreateRequest,
+    subscription_id: ua::SubscriptionId,
+    monitored_item_id: ua::MonitoredItemId,
+    /// Set when the handle has deleted the monitored item.
+    deleted: bool,
+}
+
+impl MonitoredItemEntry {
+    /// Creates entry for monitored item that has been created with [`create_data_change()`].
+    pub(crate) fn data_change(
+        request: ua::MonitoredItemCreateRequest,
+        notifier: Arc<Notifier<ua::DataValue>>,
+        subscription_id: ua::SubscriptionId,
+        monitored_item_id: ua::MonitoredItemId,
+    ) -> Arc<Self> {
+        Self::new(
+            MonitoredItemNotifier::DataChange(notifier),
+            request,
+            subscription_id,
+            monitored_item_id,
+        )
+    }
+
+    /// Creates entry for monitored item that has been created with [`create_event()`].
+    pub(crate) fn event(
+        request: ua::MonitoredItemCreateRequest,
+        notifier: Arc<Notifier<Vec<ua::Variant>>>,
+        subscription_id: ua::SubscriptionId,
+        monitored_item_id: ua::MonitoredItemId,
+    ) -> Arc<Self> {
+        Self::new(
+            MonitoredItemNotifier::Event(notifier),
+            request,
+            subscription_id,
+            monitored_item_id,
+        )
+    }
+
+    fn new(
+        notifier: MonitoredItemNotifier,
+        request: ua::MonitoredItemCreateRequest,
+        subscription_id: ua::SubscriptionId,
+        monitored_item_id: ua::MonitoredItemId,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            notifier,
+            state: Mutex::new(MonitoredItemState {
+                request,
+                subscription_id,
+                monitored_item_id,
+                deleted: false,
+            }),
+        })
+    }
+
+    pub(crate) fn subscription_id(&self) -> ua::SubscriptionId {
+        self.state().subscription_id
+    }
+
+    pub(crate) fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        self.state().monitored_item_id
+    }
+
+    /// Sets parameters to use when re-creating the monitored item.
+    pub(crate) fn set_requested_parameters(&self, requested_parameters: &ua::MonitoringParameters) {
+        let mut state = self.state();
+        state.request = state
+            .request
+            .clone()
+            .with_requested_parameters(requested_parameters);
+    }
+
+    /// Marks monitored item as deleted.
+    ///
+    /// This returns the current subscription ID and monitored item ID. The monitored item is not
+    /// re-created afterwards.
+    pub(crate) fn delete(&self) -> (ua::SubscriptionId, ua::MonitoredItemId) {
+        let mut state = self.state();
+        state.deleted = true;
+        (state.subscription_id, state.monitored_item_id)
+    }
+
+    fn recreate(&self, client: &ua::Client, subscription_id: ua::SubscriptionId) {
+        let request = {
+            let state = self.state();
+            if state.deleted {
+                return;
+            }
+            state.request.clone()
+        };
+
+        let monitored_item_id = match self.create(client, subscription_id, &request) {
+            Ok(monitored_item_id) => monitored_item_id,
+            Err(error) => {
+                log::warn!("Error when re-creating monitored item: {error}");
+                return;
+            }
+        };
+
+        let mut state = self.state();
+        if state.deleted {
+            // The handle has been dropped while we were busy, with the previous ID.
+            drop(state);
+            delete_monitored_item(client, subscription_id, monitored_item_id);
+            return;
+        }
+        state.subscription_id = subscription_id;
+        state.monitored_item_id = monitored_item_id;
+    }
+
+    fn create(
+        &self,
+        client: &ua::Client,
+        subscription_id: ua::SubscriptionId,
+        request: &ua::MonitoredItemCreateRequest,
+    ) -> Result<ua::MonitoredItemId> {
+        match &self.notifier {
+            MonitoredItemNotifier::DataChange(notifier) => {
+                create_data_change(client, subscription_id, request, notifier)
+                    .map(|result| result.monitored_item_id())
+            }
+            MonitoredItemNotifier::Event(notifier) => {
+                create_event(client, subscription_id, request, notifier)
+            }
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, MonitoredItemState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for MonitoredItemEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MonitoredItemEntry")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,101 @@
+//! Local server for tests that need a client connection.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU16, Ordering},
+        mpsc, Arc,
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::{Client, Result, Server, ServerBuilder};
+
+/// Next port to use. Tests run in parallel, each server must listen on its own port.
+static NEXT_PORT: AtomicU16 = AtomicU16::new(48400);
+
+/// Server running in background thread.
+///
+/// The server is shut down when this is dropped.
+pub(crate) struct TestServer {
+    server: Server,
+    port: u16,
+    cancelled: Arc<AtomicBool>,
+    runner: Option<JoinHandle<Result<()>>>,
+}
+
+impl TestServer {
+    /// Starts server with default configuration.
+    pub(crate) fn start() -> Self {
+        Self::start_with(ServerBuilder::default())
+    }
+
+    /// Starts server from builder.
+    ///
+    /// This blocks until the server is listening for connections.
+    pub(crate) fn start_with(builder: ServerBuilder) -> Self {
+        Self::start_on_port(builder, NEXT_PORT.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Shuts down server and starts new one with default configuration on the same port.
+    ///
+    /// Connected clients lose their connection (and their session) in the process.
+    pub(crate) fn restart(self) -> Self {
+        let port = self.port;
+        drop(self);
+        Self::start_on_port(ServerBuilder::default(), port)
+    }
+
+    fn start_on_port(builder: ServerBuilder, port: u16) -> Self {
+        let (server, runner) = builder.port(port).build();
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (started_tx, started_rx) = mpsc::channel();
+        let runner = thread::spawn({
+            let cancelled = Arc::clone(&cancelled);
+            move || {
+                runner.run_until_cancelled(|| {
+                    // This is first called after the server has started up.
+                    let _unused = started_tx.send(());
+                    cancelled.load(Ordering::Relaxed)
+                })
+            }
+        });
+        started_rx.recv().expect("server should start");
+
+        Self {
+            server,
+            port,
+            cancelled,
+            runner: Some(runner),
+        }
+    }
+
+    pub(crate) const fn server(&self) -> &Server {
+        &self.server
+    }
+
+    pub(crate) fn endpoint_url(&self) -> String {
+        format!("opc.tcp://localhost:{}", self.port)
+    }
+
+    /// Connects new client to server.
+    pub(crate) fn connect(&self) -> Client {
+        Client::new(&self.endpoint_url()).expect("client should connect")
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        let Some(runner) = self.runner.take() else {
+            return;
+        };
+        let result = runner.join();
+        // Do not panic again when the test has failed already.
+        if !thread::panicking() {
+            result
+                .expect("server thread should not panic")
+                .expect("server should shut down");
+        }
+    }
+}
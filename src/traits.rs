@@ -9,6 +9,7 @@ use crate::{ua, DataType};
 /// This is used to match the appropriate result types at compile time when reading attributes from
 /// nodes. See the following methods for details:
 ///
+/// - [`Client::read_attribute()`](crate::Client::read_attribute)
 /// - [`AsyncClient::read_attribute()`](crate::AsyncClient::read_attribute)
 /// - [`Server::read_attribute()`](crate::Server::read_attribute)
 pub trait Attribute: fmt::Debug + Copy {
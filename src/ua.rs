@@ -8,15 +8,24 @@ mod client_config;
 mod continuation_point;
 mod data_types;
 mod event_id;
+mod log_category;
+mod log_level;
 mod monitored_item_id;
 mod node_class_mask;
+mod node_metadata;
+mod numeric_range;
+mod object_id;
+mod object_type_id;
+mod reference_type_id;
 mod secure_channel_state;
 mod server;
 mod server_config;
 mod session_state;
 mod specified_attributes;
 mod subscription_id;
+mod subscription_parameters;
 mod user_identity_token;
+mod variable_type_id;
 
 pub use self::{
     access_level::AccessLevel,
@@ -26,13 +35,24 @@ pub use self::{
     continuation_point::ContinuationPoint,
     data_types::*,
     event_id::EventId,
+    log_category::LogCategory,
+    log_level::LogLevel,
     monitored_item_id::MonitoredItemId,
     node_class_mask::NodeClassMask,
+    node_metadata::NodeMetadata,
+    numeric_range::NumericRange,
+    object_id::ObjectId,
+    object_type_id::ObjectTypeId,
+    reference_type_id::ReferenceTypeId,
     secure_channel_state::SecureChannelState,
     server::Server,
     session_state::SessionState,
     specified_attributes::SpecifiedAttributes,
     subscription_id::SubscriptionId,
+    subscription_parameters::SubscriptionParameters,
     user_identity_token::UserIdentityToken,
+    variable_type_id::VariableTypeId,
+};
+pub(crate) use self::{
+    client::ReconnectBackoff, client_config::ClientConfig, server_config::ServerConfig,
 };
-pub(crate) use self::{client_config::ClientConfig, server_config::ServerConfig};
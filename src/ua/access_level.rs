@@ -17,6 +17,23 @@ impl AccessLevel {
         self.apply_mask(UA_ACCESSLEVELTYPE_CURRENTWRITE, current_write)
     }
 
+    /// Checks if current value is readable.
+    #[must_use]
+    pub const fn current_read(&self) -> bool {
+        self.has_mask(UA_ACCESSLEVELTYPE_CURRENTREAD)
+    }
+
+    /// Checks if current value is writable.
+    #[must_use]
+    pub const fn current_write(&self) -> bool {
+        self.has_mask(UA_ACCESSLEVELTYPE_CURRENTWRITE)
+    }
+
+    #[allow(clippy::as_conversions, clippy::cast_possible_truncation)] // Masks are in range of `u8`.
+    const fn has_mask(&self, mask: u32) -> bool {
+        self.0 & (mask as u8) != 0
+    }
+
     fn apply_mask(mut self, mask: u32, flag: bool) -> Self {
         // PANIC: Mask is always in range of `u8`.
         let mask = u8::try_from(mask).unwrap_or(0);
@@ -28,6 +45,10 @@ impl AccessLevel {
         self
     }
 
+    pub(crate) const fn from_u8(access_level: u8) -> Self {
+        Self(access_level)
+    }
+
     pub(crate) const fn as_u8(&self) -> u8 {
         self.0
     }
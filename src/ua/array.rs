@@ -166,6 +166,31 @@ impl<T: DataType> Array<T> {
         Some(Self::from_slice(slice))
     }
 
+    /// Creates new array by taking ownership of existing raw parts.
+    ///
+    /// This may be used when `open62541` functions return newly allocated arrays through out
+    /// parameters. Unlike [`from_raw_parts()`](Self::from_raw_parts), this does not copy elements.
+    ///
+    /// # Safety
+    ///
+    /// Ownership of the raw parts passes to `Self`. They must have been allocated by
+    /// [`UA_Array_new()`] (or be an empty or undefined array) and must not be used afterwards.
+    #[must_use]
+    pub(crate) unsafe fn from_owned_raw_parts(size: usize, ptr: *mut T::Inner) -> Option<Self> {
+        let Some(size) = NonZeroUsize::new(size) else {
+            // This indicates an undefined array of unknown length. We do not handle this in the
+            // type but return `None` instead. Otherwise, we expect the sentinel value to indicate
+            // an empty array of length 0 which needs no deallocation.
+            return (!ptr.is_null()).then_some(Self(State::Empty));
+        };
+
+        // We require a proper pointer for safe operation.
+        debug_assert_ne!(ptr.cast::<c_void>(), unsafe { UA_EMPTY_ARRAY_SENTINEL });
+        let ptr = NonNull::new(ptr)?;
+
+        Some(Self(State::NonEmpty { ptr, size }))
+    }
+
     /// Creates slice from existing raw parts.
     ///
     /// # Safety
@@ -547,6 +572,29 @@ mod tests {
         drop(array);
     }
 
+    #[test]
+    fn owned_raw_parts() {
+        let array = ua::Array::from_slice(&[1, 2, 3].map(ua::Byte::new));
+        let (size, ptr) = array.into_raw_parts();
+        // SAFETY: We have given up ownership of the raw parts above.
+        let array = unsafe { ua::Array::<ua::Byte>::from_owned_raw_parts(size, ptr) };
+        assert_eq!(
+            array.map(ua::Array::into_vec),
+            Some([1, 2, 3].map(ua::Byte::new).to_vec())
+        );
+
+        let array = ua::Array::<ua::Byte>::from_slice(&[]);
+        let (size, ptr) = array.into_raw_parts();
+        // SAFETY: We have given up ownership of the raw parts above.
+        let array = unsafe { ua::Array::<ua::Byte>::from_owned_raw_parts(size, ptr) };
+        assert!(array.is_some_and(|array| array.is_empty()));
+
+        // Undefined arrays are not represented.
+        // SAFETY: There is no allocation to take ownership of.
+        let array = unsafe { ua::Array::<ua::Byte>::from_owned_raw_parts(0, ptr::null_mut()) };
+        assert!(array.is_none());
+    }
+
     #[test]
     fn convert_array() {
         let array = ua::Array::from_slice(&[1, 2, 3].map(ua::Byte::new));
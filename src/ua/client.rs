@@ -1,11 +1,21 @@
-use std::ptr::{self, NonNull};
+use std::{
+    mem,
+    ptr::{self, NonNull},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
 
 use open62541_sys::{
-    UA_Client, UA_Client_delete, UA_Client_disconnect, UA_Client_getState, UA_Client_new,
-    UA_Client_newWithConfig,
+    UA_Client, UA_Client_delete, UA_Client_disconnect, UA_Client_getConfig,
+    UA_Client_getSessionAuthenticationToken, UA_Client_getState, UA_Client_new,
+    UA_Client_newWithConfig, UA_Client_run_iterate, __UA_Client_connect,
 };
 
-use crate::{ua, DataType as _, Error};
+use crate::{
+    subscription_registry::{SubscriptionEntry, SubscriptionRegistry},
+    ua::{self, client_config},
+    DataType as _, Error,
+};
 
 /// Combined state for [`Client`] and [`AsyncClient`].
 ///
@@ -25,7 +35,7 @@ pub struct ClientState {
 /// This owns the wrapped data type. When the wrapper is dropped, its inner value is cleaned up with
 /// [`UA_Client_delete()`].
 #[derive(Debug)]
-pub struct Client(NonNull<UA_Client>);
+pub struct Client(NonNull<UA_Client>, Option<ReconnectBackoff>);
 
 // SAFETY: We know that the underlying `UA_Client` allows access from different threads, i.e. it may
 // be dropped in a different thread from where it was created.
@@ -45,7 +55,16 @@ impl Client {
         let inner = unsafe { UA_Client_newWithConfig(ptr::addr_of!(config)) };
         // PANIC: The only possible errors here are out-of-memory.
         let inner = NonNull::new(inner).expect("create UA_Client");
-        Self(inner)
+        Self(inner, None)
+    }
+
+    /// Enables reconnecting with exponential backoff.
+    ///
+    /// This replaces the automatic reconnect of `open62541`, which would retry in every iteration of
+    /// the event loop. The client config must have `noReconnect` set for this to take effect.
+    pub(crate) fn with_reconnect_backoff(mut self, reconnect_backoff: ReconnectBackoff) -> Self {
+        self.1 = Some(reconnect_backoff);
+        self
     }
 
     /// Returns const pointer to value.
@@ -96,13 +115,164 @@ impl Client {
         }
     }
 
+    /// Gets authentication token of current session.
+    ///
+    /// This identifies the session: the token changes when the client has to create a new session
+    /// after reconnecting. Returns `None` when there is no session.
+    pub(crate) fn session_authentication_token(&self) -> Option<ua::NodeId> {
+        let mut authentication_token = ua::NodeId::init();
+        let mut server_nonce = ua::ByteString::init();
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_getSessionAuthenticationToken(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                authentication_token.as_mut_ptr(),
+                server_nonce.as_mut_ptr(),
+            )
+        });
+        Error::verify_good(&status_code).ok()?;
+
+        Some(authentication_token)
+    }
+
+    /// Registers subscription to re-create after reconnecting.
+    ///
+    /// This has no effect without [`ReconnectBackoff`].
+    pub(crate) fn register_subscription(&self, subscription: &Arc<SubscriptionEntry>) {
+        if let Some(reconnect_backoff) = &self.1 {
+            reconnect_backoff.subscriptions.register(subscription);
+        }
+    }
+
+    /// Runs single iteration of event loop.
+    ///
+    /// With [`ReconnectBackoff`], this also reconnects the client after it has lost its connection,
+    /// once the current delay has elapsed. The returned status code is good while waiting for the
+    /// next attempt. When a new session has been activated, registered subscriptions are re-created
+    /// before returning.
+    pub(crate) fn run_iterate(&self, timeout_millis: u32) -> ua::StatusCode {
+        log::trace!("Running iterate");
+
+        // This returns after the timeout even when nothing was processed. The internal mutex is
+        // _not_ held for the entire time though, so we can send out requests concurrently while the
+        // client is running the iteration.
+        let status_code = ua::StatusCode::new(unsafe {
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            UA_Client_run_iterate(self.as_ptr().cast_mut(), timeout_millis)
+        });
+
+        let Some(reconnect_backoff) = &self.1 else {
+            return status_code;
+        };
+
+        let mut channel_state = ua::SecureChannelState::init();
+        let mut session_state = ua::SessionState::init();
+        unsafe {
+            UA_Client_getState(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                channel_state.as_mut_ptr(),
+                session_state.as_mut_ptr(),
+                ptr::null_mut(),
+            );
+        }
+
+        let mut state = reconnect_backoff
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if state.stopped {
+            return status_code;
+        }
+
+        if session_state == ua::SessionState::ACTIVATED {
+            // Start over with the shortest delay when the connection is lost the next time.
+            state.delay = reconnect_backoff.min;
+            state.next_attempt = None;
+            let was_activated = mem::replace(&mut state.activated, true);
+            // Release lock: re-creating runs service requests, which may invoke callbacks.
+            drop(state);
+            if !was_activated {
+                // The session may have been re-activated (which keeps subscriptions) or created
+                // anew (which loses them). This only re-creates subscriptions when necessary.
+                reconnect_backoff.subscriptions.recreate(self);
+            }
+            return status_code;
+        }
+        state.activated = false;
+
+        if channel_state != ua::SecureChannelState::CLOSED {
+            // Still connecting, or the session is being re-activated.
+            return status_code;
+        }
+
+        let now = Instant::now();
+        let delay = state.delay;
+        let next_attempt = *state.next_attempt.get_or_insert(now + delay);
+        if now < next_attempt {
+            return ua::StatusCode::GOOD;
+        }
+
+        log::info!("Reconnecting to endpoint after {delay:?}");
+
+        state.next_attempt = None;
+        state.delay = delay.saturating_mul(2).min(reconnect_backoff.max);
+
+        let status_code = ua::StatusCode::new(unsafe {
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`. This reuses the
+            // endpoint URL from the initial connect and does not modify the config.
+            __UA_Client_connect(self.as_ptr().cast_mut(), true)
+        });
+        if let Err(error) = Error::verify_good(&status_code) {
+            log::warn!("Error when reconnecting: {error}");
+        }
+
+        ua::StatusCode::GOOD
+    }
+
+    /// Stops reconnecting with [`ReconnectBackoff`].
+    ///
+    /// This must be called before disconnecting on purpose. Otherwise, the disconnect would be
+    /// treated as lost connection and the client would reconnect.
+    pub(crate) fn stop_reconnect(&self) {
+        if let Some(reconnect_backoff) = &self.1 {
+            let mut state = reconnect_backoff
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            state.stopped = true;
+        }
+    }
+
+    /// Resumes reconnecting with [`ReconnectBackoff`].
+    ///
+    /// This undoes [`stop_reconnect()`](Self::stop_reconnect) when the client is connected again.
+    /// Subscriptions from before the disconnect are not re-created in the new session.
+    pub(crate) fn resume_reconnect(&mut self) {
+        if let Some(reconnect_backoff) = &mut self.1 {
+            let state = reconnect_backoff
+                .state
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner);
+            *state = BackoffState::new(reconnect_backoff.min);
+            reconnect_backoff.subscriptions = SubscriptionRegistry::default();
+        }
+    }
+
     /// Disconnects from endpoint.
-    pub(crate) fn disconnect(mut self) {
+    ///
+    /// This does not consume the client: it may still be shared with a background thread that runs
+    /// the event loop. Such a thread terminates by itself after the disconnect.
+    pub(crate) fn disconnect(&self) {
         log::info!("Disconnecting from endpoint");
 
+        self.stop_reconnect();
+
         let status_code = ua::StatusCode::new(unsafe {
-            // SAFETY: We retain ownership of `self`.
-            UA_Client_disconnect(self.as_mut_ptr())
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            UA_Client_disconnect(self.as_ptr().cast_mut())
         });
         if let Err(error) = Error::verify_good(&status_code) {
             log::warn!("Error while disconnecting client: {error}");
@@ -117,7 +287,12 @@ impl Drop for Client {
         // `UA_Client_delete()` matches `UA_Client_new()`. This may block (!) whenever the client is
         // still connected, for as long as it takes to take down the connection. This can be avoided
         // by calling `disconnect()` instead of simply dropping the client.
+        //
+        // The state callback may still be called while deleting, release its context afterwards.
+        let client_context = unsafe { (*UA_Client_getConfig(self.as_mut_ptr())).clientContext };
         unsafe { UA_Client_delete(self.as_mut_ptr()) }
+        // SAFETY: The client context has been set by `ua::ClientConfig` (if at all).
+        unsafe { client_config::release_client_context(client_context) };
     }
 }
 
@@ -126,6 +301,56 @@ impl Default for Client {
     fn default() -> Self {
         // `UA_Client_new()` matches `UA_Client_delete()`.
         let inner = NonNull::new(unsafe { UA_Client_new() }).expect("create UA_Client");
-        Self(inner)
+        Self(inner, None)
+    }
+}
+
+/// Exponential backoff for reconnecting [`Client`].
+///
+/// The first attempt is made `min` after the connection has been lost. The delay doubles with each
+/// failed attempt, up to `max`, and is reset once the session has been activated again.
+#[derive(Debug)]
+pub(crate) struct ReconnectBackoff {
+    min: Duration,
+    max: Duration,
+    state: Mutex<BackoffState>,
+    /// Subscriptions to re-create when a new session has been activated.
+    subscriptions: SubscriptionRegistry,
+}
+
+impl ReconnectBackoff {
+    /// Creates backoff with given delays.
+    ///
+    /// When `max` is less than `min`, `min` is used for both.
+    pub(crate) fn new(min: Duration, max: Duration) -> Self {
+        Self {
+            min,
+            max: max.max(min),
+            state: Mutex::new(BackoffState::new(min)),
+            subscriptions: SubscriptionRegistry::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BackoffState {
+    /// Delay before the next attempt.
+    delay: Duration,
+    /// Time of the next attempt, once the connection has been found to be lost.
+    next_attempt: Option<Instant>,
+    /// Set when the client has been disconnected on purpose.
+    stopped: bool,
+    /// Set while the session is activated.
+    activated: bool,
+}
+
+impl BackoffState {
+    const fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            next_attempt: None,
+            stopped: false,
+            activated: false,
+        }
     }
 }
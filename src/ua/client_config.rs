@@ -1,9 +1,21 @@
-use std::{fmt, mem::MaybeUninit};
+use std::{
+    ffi::c_void,
+    fmt,
+    mem::MaybeUninit,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
 
-use open62541_sys::{UA_ClientConfig, UA_ClientConfig_clear, UA_ClientConfig_setDefault};
+use open62541_sys::{
+    UA_Client, UA_ClientConfig, UA_ClientConfig_clear, UA_ClientConfig_setDefault,
+    UA_Client_getConfig, UA_SecureChannelState, UA_SessionState, UA_StatusCode,
+};
 
 use crate::{ua, Error};
 
+/// Callback for changes of client state.
+pub(crate) type StateCallback =
+    Box<dyn FnMut(ua::SecureChannelState, ua::SessionState, ua::StatusCode) + Send>;
+
 pub(crate) struct ClientConfig(Option<UA_ClientConfig>);
 
 impl ClientConfig {
@@ -67,6 +79,55 @@ impl ClientConfig {
         // PANIC: The inner object can only be unset when ownership has been given away.
         self.0.as_mut().expect("should have client config")
     }
+
+    /// Sets callback for changes of client state.
+    ///
+    /// This replaces any previous callback. The callback is stored as client context: it is released
+    /// along with the config, or with the client that has been created from it.
+    pub(crate) fn set_state_callback(&mut self, callback: StateCallback) {
+        let config = unsafe { self.as_mut() };
+        // SAFETY: The client context is only ever set here.
+        unsafe { release_client_context(config.clientContext) };
+        config.clientContext = Box::into_raw(Box::new(callback)).cast::<c_void>();
+        config.stateCallback = Some(state_callback_c);
+    }
+}
+
+/// Releases client context.
+///
+/// # Safety
+///
+/// The client context must be unset (null pointer) or have been set by
+/// [`ClientConfig::set_state_callback()`]. It must not be released more than once.
+pub(crate) unsafe fn release_client_context(client_context: *mut c_void) {
+    if !client_context.is_null() {
+        // SAFETY: Client context is the result of `Box::into_raw()` in `set_state_callback()`.
+        drop(unsafe { Box::from_raw(client_context.cast::<StateCallback>()) });
+    }
+}
+
+unsafe extern "C" fn state_callback_c(
+    client: *mut UA_Client,
+    channel_state: UA_SecureChannelState,
+    session_state: UA_SessionState,
+    connect_status: UA_StatusCode,
+) {
+    // SAFETY: The config is always set for a client.
+    let client_context = unsafe { (*UA_Client_getConfig(client)).clientContext };
+    if client_context.is_null() {
+        return;
+    }
+    // SAFETY: Client context has been set by `set_state_callback()`. The callback is called with the
+    // client lock held, we have exclusive access.
+    let callback = unsafe { &mut *client_context.cast::<StateCallback>() };
+    let mut callback = AssertUnwindSafe(callback);
+
+    let channel_state = ua::SecureChannelState::from_raw(channel_state);
+    let session_state = ua::SessionState::from_raw(session_state);
+    let connect_status = ua::StatusCode::new(connect_status);
+    if let Err(err) = catch_unwind(move || callback(channel_state, session_state, connect_status)) {
+        log::error!("State callback panicked: {err:?}");
+    }
 }
 
 impl Drop for ClientConfig {
@@ -74,7 +135,10 @@ impl Drop for ClientConfig {
         // Check if we still hold the client config. If not, we need not clean up: the ownership has
         // passed to the client that was created from this config.
         if let Some(mut inner) = self.0.take() {
+            let client_context = inner.clientContext;
             unsafe { UA_ClientConfig_clear(&mut inner) }
+            // SAFETY: We own the client context, it is not used by anything else.
+            unsafe { release_client_context(client_context) };
         }
     }
 }
@@ -24,31 +24,58 @@ mod create_monitored_items_request;
 mod create_monitored_items_response;
 mod create_subscription_request;
 mod create_subscription_respones;
+mod data_change_filter;
+mod data_change_trigger;
 mod data_value;
 mod date_time;
+mod deadband_type;
 mod delete_monitored_items_request;
 mod delete_monitored_items_response;
 mod delete_subscriptions_request;
 mod delete_subscriptions_response;
+mod diagnostic_info;
+mod endpoint_description;
+mod eu_information;
+mod event_filter;
 mod expanded_node_id;
 mod extension_object;
+mod guid;
+mod history_data;
 mod localized_text;
+mod message_security_mode;
+mod modify_monitored_items_request;
+mod modify_monitored_items_response;
 mod monitored_item_create_request;
 mod monitored_item_create_result;
+mod monitored_item_modify_request;
+mod monitored_item_modify_result;
+mod monitoring_mode;
+mod monitoring_parameters;
 mod node_attributes;
 mod node_class;
 mod node_id;
 mod node_id_type;
 mod qualified_name;
+mod range;
 mod read_request;
 mod read_response;
 mod read_value_id;
 mod reference_description;
+mod register_nodes_request;
+mod register_nodes_response;
 mod relative_path;
 mod relative_path_element;
+mod response_header;
+mod set_monitoring_mode_request;
+mod set_monitoring_mode_response;
+mod simple_attribute_operand;
 mod status_code;
 mod string;
 mod timestamps_to_return;
+mod translate_browse_paths_to_node_ids_request;
+mod translate_browse_paths_to_node_ids_response;
+mod unregister_nodes_request;
+mod unregister_nodes_response;
 mod user_name_identity_token;
 mod variant;
 mod write_request;
@@ -80,37 +107,64 @@ pub use self::{
     create_monitored_items_response::CreateMonitoredItemsResponse,
     create_subscription_request::CreateSubscriptionRequest,
     create_subscription_respones::CreateSubscriptionResponse,
+    data_change_filter::DataChangeFilter,
+    data_change_trigger::DataChangeTrigger,
     data_value::DataValue,
     date_time::DateTime,
+    deadband_type::DeadbandType,
     delete_monitored_items_request::DeleteMonitoredItemsRequest,
     delete_monitored_items_response::DeleteMonitoredItemsResponse,
     delete_subscriptions_request::DeleteSubscriptionsRequest,
     delete_subscriptions_response::DeleteSubscriptionsResponse,
+    diagnostic_info::DiagnosticInfo,
+    endpoint_description::EndpointDescription,
+    eu_information::EUInformation,
+    event_filter::EventFilter,
     expanded_node_id::ExpandedNodeId,
     extension_object::ExtensionObject,
+    guid::Guid,
+    history_data::HistoryData,
     localized_text::LocalizedText,
+    message_security_mode::MessageSecurityMode,
+    modify_monitored_items_request::ModifyMonitoredItemsRequest,
+    modify_monitored_items_response::ModifyMonitoredItemsResponse,
     monitored_item_create_request::MonitoredItemCreateRequest,
     monitored_item_create_result::MonitoredItemCreateResult,
+    monitored_item_modify_request::MonitoredItemModifyRequest,
+    monitored_item_modify_result::MonitoredItemModifyResult,
+    monitoring_mode::MonitoringMode,
+    monitoring_parameters::MonitoringParameters,
     node_attributes::{
         DataTypeAttributes, MethodAttributes, NodeAttributes, ObjectAttributes,
         ObjectTypeAttributes, ReferenceTypeAttributes, VariableAttributes, VariableTypeAttributes,
         ViewAttributes,
     },
     node_class::NodeClass,
-    node_id::NodeId,
+    node_id::{NodeId, NodeIdParseError},
     node_id_type::NodeIdType,
     qualified_name::QualifiedName,
+    range::Range,
     read_request::ReadRequest,
     read_response::ReadResponse,
     read_value_id::ReadValueId,
     reference_description::ReferenceDescription,
+    register_nodes_request::RegisterNodesRequest,
+    register_nodes_response::RegisterNodesResponse,
     relative_path::RelativePath,
     relative_path_element::RelativePathElement,
+    response_header::ResponseHeader,
+    set_monitoring_mode_request::SetMonitoringModeRequest,
+    set_monitoring_mode_response::SetMonitoringModeResponse,
+    simple_attribute_operand::SimpleAttributeOperand,
     status_code::StatusCode,
     string::String,
     timestamps_to_return::TimestampsToReturn,
+    translate_browse_paths_to_node_ids_request::TranslateBrowsePathsToNodeIdsRequest,
+    translate_browse_paths_to_node_ids_response::TranslateBrowsePathsToNodeIdsResponse,
+    unregister_nodes_request::UnregisterNodesRequest,
+    unregister_nodes_response::UnregisterNodesResponse,
     user_name_identity_token::UserNameIdentityToken,
-    variant::Variant,
+    variant::{TypeMismatchError, Variant},
     write_request::WriteRequest,
     write_response::WriteResponse,
     write_value::WriteValue,
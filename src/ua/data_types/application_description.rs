@@ -93,4 +93,35 @@ impl ApplicationDescription {
             .move_into_raw(&mut self.0.discoveryUrlsSize, &mut self.0.discoveryUrls);
         self
     }
+
+    /// Gets application URI.
+    #[must_use]
+    pub fn application_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.applicationUri)
+    }
+
+    /// Gets product URI.
+    #[must_use]
+    pub fn product_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.productUri)
+    }
+
+    /// Gets application name.
+    #[must_use]
+    pub fn application_name(&self) -> &ua::LocalizedText {
+        ua::LocalizedText::raw_ref(&self.0.applicationName)
+    }
+
+    /// Gets application type.
+    #[must_use]
+    pub fn application_type(&self) -> &ua::ApplicationType {
+        ua::ApplicationType::raw_ref(&self.0.applicationType)
+    }
+
+    /// Gets discovery URLs.
+    #[must_use]
+    pub fn discovery_urls(&self) -> Option<ua::Array<ua::String>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.discoveryUrlsSize, self.0.discoveryUrls)
+    }
 }
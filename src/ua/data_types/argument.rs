@@ -1,8 +1,43 @@
-use crate::{ua, DataType, ValueType};
+use crate::{ua, DataType, Error, ValueType};
 
 crate::data_type!(Argument);
 
 impl Argument {
+    /// Creates argument description.
+    ///
+    /// Use `-1` as `value_rank` for scalar arguments, and `1` for one-dimensional arrays. Array
+    /// dimensions remain unset.
+    ///
+    /// ```
+    /// use open62541::ua;
+    /// use open62541_sys::UA_NS0ID_DOUBLE;
+    ///
+    /// let argument = ua::Argument::new(
+    ///     "setpoint",
+    ///     &ua::NodeId::ns0(UA_NS0ID_DOUBLE),
+    ///     -1,
+    ///     &ua::LocalizedText::new("en-US", "Target temperature")?,
+    /// )?;
+    /// assert_eq!(argument.name().as_str(), Some("setpoint"));
+    /// # Ok::<(), open62541::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The name must not contain any NUL bytes.
+    pub fn new(
+        name: &str,
+        data_type: &ua::NodeId,
+        value_rank: i32,
+        description: &ua::LocalizedText,
+    ) -> Result<Self, Error> {
+        Ok(Self::init()
+            .with_name(&ua::String::new(name)?)
+            .with_data_type(data_type)
+            .with_value_rank(value_rank)
+            .with_description(description))
+    }
+
     #[must_use]
     pub fn with_name(mut self, name: &ua::String) -> Self {
         name.clone_into_raw(&mut self.0.name);
@@ -58,3 +93,37 @@ impl Argument {
         ValueType::from_data_type(self.data_type())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use open62541_sys::UA_NS0ID_INT32;
+
+    use crate::{ua, ValueType};
+
+    #[test]
+    fn create_argument() {
+        let description = ua::LocalizedText::new("en-US", "Number of retries").unwrap();
+        let argument = ua::Argument::new(
+            "retries",
+            &ua::NodeId::ns0(UA_NS0ID_INT32),
+            -1,
+            &description,
+        )
+        .unwrap();
+
+        assert_eq!(argument.name().as_str(), Some("retries"));
+        assert_eq!(argument.data_type(), &ua::NodeId::ns0(UA_NS0ID_INT32));
+        assert_eq!(argument.value_type(), ValueType::Int32);
+        assert_eq!(argument.value_rank(), -1);
+        assert_eq!(argument.description(), &description);
+        assert_eq!(argument.array_dimensions(), None);
+
+        assert!(ua::Argument::new(
+            "re\0tries",
+            &ua::NodeId::ns0(UA_NS0ID_INT32),
+            -1,
+            &description
+        )
+        .is_err());
+    }
+}
@@ -8,6 +8,30 @@ impl BrowseResponse {
         // TODO: Adjust signature to return non-owned value instead.
         ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
     }
+
+    /// Gets diagnostic infos.
+    ///
+    /// When diagnostics have been requested, the size and order of the list matches the size and
+    /// order of the results. String fields of diagnostic infos refer to the
+    /// [`string_table()`](Self::string_table).
+    #[must_use]
+    pub fn diagnostics(&self) -> Option<ua::Array<ua::DiagnosticInfo>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.diagnosticInfosSize, self.0.diagnosticInfos)
+    }
+
+    /// Gets string table of response.
+    ///
+    /// This resolves string fields of the [`diagnostics()`](Self::diagnostics), e.g. with
+    /// [`ua::DiagnosticInfo::symbolic_id()`].
+    #[must_use]
+    pub fn string_table(&self) -> Option<ua::Array<ua::String>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(
+            self.0.responseHeader.stringTableSize,
+            self.0.responseHeader.stringTable,
+        )
+    }
 }
 
 impl ServiceResponse for BrowseResponse {
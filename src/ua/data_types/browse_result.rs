@@ -1,3 +1,5 @@
+use std::slice;
+
 use crate::{ua, DataType as _};
 
 crate::data_type!(BrowseResult);
@@ -8,10 +10,16 @@ impl BrowseResult {
         ua::StatusCode::new(self.0.statusCode)
     }
 
+    /// Gets references.
+    ///
+    /// This borrows the references from the result without copying them. It returns `None` when
+    /// the references have not been set at all, which some servers do instead of returning an empty
+    /// list. Iterate over `&self` to treat both cases alike.
     #[must_use]
-    pub fn references(&self) -> Option<ua::Array<ua::ReferenceDescription>> {
-        // TODO: Adjust signature to return non-owned value instead.
-        ua::Array::from_raw_parts(self.0.referencesSize, self.0.references)
+    pub fn references(&self) -> Option<&[ua::ReferenceDescription]> {
+        // SAFETY: We only borrow from `self` and do not give away ownership. The returned slice is
+        // tied to the lifetime of `self`.
+        unsafe { ua::Array::slice_from_raw_parts(self.0.referencesSize, self.0.references) }
     }
 
     /// Gets continuation point.
@@ -25,3 +33,26 @@ impl BrowseResult {
         ua::ContinuationPoint::new(ua::ByteString::raw_ref(&self.0.continuationPoint).clone())
     }
 }
+
+impl<'a> IntoIterator for &'a BrowseResult {
+    type Item = &'a ua::ReferenceDescription;
+
+    type IntoIter = slice::Iter<'a, ua::ReferenceDescription>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.references().unwrap_or(&[]).iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn iterate_references() {
+        // Unset references are treated like an empty list.
+        let result = ua::BrowseResult::init();
+        assert_eq!(result.references(), None);
+        assert_eq!(result.into_iter().count(), 0);
+    }
+}
@@ -1,6 +1,8 @@
 use std::slice;
 
-use crate::ArrayValue;
+use open62541_sys::UA_ByteString;
+
+use crate::{ArrayValue, DataType as _};
 
 // Technically, `open62541_sys::ByteString` is an alias for `open62541_sys::String`. But we treat it
 // as a distinct type to improve type safety. The difference is that `String` contains valid Unicode
@@ -11,6 +13,23 @@ crate::data_type!(ByteString);
 // strings of `length` 0. It may also be `ptr::null()` for "invalid" strings. This is similar to how
 // OPC UA treats arrays (which also distinguishes between empty and invalid instances).
 impl ByteString {
+    /// Creates byte string from bytes.
+    ///
+    /// This copies the bytes into a new allocation owned by `open62541`, i.e. the result may be
+    /// freed with `UA_ByteString_clear()` as usual.
+    #[must_use]
+    pub fn new(bytes: &[u8]) -> Self {
+        // SAFETY: This is a short-lived borrowed view into `bytes`. `UA_copy()` in `clone_raw()`
+        // only reads from the source and never frees it.
+        let src = UA_ByteString {
+            length: bytes.len(),
+            data: bytes.as_ptr().cast_mut(),
+        };
+        // `UA_copy()` turns an empty source into an empty (not invalid) byte string. This happens
+        // regardless of the (dangling) pointer that Rust uses for empty slices.
+        Self::clone_raw(&src)
+    }
+
     /// Checks if byte string is invalid.
     ///
     /// The invalid state is defined by OPC UA. It is a third state which is distinct from empty and
@@ -43,6 +62,15 @@ impl ByteString {
         }
     }
 
+    /// Returns byte string contents as slice.
+    ///
+    /// Other than [`as_bytes()`](Self::as_bytes), this does not distinguish between invalid and
+    /// empty byte strings: both return an empty slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        self.as_bytes().unwrap_or(&[])
+    }
+
     fn array_value(&self) -> ArrayValue<u8> {
         // Internally, `open62541` represents strings as `Byte` array and has the same special cases
         // as regular arrays, i.e. empty and invalid states.
@@ -50,6 +78,19 @@ impl ByteString {
     }
 }
 
+impl From<&[u8]> for ByteString {
+    fn from(value: &[u8]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Vec<u8>> for ByteString {
+    fn from(value: Vec<u8>) -> Self {
+        // We must copy the bytes: memory allocated by Rust cannot be freed by `UA_clear()`.
+        Self::new(&value)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for ByteString {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -61,3 +102,39 @@ impl serde::Serialize for ByteString {
             .and_then(|bytes| serializer.serialize_bytes(bytes))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn valid_byte_string() {
+        let byte_string = ua::ByteString::new(&[0x30, 0x82, 0x00, 0x01]);
+        assert!(!byte_string.is_invalid());
+        assert!(!byte_string.is_empty());
+        assert_eq!(byte_string.as_bytes(), Some(&[0x30, 0x82, 0x00, 0x01][..]));
+        assert_eq!(byte_string.as_slice(), &[0x30, 0x82, 0x00, 0x01]);
+
+        let byte_string = ua::ByteString::from(vec![1, 2, 3]);
+        assert_eq!(byte_string.as_slice(), &[1, 2, 3]);
+        assert_eq!(byte_string.clone(), ua::ByteString::from(&[1, 2, 3][..]));
+    }
+
+    #[test]
+    fn empty_byte_string() {
+        let byte_string = ua::ByteString::new(&[]);
+        assert!(!byte_string.is_invalid());
+        assert!(byte_string.is_empty());
+        assert_eq!(byte_string.as_bytes(), Some(&[][..]));
+        assert_eq!(byte_string.as_slice(), &[]);
+    }
+
+    #[test]
+    fn invalid_byte_string() {
+        let byte_string = ua::ByteString::init();
+        assert!(byte_string.is_invalid());
+        assert!(!byte_string.is_empty());
+        assert_eq!(byte_string.as_bytes(), None);
+        assert_eq!(byte_string.as_slice(), &[]);
+    }
+}
@@ -1,10 +1,64 @@
 use open62541_sys::UA_CreateSubscriptionRequest_default;
 
+use crate::{ua, ServiceRequest};
+
 crate::data_type!(CreateSubscriptionRequest);
 
+impl CreateSubscriptionRequest {
+    /// Sets requested publishing interval (in milliseconds).
+    #[must_use]
+    pub const fn with_requested_publishing_interval(
+        mut self,
+        requested_publishing_interval: f64,
+    ) -> Self {
+        self.0.requestedPublishingInterval = requested_publishing_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_requested_lifetime_count(mut self, requested_lifetime_count: u32) -> Self {
+        self.0.requestedLifetimeCount = requested_lifetime_count;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_requested_max_keep_alive_count(
+        mut self,
+        requested_max_keep_alive_count: u32,
+    ) -> Self {
+        self.0.requestedMaxKeepAliveCount = requested_max_keep_alive_count;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_notifications_per_publish(
+        mut self,
+        max_notifications_per_publish: u32,
+    ) -> Self {
+        self.0.maxNotificationsPerPublish = max_notifications_per_publish;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_publishing_enabled(mut self, publishing_enabled: bool) -> Self {
+        self.0.publishingEnabled = publishing_enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.0.priority = priority;
+        self
+    }
+}
+
 impl Default for CreateSubscriptionRequest {
     fn default() -> Self {
         let inner = unsafe { UA_CreateSubscriptionRequest_default() };
         Self(inner)
     }
 }
+
+impl ServiceRequest for CreateSubscriptionRequest {
+    type Response = ua::CreateSubscriptionResponse;
+}
@@ -1,4 +1,4 @@
-use crate::ua;
+use crate::{ua, ServiceResponse};
 
 crate::data_type!(CreateSubscriptionResponse);
 
@@ -7,4 +7,28 @@ impl CreateSubscriptionResponse {
     pub const fn subscription_id(&self) -> ua::SubscriptionId {
         ua::SubscriptionId::new(self.0.subscriptionId)
     }
+
+    /// Gets revised publishing interval (in milliseconds).
+    #[must_use]
+    pub const fn revised_publishing_interval(&self) -> f64 {
+        self.0.revisedPublishingInterval
+    }
+
+    #[must_use]
+    pub const fn revised_lifetime_count(&self) -> u32 {
+        self.0.revisedLifetimeCount
+    }
+
+    #[must_use]
+    pub const fn revised_max_keep_alive_count(&self) -> u32 {
+        self.0.revisedMaxKeepAliveCount
+    }
+}
+
+impl ServiceResponse for CreateSubscriptionResponse {
+    type Request = ua::CreateSubscriptionRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
 }
@@ -0,0 +1,51 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(DataChangeFilter);
+
+impl DataChangeFilter {
+    /// Creates filter with deadband.
+    ///
+    /// Notifications are sent when the status or the value changes. With
+    /// [`ua::DeadbandType::ABSOLUTE`], changes of numeric values count only when they exceed
+    /// `deadband_value`. With [`ua::DeadbandType::PERCENT`], changes count only when they exceed
+    /// the given percentage of the variable's `EURange` property.
+    #[must_use]
+    pub fn deadband(deadband_type: &ua::DeadbandType, deadband_value: f64) -> Self {
+        Self::init()
+            .with_trigger(&ua::DataChangeTrigger::STATUSVALUE)
+            .with_deadband_type(deadband_type)
+            .with_deadband_value(deadband_value)
+    }
+
+    /// Sets trigger.
+    ///
+    /// This selects the changes that are reported: status only, status and value (the default of
+    /// the server when no filter is given), or status, value, and source timestamp.
+    #[must_use]
+    pub fn with_trigger(mut self, trigger: &ua::DataChangeTrigger) -> Self {
+        trigger.clone_into_raw(&mut self.0.trigger);
+        self
+    }
+
+    #[must_use]
+    pub fn with_deadband_type(mut self, deadband_type: &ua::DeadbandType) -> Self {
+        self.0.deadbandType = deadband_type.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_deadband_value(mut self, deadband_value: f64) -> Self {
+        self.0.deadbandValue = deadband_value;
+        self
+    }
+
+    #[must_use]
+    pub fn trigger(&self) -> &ua::DataChangeTrigger {
+        ua::DataChangeTrigger::raw_ref(&self.0.trigger)
+    }
+
+    #[must_use]
+    pub const fn deadband_value(&self) -> f64 {
+        self.0.deadbandValue
+    }
+}
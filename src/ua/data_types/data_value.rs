@@ -3,6 +3,9 @@ use crate::{ua, DataType, Result};
 crate::data_type!(DataValue);
 
 impl DataValue {
+    /// Creates data value with value.
+    ///
+    /// This sets only the value. Status code and timestamps are left unset.
     #[must_use]
     pub fn new(value: ua::Variant) -> Self {
         let mut inner = ua::DataValue::init();
@@ -30,6 +33,27 @@ impl DataValue {
         self
     }
 
+    /// Sets source timestamp.
+    ///
+    /// This is the time when the value was last changed at its source.
+    #[must_use]
+    pub fn with_source_timestamp(mut self, source_timestamp: &ua::DateTime) -> Self {
+        source_timestamp.clone_into_raw(&mut self.0.sourceTimestamp);
+        self.0.set_hasSourceTimestamp(true);
+        self
+    }
+
+    /// Sets server timestamp.
+    ///
+    /// This is the time when the server last received the value or confirmed that it has not
+    /// changed.
+    #[must_use]
+    pub fn with_server_timestamp(mut self, server_timestamp: &ua::DateTime) -> Self {
+        server_timestamp.clone_into_raw(&mut self.0.serverTimestamp);
+        self.0.set_hasServerTimestamp(true);
+        self
+    }
+
     /// Gets value.
     ///
     /// This returns the value as [`ua::Variant`] if it is set. Returns `None` when the `DataValue`
@@ -41,6 +65,9 @@ impl DataValue {
             .then(|| ua::Variant::raw_ref(&self.0.value))
     }
 
+    /// Gets source timestamp.
+    ///
+    /// This is the time when the value was last changed at its source.
     #[must_use]
     pub fn source_timestamp(&self) -> Option<&ua::DateTime> {
         self.0
@@ -48,6 +75,10 @@ impl DataValue {
             .then(|| ua::DateTime::raw_ref(&self.0.sourceTimestamp))
     }
 
+    /// Gets server timestamp.
+    ///
+    /// This is the time when the server last received the value or confirmed that it has not
+    /// changed.
     #[must_use]
     pub fn server_timestamp(&self) -> Option<&ua::DateTime> {
         self.0
@@ -69,6 +100,10 @@ impl DataValue {
             .then_some(self.0.serverPicoseconds)
     }
 
+    /// Gets status code.
+    ///
+    /// This returns `None` when the status code has been omitted. See [`status()`](Self::status)
+    /// to get the effective status code instead.
     #[must_use]
     pub fn status_code(&self) -> Option<ua::StatusCode> {
         self.0
@@ -76,7 +111,224 @@ impl DataValue {
             .then(|| ua::StatusCode::new(self.0.status))
     }
 
+    /// Gets effective status code.
+    ///
+    /// The OPC UA specification allows omitting the status code for good values. In this case,
+    /// this returns [`ua::StatusCode::GOOD`].
+    ///
+    /// Use this to check the quality of the value before accessing it: a data value with a bad
+    /// status code often comes without value.
+    #[must_use]
+    pub fn status(&self) -> ua::StatusCode {
+        self.status_code().unwrap_or(ua::StatusCode::GOOD)
+    }
+
+    /// Encodes data value with OPC UA JSON encoding.
+    ///
+    /// This uses the reversible encoding defined by the OPC UA specification. See
+    /// [`ua::Variant::to_json()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the data value cannot be encoded.
+    pub fn to_json(&self) -> Result<String> {
+        crate::encoding::encode_json(self)
+    }
+
     pub(crate) fn to_generic<T: DataType>(&self) -> Result<crate::DataValue<T>> {
         crate::DataValue::new(self)
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+
+    use serde::{de, ser::SerializeStruct as _};
+
+    use crate::{ua, DataType as _};
+
+    use super::DataValue;
+
+    const FIELDS: &[&str] = &[
+        "value",
+        "status_code",
+        #[cfg(feature = "time")]
+        "source_timestamp",
+        #[cfg(feature = "time")]
+        "server_timestamp",
+    ];
+
+    /// Serializes data value.
+    ///
+    /// Fields that are not set in the data value are omitted. The status code is serialized as its
+    /// numeric code. Timestamps are serialized only with feature `time`.
+    impl serde::Serialize for DataValue {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let mut state = serializer.serialize_struct("DataValue", FIELDS.len())?;
+            if let Some(value) = self.value() {
+                state.serialize_field("value", value)?;
+            } else {
+                state.skip_field("value")?;
+            }
+            if let Some(status_code) = self.status_code() {
+                state.serialize_field("status_code", &status_code.code())?;
+            } else {
+                state.skip_field("status_code")?;
+            }
+            #[cfg(feature = "time")]
+            if let Some(source_timestamp) = self.source_timestamp() {
+                state.serialize_field("source_timestamp", source_timestamp)?;
+            } else {
+                state.skip_field("source_timestamp")?;
+            }
+            #[cfg(feature = "time")]
+            if let Some(server_timestamp) = self.server_timestamp() {
+                state.serialize_field("server_timestamp", server_timestamp)?;
+            } else {
+                state.skip_field("server_timestamp")?;
+            }
+            state.end()
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for DataValue {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("DataValue", FIELDS, DataValueVisitor)
+        }
+    }
+
+    struct DataValueVisitor;
+
+    impl<'de> de::Visitor<'de> for DataValueVisitor {
+        type Value = DataValue;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an OPC UA data value")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut data_value = DataValue::init();
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "value" => {
+                        map.next_value::<ua::Variant>()?
+                            .move_into_raw(&mut data_value.0.value);
+                        data_value.0.set_hasValue(true);
+                    }
+                    "status_code" => {
+                        data_value.0.status = map.next_value()?;
+                        data_value.0.set_hasStatus(true);
+                    }
+                    #[cfg(feature = "time")]
+                    "source_timestamp" => {
+                        map.next_value::<ua::DateTime>()?
+                            .move_into_raw(&mut data_value.0.sourceTimestamp);
+                        data_value.0.set_hasSourceTimestamp(true);
+                    }
+                    #[cfg(feature = "time")]
+                    "server_timestamp" => {
+                        map.next_value::<ua::DateTime>()?
+                            .move_into_raw(&mut data_value.0.serverTimestamp);
+                        data_value.0.set_hasServerTimestamp(true);
+                    }
+                    _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                }
+            }
+
+            Ok(data_value)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::{ua, DataType as _};
+
+        #[test]
+        fn json_serialization() {
+            let data_value = ua::DataValue::new(ua::Variant::scalar(ua::Double::new(1.5)));
+            let json = serde_json::to_string(&data_value).expect("should serialize data value");
+            assert_eq!(json, r#"{"value":1.5}"#);
+
+            let data_value: ua::DataValue =
+                serde_json::from_str(&json).expect("should deserialize data value");
+            assert_eq!(
+                data_value
+                    .value()
+                    .and_then(ua::Variant::as_scalar::<ua::Double>),
+                Some(&ua::Double::new(1.5))
+            );
+            assert_eq!(data_value.status_code(), None);
+
+            let data_value =
+                ua::DataValue::init().with_status_code(&ua::StatusCode::BADNOTREADABLE);
+            let json = serde_json::to_string(&data_value).expect("should serialize data value");
+            assert_eq!(json, r#"{"status_code":2151284736}"#);
+
+            let data_value: ua::DataValue =
+                serde_json::from_str(&json).expect("should deserialize data value");
+            assert_eq!(data_value.value(), None);
+            assert_eq!(
+                data_value.status_code(),
+                Some(ua::StatusCode::BADNOTREADABLE)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn value_and_status() {
+        let data_value = ua::DataValue::new(ua::Variant::scalar(ua::UInt16::new(123)));
+        assert_eq!(
+            data_value
+                .value()
+                .and_then(ua::Variant::as_scalar::<ua::UInt16>),
+            Some(&ua::UInt16::new(123))
+        );
+        assert_eq!(data_value.status_code(), None);
+        assert_eq!(data_value.status(), ua::StatusCode::GOOD);
+        assert_eq!(data_value.source_timestamp(), None);
+        assert_eq!(data_value.server_timestamp(), None);
+        assert!(data_value.to_generic::<ua::UInt16>().is_ok());
+    }
+
+    #[test]
+    fn json_encoding() {
+        let data_value = ua::DataValue::new(ua::Variant::scalar(ua::Float::new(1.5)))
+            .with_status_code(&ua::StatusCode::BADNOTREADABLE);
+        assert_eq!(
+            data_value.to_json().expect("should encode data value"),
+            r#"{"Value":{"Type":10,"Body":1.5},"Status":2151284736}"#
+        );
+    }
+
+    #[test]
+    fn bad_status_without_value() {
+        let data_value = ua::DataValue::init().with_status_code(&ua::StatusCode::BADNOTREADABLE);
+        assert_eq!(data_value.value(), None);
+        assert_eq!(
+            data_value.status_code(),
+            Some(ua::StatusCode::BADNOTREADABLE)
+        );
+        assert_eq!(data_value.status(), ua::StatusCode::BADNOTREADABLE);
+
+        let error = data_value
+            .to_generic::<ua::Variant>()
+            .expect_err("should not convert bad value");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNOTREADABLE);
+    }
+}
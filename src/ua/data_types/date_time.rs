@@ -1,25 +1,107 @@
+use std::time::{Duration, SystemTime};
+
+use open62541_sys::UA_DATETIME_UNIX_EPOCH;
+
+use crate::{Error, Result};
+
 crate::data_type!(DateTime);
 
+/// Number of nanoseconds per tick of [`DateTime`].
+const NANOS_PER_TICK: i128 = 100;
+
 impl DateTime {
+    /// Creates [`DateTime`] from nanoseconds since Unix epoch.
+    ///
+    /// Negative values refer to points in time before 1970-01-01. `DateTime` has a resolution of
+    /// 100 nanoseconds, the remainder is truncated (towards the past).
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// // 2024-02-09 12:34:56 UTC
+    /// let dt = ua::DateTime::from_unix_nanos(1_707_482_096_000_000_000).unwrap();
+    /// assert_eq!(dt.to_unix_nanos(), 1_707_482_096_000_000_000);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The date/time must be in range of the 64-bit representation of [`DateTime`].
+    pub fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        // OPC UA encodes `DateTime` as Windows file time: a 64-bit value that represents the number
+        // of 100-nanosecond intervals that have elapsed since 12:00 A.M. January 1, 1601 (UTC).
+        let ticks_unix = nanos.div_euclid(NANOS_PER_TICK);
+        let ticks_ua = ticks_unix + i128::from(UA_DATETIME_UNIX_EPOCH);
+
+        i64::try_from(ticks_ua)
+            .map_err(|_| Error::internal("DateTime should be in range"))
+            .map(Self)
+    }
+
+    /// Gets nanoseconds since Unix epoch.
+    ///
+    /// Negative values refer to points in time before 1970-01-01. This conversion is lossless.
+    #[must_use]
+    pub fn to_unix_nanos(&self) -> i128 {
+        // OPC UA encodes `DateTime` as Windows file time: a 64-bit value that represents the number
+        // of 100-nanosecond intervals that have elapsed since 12:00 A.M. January 1, 1601 (UTC).
+        let ticks_unix = i128::from(self.0) - i128::from(UA_DATETIME_UNIX_EPOCH);
+        ticks_unix * NANOS_PER_TICK
+    }
+
+    /// Converts to [`SystemTime`].
+    ///
+    /// This returns `None` when the value is out of range for [`SystemTime`] on this platform.
+    #[must_use]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let nanos_unix = self.to_unix_nanos();
+        let duration = duration_from_nanos(nanos_unix.unsigned_abs())?;
+
+        if nanos_unix >= 0 {
+            SystemTime::UNIX_EPOCH.checked_add(duration)
+        } else {
+            SystemTime::UNIX_EPOCH.checked_sub(duration)
+        }
+    }
+
     #[cfg(feature = "time")]
     #[must_use]
     pub fn to_utc(&self) -> Option<time::OffsetDateTime> {
-        use open62541_sys::{UA_DATETIME_UNIX_EPOCH, UA_DATETIME_USEC};
+        time::OffsetDateTime::from_unix_timestamp_nanos(self.to_unix_nanos()).ok()
+    }
+}
 
-        // OPC UA encodes `DateTime` as Windows file time: a 64-bit value that represents the number
-        // of 100-nanosecond intervals that have elapsed since 12:00 A.M. January 1, 1601 (UTC).
-        let ticks_ua = i128::from(self.0);
-        let ticks_unix = ticks_ua - i128::from(UA_DATETIME_UNIX_EPOCH);
-        let nanos_unix = ticks_unix * i128::from(1000 / UA_DATETIME_USEC);
+impl TryFrom<SystemTime> for DateTime {
+    type Error = Error;
 
-        time::OffsetDateTime::from_unix_timestamp_nanos(nanos_unix).ok()
+    /// Creates [`DateTime`] from [`SystemTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use open62541::ua;
+    /// use std::time::SystemTime;
+    ///
+    /// let dt: ua::DateTime = SystemTime::now().try_into().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The date/time must be in range of the 64-bit representation of [`DateTime`].
+    fn try_from(from: SystemTime) -> Result<Self> {
+        let nanos_unix = match from.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => i128::try_from(duration.as_nanos()),
+            // Point in time lies before Unix epoch.
+            Err(error) => i128::try_from(error.duration().as_nanos()).map(|nanos| -nanos),
+        }
+        .map_err(|_| Error::internal("DateTime should be in range"))?;
+
+        Self::from_unix_nanos(nanos_unix)
     }
 }
 
 #[cfg(feature = "time")]
 impl TryFrom<time::OffsetDateTime> for DateTime {
-    // Explicit module path to avoid linter errors when feature is not enable by `#[cfg()]`.
-    type Error = crate::Error;
+    type Error = Error;
 
     /// Creates [`DateTime`] from [`time::OffsetDateTime`].
     ///
@@ -35,25 +117,27 @@ impl TryFrom<time::OffsetDateTime> for DateTime {
     /// # Errors
     ///
     /// The date/time must be valid and in range of the 64-bit representation of [`DateTime`].
-    fn try_from(from: time::OffsetDateTime) -> Result<Self, Self::Error> {
-        use open62541_sys::{UA_DATETIME_UNIX_EPOCH, UA_DATETIME_USEC};
+    fn try_from(from: time::OffsetDateTime) -> Result<Self> {
+        Self::from_unix_nanos(from.unix_timestamp_nanos())
+    }
+}
 
-        // OPC UA encodes `DateTime` as Windows file time: a 64-bit value that represents the number
-        // of 100-nanosecond intervals that have elapsed since 12:00 A.M. January 1, 1601 (UTC).
-        let nanos_unix = from.unix_timestamp_nanos();
-        let ticks_unix = nanos_unix / i128::from(1000 / UA_DATETIME_USEC);
-        let ticks_ua = ticks_unix + i128::from(UA_DATETIME_UNIX_EPOCH);
+/// Creates [`Duration`] from nanoseconds.
+///
+/// This returns `None` when the value is out of range.
+fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
 
-        i64::try_from(ticks_ua)
-            // Explicit module path to avoid linter errors when feature is not enable by `#[cfg()]`.
-            .map_err(|_| crate::Error::internal("DateTime should be in range"))
-            .map(Self)
-    }
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+    // PANIC: The remainder is always less than `NANOS_PER_SEC` and thus fits into `u32`.
+    let nanos = u32::try_from(nanos % NANOS_PER_SEC).expect("remainder should fit into u32");
+
+    Some(Duration::new(secs, nanos))
 }
 
 #[cfg(all(feature = "serde", feature = "time"))]
 impl serde::Serialize for DateTime {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
@@ -63,8 +147,71 @@ impl serde::Serialize for DateTime {
     }
 }
 
+#[cfg(all(feature = "serde", feature = "time"))]
+impl<'de> serde::Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let dt = time::serde::rfc3339::deserialize(deserializer)?;
+        Self::from_unix_nanos(dt.unix_timestamp_nanos()).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use open62541_sys::UA_DATETIME_UNIX_EPOCH;
+
+    use crate::ua;
+
+    #[test]
+    fn unix_epoch() {
+        let dt = ua::DateTime::from_unix_nanos(0).unwrap();
+        assert_eq!(dt.0, UA_DATETIME_UNIX_EPOCH);
+        assert_eq!(dt.to_unix_nanos(), 0);
+        assert_eq!(dt.to_system_time(), Some(SystemTime::UNIX_EPOCH));
+    }
+
+    #[test]
+    fn known_timestamp() {
+        // 2024-02-09 12:34:56.1234567 UTC as Windows file time.
+        let dt = ua::DateTime::from_unix_nanos(1_707_482_096_123_456_700).unwrap();
+        assert_eq!(dt.0, 133_519_556_961_234_567);
+
+        let system_time = SystemTime::UNIX_EPOCH + Duration::new(1_707_482_096, 123_456_700);
+        assert_eq!(dt.to_system_time(), Some(system_time));
+        assert_eq!(ua::DateTime::try_from(system_time).unwrap(), dt);
+    }
+
+    #[test]
+    fn before_unix_epoch() {
+        // 1601-01-01 00:00:00 UTC is the origin of OPC UA date/time values.
+        let dt = ua::DateTime::from_unix_nanos(-11_644_473_600_000_000_000).unwrap();
+        assert_eq!(dt.0, 0);
+
+        // 1969-12-31 23:59:59.9999999 UTC is a single tick before the Unix epoch.
+        let dt = ua::DateTime::from_unix_nanos(-100).unwrap();
+        assert_eq!(dt.0, UA_DATETIME_UNIX_EPOCH - 1);
+        assert_eq!(dt.to_unix_nanos(), -100);
+
+        // Sub-tick nanoseconds are truncated towards the past.
+        let dt = ua::DateTime::from_unix_nanos(-1).unwrap();
+        assert_eq!(dt.to_unix_nanos(), -100);
+
+        let system_time = SystemTime::UNIX_EPOCH - Duration::from_secs(86_400);
+        let dt = ua::DateTime::try_from(system_time).unwrap();
+        assert_eq!(dt.to_unix_nanos(), -86_400_000_000_000);
+        assert_eq!(dt.to_system_time(), Some(system_time));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(ua::DateTime::from_unix_nanos(i128::MAX).is_err());
+        assert!(ua::DateTime::from_unix_nanos(i128::MIN).is_err());
+    }
+
     #[cfg(feature = "time")]
     #[test]
     fn from_offset_to_utc() {
@@ -0,0 +1,3 @@
+crate::data_type!(DeadbandType);
+
+crate::enum_variants!(DeadbandType, UA_DeadbandType, [NONE, ABSOLUTE, PERCENT]);
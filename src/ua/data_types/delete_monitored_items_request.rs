@@ -1,4 +1,4 @@
-use crate::ua;
+use crate::{ua, ServiceRequest};
 
 crate::data_type!(DeleteMonitoredItemsRequest);
 
@@ -23,3 +23,7 @@ impl DeleteMonitoredItemsRequest {
         self
     }
 }
+
+impl ServiceRequest for DeleteMonitoredItemsRequest {
+    type Response = ua::DeleteMonitoredItemsResponse;
+}
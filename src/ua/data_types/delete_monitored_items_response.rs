@@ -1 +1,23 @@
+use crate::{ua, ServiceResponse};
+
 crate::data_type!(DeleteMonitoredItemsResponse);
+
+impl DeleteMonitoredItemsResponse {
+    /// Gets results.
+    ///
+    /// The size and order of the list matches the size and order of the monitored item IDs in the
+    /// request.
+    #[must_use]
+    pub fn results(&self) -> Option<ua::Array<ua::StatusCode>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
+    }
+}
+
+impl ServiceResponse for DeleteMonitoredItemsResponse {
+    type Request = ua::DeleteMonitoredItemsRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
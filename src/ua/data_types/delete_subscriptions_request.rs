@@ -1,4 +1,4 @@
-use crate::ua;
+use crate::{ua, ServiceRequest};
 
 crate::data_type!(DeleteSubscriptionsRequest);
 
@@ -14,3 +14,7 @@ impl DeleteSubscriptionsRequest {
         self
     }
 }
+
+impl ServiceRequest for DeleteSubscriptionsRequest {
+    type Response = ua::DeleteSubscriptionsResponse;
+}
@@ -0,0 +1,145 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(DiagnosticInfo);
+
+impl DiagnosticInfo {
+    /// Gets symbolic ID.
+    ///
+    /// The symbolic ID is stored as index into the string table of the response that contains the
+    /// diagnostic info. Pass this table to resolve the index, see for example
+    /// [`ua::ReadResponse::string_table()`].
+    #[must_use]
+    pub fn symbolic_id<'a>(&self, string_table: &'a [ua::String]) -> Option<&'a ua::String> {
+        self.0
+            .hasSymbolicId()
+            .then_some(self.0.symbolicId)
+            .and_then(|index| table_entry(string_table, index))
+    }
+
+    /// Gets namespace URI of symbolic ID.
+    ///
+    /// See [`symbolic_id()`](Self::symbolic_id) for the meaning of `string_table`.
+    #[must_use]
+    pub fn namespace_uri<'a>(&self, string_table: &'a [ua::String]) -> Option<&'a ua::String> {
+        self.0
+            .hasNamespaceUri()
+            .then_some(self.0.namespaceUri)
+            .and_then(|index| table_entry(string_table, index))
+    }
+
+    /// Gets localized text.
+    ///
+    /// See [`symbolic_id()`](Self::symbolic_id) for the meaning of `string_table`. The locale of
+    /// the text is available through [`locale()`](Self::locale).
+    #[must_use]
+    pub fn localized_text<'a>(&self, string_table: &'a [ua::String]) -> Option<&'a ua::String> {
+        self.0
+            .hasLocalizedText()
+            .then_some(self.0.localizedText)
+            .and_then(|index| table_entry(string_table, index))
+    }
+
+    /// Gets locale of localized text.
+    ///
+    /// See [`symbolic_id()`](Self::symbolic_id) for the meaning of `string_table`.
+    #[must_use]
+    pub fn locale<'a>(&self, string_table: &'a [ua::String]) -> Option<&'a ua::String> {
+        self.0
+            .hasLocale()
+            .then_some(self.0.locale)
+            .and_then(|index| table_entry(string_table, index))
+    }
+
+    /// Gets additional (vendor-specific) information.
+    ///
+    /// Unlike the other string fields, this is stored in the diagnostic info itself.
+    #[must_use]
+    pub fn additional_info(&self) -> Option<String> {
+        self.0
+            .hasAdditionalInfo()
+            .then(|| ua::String::raw_ref(&self.0.additionalInfo))
+            .and_then(|additional_info| additional_info.as_str().map(ToOwned::to_owned))
+    }
+
+    /// Gets status code returned by the underlying system.
+    #[must_use]
+    pub fn inner_status_code(&self) -> Option<ua::StatusCode> {
+        self.0
+            .hasInnerStatusCode()
+            .then(|| ua::StatusCode::new(self.0.innerStatusCode))
+    }
+
+    /// Gets diagnostic info of the underlying system.
+    ///
+    /// This may be chained for several levels. Its string fields resolve against the same string
+    /// table as the outer diagnostic info.
+    #[must_use]
+    pub fn inner_diagnostic_info(&self) -> Option<&DiagnosticInfo> {
+        if !self.0.hasInnerDiagnosticInfo() {
+            return None;
+        }
+        // SAFETY: When set, the pointer is valid for as long as `self` is.
+        unsafe { self.0.innerDiagnosticInfo.as_ref() }.map(DiagnosticInfo::raw_ref)
+    }
+}
+
+/// Looks up string in string table.
+///
+/// Indices are signed in OPC UA, negative values denote unset fields.
+fn table_entry(string_table: &[ua::String], index: i32) -> Option<&ua::String> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| string_table.get(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn resolve_string_table() {
+        let string_table = [
+            ua::String::new("BadValue").unwrap(),
+            ua::String::new("urn:lorem").unwrap(),
+            ua::String::new("Value out of range").unwrap(),
+        ];
+
+        let mut inner = ua::DiagnosticInfo::init();
+        inner.0.set_hasSymbolicId(true);
+        inner.0.symbolicId = 0;
+        inner.0.set_hasInnerStatusCode(true);
+        inner.0.innerStatusCode = ua::StatusCode::BADOUTOFRANGE.into_raw();
+
+        let mut outer = ua::DiagnosticInfo::init();
+        outer.0.set_hasNamespaceUri(true);
+        outer.0.namespaceUri = 1;
+        outer.0.set_hasLocalizedText(true);
+        outer.0.localizedText = 2;
+        // Invalid index must not be resolved.
+        outer.0.set_hasLocale(true);
+        outer.0.locale = 3;
+        outer.0.set_hasAdditionalInfo(true);
+        ua::String::new("ipsum")
+            .unwrap()
+            .move_into_raw(&mut outer.0.additionalInfo);
+        outer.0.set_hasInnerDiagnosticInfo(true);
+        outer.0.innerDiagnosticInfo = inner.leak_into_raw();
+
+        assert_eq!(outer.symbolic_id(&string_table), None);
+        assert_eq!(outer.namespace_uri(&string_table), string_table.get(1));
+        assert_eq!(outer.localized_text(&string_table), string_table.get(2));
+        assert_eq!(outer.locale(&string_table), None);
+        assert_eq!(outer.additional_info().as_deref(), Some("ipsum"));
+        assert_eq!(outer.inner_status_code(), None);
+
+        let inner = outer
+            .inner_diagnostic_info()
+            .expect("should have inner info");
+        assert_eq!(inner.symbolic_id(&string_table), string_table.first());
+        assert_eq!(
+            inner.inner_status_code(),
+            Some(ua::StatusCode::BADOUTOFRANGE)
+        );
+        assert_eq!(inner.inner_diagnostic_info(), None);
+    }
+}
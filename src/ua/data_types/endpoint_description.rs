@@ -0,0 +1,54 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(EndpointDescription);
+
+impl EndpointDescription {
+    /// Gets endpoint URL.
+    #[must_use]
+    pub fn endpoint_url(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.endpointUrl)
+    }
+
+    /// Gets description of server that offers endpoint.
+    #[must_use]
+    pub fn server(&self) -> &ua::ApplicationDescription {
+        ua::ApplicationDescription::raw_ref(&self.0.server)
+    }
+
+    /// Gets certificate of server.
+    ///
+    /// This is empty (or invalid) for endpoints without security.
+    #[must_use]
+    pub fn server_certificate(&self) -> &ua::ByteString {
+        ua::ByteString::raw_ref(&self.0.serverCertificate)
+    }
+
+    /// Gets message security mode.
+    #[must_use]
+    pub fn security_mode(&self) -> &ua::MessageSecurityMode {
+        ua::MessageSecurityMode::raw_ref(&self.0.securityMode)
+    }
+
+    /// Gets URI of security policy.
+    ///
+    /// Endpoints without security use `http://opcfoundation.org/UA/SecurityPolicy#None`.
+    #[must_use]
+    pub fn security_policy_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.securityPolicyUri)
+    }
+
+    /// Gets URI of transport profile.
+    #[must_use]
+    pub fn transport_profile_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.transportProfileUri)
+    }
+
+    /// Gets security level.
+    ///
+    /// This is a relative measure assigned by the server: endpoints with higher values are more
+    /// secure than endpoints with lower values.
+    #[must_use]
+    pub const fn security_level(&self) -> u8 {
+        self.0.securityLevel
+    }
+}
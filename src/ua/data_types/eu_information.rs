@@ -0,0 +1,58 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(EUInformation);
+
+impl EUInformation {
+    #[must_use]
+    pub fn with_namespace_uri(mut self, namespace_uri: &ua::String) -> Self {
+        namespace_uri.clone_into_raw(&mut self.0.namespaceUri);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_unit_id(mut self, unit_id: i32) -> Self {
+        self.0.unitId = unit_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_display_name(mut self, display_name: &ua::LocalizedText) -> Self {
+        display_name.clone_into_raw(&mut self.0.displayName);
+        self
+    }
+
+    #[must_use]
+    pub fn with_description(mut self, description: &ua::LocalizedText) -> Self {
+        description.clone_into_raw(&mut self.0.description);
+        self
+    }
+
+    /// Gets URI of organization that defines the unit.
+    ///
+    /// For units from UNECE Recommendation 20, this is
+    /// `http://www.opcfoundation.org/UA/units/un/cefact`.
+    #[must_use]
+    pub fn namespace_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.namespaceUri)
+    }
+
+    /// Gets identifier of unit.
+    ///
+    /// This is `-1` when no identifier is available.
+    #[must_use]
+    pub const fn unit_id(&self) -> i32 {
+        self.0.unitId
+    }
+
+    /// Gets display name of unit, e.g. `°C`.
+    #[must_use]
+    pub fn display_name(&self) -> &ua::LocalizedText {
+        ua::LocalizedText::raw_ref(&self.0.displayName)
+    }
+
+    /// Gets description of unit, e.g. `degree Celsius`.
+    #[must_use]
+    pub fn description(&self) -> &ua::LocalizedText {
+        ua::LocalizedText::raw_ref(&self.0.description)
+    }
+}
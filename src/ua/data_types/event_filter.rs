@@ -0,0 +1,16 @@
+use crate::ua;
+
+crate::data_type!(EventFilter);
+
+impl EventFilter {
+    /// Sets select clauses.
+    ///
+    /// These select the event fields to return. Event notifications hold the values of the fields
+    /// in the same order.
+    #[must_use]
+    pub fn with_select_clauses(mut self, select_clauses: &[ua::SimpleAttributeOperand]) -> Self {
+        let array = ua::Array::from_slice(select_clauses);
+        array.move_into_raw(&mut self.0.selectClausesSize, &mut self.0.selectClauses);
+        self
+    }
+}
@@ -25,18 +25,117 @@ impl ExpandedNodeId {
         Self(unsafe { UA_EXPANDEDNODEID_NODEID(node_id.into_raw()) })
     }
 
+    /// Gets node ID.
+    ///
+    /// Note that the namespace index of the node ID must be ignored when the expanded node ID has
+    /// a [`namespace_uri()`](Self::namespace_uri), and that the node ID refers to a node on another
+    /// server when it has a non-zero [`server_index()`](Self::server_index). Use
+    /// [`to_node_id()`](Self::to_node_id) to get the node ID with the namespace index resolved.
     #[must_use]
     pub fn node_id(&self) -> &ua::NodeId {
         ua::NodeId::raw_ref(&self.0.nodeId)
     }
 
+    /// Gets namespace URI.
+    ///
+    /// This returns `None` when the namespace is given by the namespace index of the
+    /// [`node_id()`](Self::node_id) instead.
     #[must_use]
-    pub fn namespace_uri(&self) -> &ua::String {
-        ua::String::raw_ref(&self.0.namespaceUri)
+    pub fn namespace_uri(&self) -> Option<&ua::String> {
+        let namespace_uri = ua::String::raw_ref(&self.0.namespaceUri);
+        // Null and empty strings both mean that no namespace URI has been specified.
+        (!namespace_uri.is_invalid() && !namespace_uri.is_empty()).then_some(namespace_uri)
     }
 
+    /// Gets server index.
+    ///
+    /// This is `0` for nodes on the local server.
     #[must_use]
     pub const fn server_index(&self) -> u32 {
         self.0.serverIndex
     }
+
+    /// Converts to local node ID.
+    ///
+    /// When the namespace is given by [`namespace_uri()`](Self::namespace_uri), the URI is looked
+    /// up in `namespace_array` (the server's `NamespaceArray`, see [`Server::namespace_array()`])
+    /// and its position there becomes the namespace index of the returned node ID. Pass an empty
+    /// slice to accept only node IDs whose namespace is given by index.
+    ///
+    /// This returns `None` when the expanded node ID refers to a node on another server, or when
+    /// its namespace URI is not found in `namespace_array`.
+    ///
+    /// [`Server::namespace_array()`]: crate::Server::namespace_array
+    #[must_use]
+    pub fn to_node_id(&self, namespace_array: &[ua::String]) -> Option<ua::NodeId> {
+        if self.server_index() != 0 {
+            return None;
+        }
+
+        let node_id = self.node_id().clone();
+        let Some(namespace_uri) = self.namespace_uri() else {
+            return Some(node_id);
+        };
+
+        let namespace_index = namespace_array
+            .iter()
+            .position(|namespace| namespace == namespace_uri)?;
+        let mut node_id = node_id.into_raw();
+        node_id.namespaceIndex = u16::try_from(namespace_index).ok()?;
+        // SAFETY: We pass back ownership of the value taken above.
+        Some(unsafe { ua::NodeId::from_raw(node_id) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn local_node_id() {
+        let expanded_node_id = ua::ExpandedNodeId::numeric(0, 2258);
+        assert_eq!(expanded_node_id.namespace_uri(), None);
+        assert_eq!(expanded_node_id.server_index(), 0);
+        assert_eq!(
+            expanded_node_id.to_node_id(&[]),
+            Some(ua::NodeId::numeric(0, 2258))
+        );
+    }
+
+    #[test]
+    fn remote_node_id() {
+        let mut expanded_node_id = ua::ExpandedNodeId::numeric(0, 2258);
+        expanded_node_id.0.serverIndex = 1;
+        assert_eq!(expanded_node_id.server_index(), 1);
+        assert_eq!(expanded_node_id.node_id(), &ua::NodeId::numeric(0, 2258));
+        assert_eq!(expanded_node_id.to_node_id(&[]), None);
+    }
+
+    #[test]
+    fn namespace_uri_node_id() {
+        let mut expanded_node_id = ua::ExpandedNodeId::numeric(0, 2258);
+        ua::String::new("urn:lorem:ipsum")
+            .unwrap()
+            .move_into_raw(&mut expanded_node_id.0.namespaceUri);
+        assert_eq!(
+            expanded_node_id.namespace_uri(),
+            Some(&ua::String::new("urn:lorem:ipsum").unwrap())
+        );
+        // The namespace index of the node ID is ignored, the URI is looked up instead.
+        let namespace_array = [
+            ua::String::new("http://opcfoundation.org/UA/").unwrap(),
+            ua::String::new("urn:dolor").unwrap(),
+            ua::String::new("urn:lorem:ipsum").unwrap(),
+        ];
+        assert_eq!(
+            expanded_node_id.to_node_id(&namespace_array),
+            Some(ua::NodeId::numeric(2, 2258))
+        );
+        // Unknown namespace URIs cannot be resolved.
+        assert_eq!(expanded_node_id.to_node_id(&[]), None);
+        assert_eq!(
+            expanded_node_id.to_node_id(namespace_array.get(..2).unwrap()),
+            None
+        );
+    }
 }
@@ -1,8 +1,10 @@
-use std::ffi::c_void;
+use std::{ffi::c_void, ptr};
 
-use open62541_sys::{UA_ExtensionObjectEncoding, UA_ExtensionObject_setValueCopy};
+use open62541_sys::{
+    UA_ExtensionObjectEncoding, UA_ExtensionObject_setValueCopy, UA_decodeBinary, UA_encodeBinary,
+};
 
-use crate::{ua, DataType};
+use crate::{ua, DataType, Error, Result};
 
 crate::data_type!(ExtensionObject);
 
@@ -25,6 +27,76 @@ impl ExtensionObject {
         extension_object
     }
 
+    /// Creates extension object with binary-encoded value.
+    ///
+    /// Unlike [`new()`](Self::new), this encodes the value right away and stores the encoded bytes
+    /// along with the binary encoding ID of the data type. This is the form in which extension
+    /// objects are transmitted.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the value cannot be encoded.
+    pub fn encode<T: DataType>(value: &T) -> Result<Self> {
+        let mut body = ua::ByteString::init();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_encodeBinary(
+                value.as_ptr().cast::<c_void>(),
+                T::data_type(),
+                body.as_mut_ptr(),
+            )
+        });
+        Error::verify_good(&status_code)?;
+
+        // SAFETY: The data type is a static definition that is valid for access.
+        let encoding_id = ua::NodeId::raw_ref(unsafe { &(*T::data_type()).binaryEncodingId });
+
+        let mut extension_object = Self::init();
+        extension_object.0.encoding =
+            UA_ExtensionObjectEncoding::UA_EXTENSIONOBJECT_ENCODED_BYTESTRING;
+        let encoded_content = unsafe { extension_object.0.content.encoded.as_mut() };
+        encoding_id.clone_into_raw(&mut encoded_content.typeId);
+        body.move_into_raw(&mut encoded_content.body);
+
+        Ok(extension_object)
+    }
+
+    /// Gets value of given data type.
+    ///
+    /// This handles both decoded values (as created by [`new()`](Self::new)) and binary-encoded
+    /// values (as received from the server or created by [`encode()`](Self::encode)). Encoded
+    /// content is decoded only when its encoding ID matches the binary encoding ID of `T`.
+    ///
+    /// Returns `None` when the extension object holds a value of a different data type, or when
+    /// the encoded value cannot be decoded.
+    #[must_use]
+    pub fn decode<T: DataType>(&self) -> Option<T> {
+        if let Some(value) = self.decoded_content::<T>() {
+            return Some(value.clone());
+        }
+
+        let (encoding_id, body) = self.encoded_content_bytestring()?;
+
+        // SAFETY: The data type is a static definition that is valid for access.
+        let expected_encoding_id =
+            ua::NodeId::raw_ref(unsafe { &(*T::data_type()).binaryEncodingId });
+        if encoding_id != expected_encoding_id {
+            return None;
+        }
+
+        let mut value = T::init();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_decodeBinary(
+                body.as_ptr(),
+                value.as_mut_ptr().cast::<c_void>(),
+                T::data_type(),
+                ptr::null(),
+            )
+        });
+        Error::verify_good(&status_code).ok()?;
+
+        Some(value)
+    }
+
     /// Gets encoded byte string content.
     #[must_use]
     pub fn encoded_content_bytestring(&self) -> Option<(&ua::NodeId, &ua::ByteString)> {
@@ -75,3 +147,42 @@ impl ExtensionObject {
         unsafe { decoded_content.data.cast::<T::Inner>().as_ref() }.map(T::raw_ref)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let value = ua::ReadValueId::init()
+            .with_node_id(&ua::NodeId::string(1, "Lorem Ipsum"))
+            .with_attribute_id(&ua::AttributeId::DISPLAYNAME);
+
+        let extension_object = ua::ExtensionObject::encode(&value).expect("should encode value");
+        assert!(extension_object.encoded_content_bytestring().is_some());
+        assert!(extension_object
+            .decoded_content::<ua::ReadValueId>()
+            .is_none());
+
+        assert_eq!(
+            extension_object.decode::<ua::ReadValueId>(),
+            Some(value.clone())
+        );
+        assert_eq!(extension_object.decode::<ua::BrowseDescription>(), None);
+
+        // Variants unwrap structured values transparently.
+        let variant = ua::Variant::scalar(extension_object);
+        assert_eq!(variant.to_scalar::<ua::ReadValueId>(), Some(value));
+    }
+
+    #[test]
+    fn decode_decoded_content() {
+        let value = ua::ReadValueId::init().with_node_id(&ua::NodeId::numeric(0, 2258));
+
+        let extension_object = ua::ExtensionObject::new(&value);
+        assert!(extension_object.encoded_content_bytestring().is_none());
+
+        assert_eq!(extension_object.decode::<ua::ReadValueId>(), Some(value));
+        assert_eq!(extension_object.decode::<ua::BrowseDescription>(), None);
+    }
+}
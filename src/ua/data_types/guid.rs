@@ -0,0 +1,152 @@
+use std::{fmt, str};
+
+use open62541_sys::{UA_Guid, UA_Guid_parse, UA_Guid_print};
+
+use crate::{ua, DataType as _, Error};
+
+crate::data_type!(Guid);
+
+impl Guid {
+    /// Creates GUID from its individual fields.
+    #[must_use]
+    pub const fn new(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self(UA_Guid {
+            data1,
+            data2,
+            data3,
+            data4,
+        })
+    }
+
+    /// Creates GUID from 128-bit value.
+    ///
+    /// The most significant byte of `value` becomes the first byte of the GUID. This matches the
+    /// order in which the hexadecimal digits of the GUID appear in its string representation.
+    #[must_use]
+    pub const fn from_u128(value: u128) -> Self {
+        Self::from_bytes(value.to_be_bytes())
+    }
+
+    /// Creates GUID from bytes.
+    ///
+    /// The bytes are expected in the order defined by RFC 4122, i.e. the fields `data1`, `data2`,
+    /// and `data3` are stored in big-endian byte order.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        let [b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15] = bytes;
+        Self::new(
+            u32::from_be_bytes([b0, b1, b2, b3]),
+            u16::from_be_bytes([b4, b5]),
+            u16::from_be_bytes([b6, b7]),
+            [b8, b9, b10, b11, b12, b13, b14, b15],
+        )
+    }
+
+    /// Gets GUID as bytes.
+    ///
+    /// This is the inverse of [`from_bytes()`](Self::from_bytes).
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        let [b0, b1, b2, b3] = self.0.data1.to_be_bytes();
+        let [b4, b5] = self.0.data2.to_be_bytes();
+        let [b6, b7] = self.0.data3.to_be_bytes();
+        let [b8, b9, b10, b11, b12, b13, b14, b15] = self.0.data4;
+        [
+            b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15,
+        ]
+    }
+
+    /// Gets GUID as 128-bit value.
+    ///
+    /// This is the inverse of [`from_u128()`](Self::from_u128).
+    #[must_use]
+    pub const fn to_u128(&self) -> u128 {
+        u128::from_be_bytes(self.to_bytes())
+    }
+}
+
+impl str::FromStr for Guid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut guid = Guid::init();
+
+        let status_code = ua::StatusCode::new({
+            let str = ua::String::new(s)?;
+            // SAFETY: `UA_Guid_parse()` expects the string passed by value but does not take
+            // ownership.
+            let str = unsafe { ua::String::to_raw_copy(&str) };
+            unsafe { UA_Guid_parse(guid.as_mut_ptr(), str) }
+        });
+        Error::verify_good(&status_code)?;
+
+        Ok(guid)
+    }
+}
+
+impl fmt::Display for Guid {
+    /// Formats GUID in hyphenated form as defined by RFC 4122.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let guid = ua::Guid::from_u128(0x72962b91_fa75_4ae6_8d28_b404dc7daf63);
+    /// assert_eq!(guid.to_string(), "72962b91-fa75-4ae6-8d28-b404dc7daf63");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = ua::String::init();
+
+        let status_code = &ua::StatusCode::new({
+            // This mirrors the behavior of `UA_Guid_parse()` above.
+            unsafe { UA_Guid_print(self.as_ptr(), output.as_mut_ptr()) }
+        });
+        Error::verify_good(status_code).map_err(|_| fmt::Error)?;
+
+        f.write_str(output.as_str().unwrap_or(""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    const GUID_STR: &str = "72962b91-fa75-4ae6-8d28-b404dc7daf63";
+
+    const GUID_BYTES: [u8; 16] = [
+        0x72, 0x96, 0x2b, 0x91, 0xfa, 0x75, 0x4a, 0xe6, 0x8d, 0x28, 0xb4, 0x04, 0xdc, 0x7d, 0xaf,
+        0x63,
+    ];
+
+    #[test]
+    fn byte_layout() {
+        // `UA_Guid_parse()` is the reference for the expected memory layout.
+        let parsed: ua::Guid = GUID_STR.parse().expect("should be valid GUID");
+
+        let guid = ua::Guid::new(
+            0x7296_2b91,
+            0xfa75,
+            0x4ae6,
+            [0x8d, 0x28, 0xb4, 0x04, 0xdc, 0x7d, 0xaf, 0x63],
+        );
+        assert_eq!(guid, parsed);
+        assert_eq!(ua::Guid::from_bytes(GUID_BYTES), parsed);
+        assert_eq!(
+            ua::Guid::from_u128(0x7296_2b91_fa75_4ae6_8d28_b404_dc7d_af63),
+            parsed
+        );
+
+        // We get the original values back.
+        assert_eq!(parsed.to_bytes(), GUID_BYTES);
+        assert_eq!(parsed.to_u128(), 0x7296_2b91_fa75_4ae6_8d28_b404_dc7d_af63);
+    }
+
+    #[test]
+    fn string_representation() {
+        let guid = ua::Guid::from_bytes(GUID_BYTES);
+        assert_eq!(guid.to_string(), GUID_STR);
+
+        "lorem"
+            .parse::<ua::Guid>()
+            .expect_err("should be invalid GUID");
+    }
+}
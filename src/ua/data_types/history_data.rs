@@ -0,0 +1,11 @@
+use crate::ua;
+
+crate::data_type!(HistoryData);
+
+impl HistoryData {
+    #[must_use]
+    pub fn data_values(&self) -> Option<ua::Array<ua::DataValue>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.dataValuesSize, self.0.dataValues)
+    }
+}
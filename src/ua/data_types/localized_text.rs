@@ -28,13 +28,118 @@ impl LocalizedText {
         Ok(self)
     }
 
+    /// Gets locale.
+    ///
+    /// This may be empty when the text is not tied to any particular locale.
     #[must_use]
     pub fn locale(&self) -> &ua::String {
         ua::String::raw_ref(&self.0.locale)
     }
 
+    /// Gets text.
     #[must_use]
     pub fn text(&self) -> &ua::String {
         ua::String::raw_ref(&self.0.text)
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+
+    use serde::{de, ser::SerializeStruct as _};
+
+    use super::LocalizedText;
+
+    const FIELDS: &[&str] = &["locale", "text"];
+
+    impl serde::Serialize for LocalizedText {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let mut state = serializer.serialize_struct("LocalizedText", FIELDS.len())?;
+            state.serialize_field("locale", self.locale())?;
+            state.serialize_field("text", self.text())?;
+            state.end()
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for LocalizedText {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("LocalizedText", FIELDS, LocalizedTextVisitor)
+        }
+    }
+
+    struct LocalizedTextVisitor;
+
+    impl<'de> de::Visitor<'de> for LocalizedTextVisitor {
+        type Value = LocalizedText;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an OPC UA localized text")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut locale: Option<String> = None;
+            let mut text: Option<String> = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "locale" => locale = Some(map.next_value()?),
+                    "text" => text = Some(map.next_value()?),
+                    _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                }
+            }
+
+            let locale = locale.ok_or_else(|| de::Error::missing_field("locale"))?;
+            let text = text.ok_or_else(|| de::Error::missing_field("text"))?;
+
+            LocalizedText::new(&locale, &text).map_err(de::Error::custom)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::ua;
+
+        #[test]
+        fn json_serialization() {
+            let text = ua::LocalizedText::new("en-US", "lorem").unwrap();
+            let json = serde_json::to_string(&text).expect("should serialize localized text");
+            assert_eq!(json, r#"{"locale":"en-US","text":"lorem"}"#);
+
+            let text: ua::LocalizedText =
+                serde_json::from_str(&json).expect("should deserialize localized text");
+            assert_eq!(text, ua::LocalizedText::new("en-US", "lorem").unwrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    #[test]
+    fn value_representation() {
+        let text = ua::LocalizedText::new("en-US", "lorem").expect("should create text");
+
+        // We get the original values back.
+        assert_eq!(text.locale().as_str(), Some("en-US"));
+        assert_eq!(text.text().as_str(), Some("lorem"));
+
+        // Clones own their strings and outlive the original value.
+        let clone = text.clone();
+        drop(text);
+        assert_eq!(clone.locale().as_str(), Some("en-US"));
+        assert_eq!(clone.text().as_str(), Some("lorem"));
+
+        ua::LocalizedText::new("en-US", "lorem\0ipsum").expect_err("should reject NUL");
+    }
+}
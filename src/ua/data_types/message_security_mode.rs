@@ -0,0 +1,7 @@
+crate::data_type!(MessageSecurityMode);
+
+crate::enum_variants!(
+    MessageSecurityMode,
+    UA_MessageSecurityMode,
+    [INVALID, NONE, SIGN, SIGNANDENCRYPT],
+);
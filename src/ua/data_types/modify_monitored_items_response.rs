@@ -0,0 +1,23 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(ModifyMonitoredItemsResponse);
+
+impl ModifyMonitoredItemsResponse {
+    /// Gets results.
+    ///
+    /// The size and order of the list matches the size and order of the items to modify in the
+    /// request.
+    #[must_use]
+    pub fn results(&self) -> Option<ua::Array<ua::MonitoredItemModifyResult>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
+    }
+}
+
+impl ServiceResponse for ModifyMonitoredItemsResponse {
+    type Request = ua::ModifyMonitoredItemsRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
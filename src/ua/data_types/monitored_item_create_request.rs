@@ -10,6 +10,50 @@ impl MonitoredItemCreateRequest {
         node_id.clone_into_raw(&mut self.0.itemToMonitor.nodeId);
         self
     }
+
+    #[must_use]
+    pub fn with_attribute_id(mut self, attribute_id: &ua::AttributeId) -> Self {
+        self.0.itemToMonitor.attributeId = attribute_id.as_u32();
+        self
+    }
+
+    /// Sets requested filter.
+    ///
+    /// The filter depends on the monitored attribute. Event notifiers require [`ua::EventFilter`],
+    /// values accept [`ua::DataChangeFilter`], both wrapped in [`ua::ExtensionObject`].
+    #[must_use]
+    pub fn with_filter(mut self, filter: &ua::ExtensionObject) -> Self {
+        filter.clone_into_raw(&mut self.0.requestedParameters.filter);
+        self
+    }
+
+    /// Sets requested sampling interval (in milliseconds).
+    ///
+    /// Use `0.0` to request the fastest practical rate, or `-1.0` to use the publishing interval
+    /// of the subscription.
+    #[must_use]
+    pub const fn with_sampling_interval(mut self, sampling_interval: f64) -> Self {
+        self.0.requestedParameters.samplingInterval = sampling_interval;
+        self
+    }
+
+    /// Sets requested parameters.
+    ///
+    /// These replace all parameters set before, including sampling interval and filter.
+    #[must_use]
+    pub fn with_requested_parameters(
+        mut self,
+        requested_parameters: &ua::MonitoringParameters,
+    ) -> Self {
+        requested_parameters.clone_into_raw(&mut self.0.requestedParameters);
+        self
+    }
+
+    /// Gets requested parameters.
+    #[must_use]
+    pub fn requested_parameters(&self) -> &ua::MonitoringParameters {
+        ua::MonitoringParameters::raw_ref(&self.0.requestedParameters)
+    }
 }
 
 impl Default for MonitoredItemCreateRequest {
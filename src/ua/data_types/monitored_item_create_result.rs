@@ -3,8 +3,25 @@ use crate::ua;
 crate::data_type!(MonitoredItemCreateResult);
 
 impl MonitoredItemCreateResult {
+    #[must_use]
+    pub const fn status_code(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.statusCode)
+    }
+
     #[must_use]
     pub const fn monitored_item_id(&self) -> ua::MonitoredItemId {
         ua::MonitoredItemId::new(self.0.monitoredItemId)
     }
+
+    /// Gets sampling interval (in milliseconds) as revised by the server.
+    #[must_use]
+    pub const fn revised_sampling_interval(&self) -> f64 {
+        self.0.revisedSamplingInterval
+    }
+
+    /// Gets queue size as revised by the server.
+    #[must_use]
+    pub const fn revised_queue_size(&self) -> u32 {
+        self.0.revisedQueueSize
+    }
 }
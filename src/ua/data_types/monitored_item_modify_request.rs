@@ -0,0 +1,23 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(MonitoredItemModifyRequest);
+
+impl MonitoredItemModifyRequest {
+    #[must_use]
+    pub const fn with_monitored_item_id(mut self, monitored_item_id: ua::MonitoredItemId) -> Self {
+        self.0.monitoredItemId = monitored_item_id.as_u32();
+        self
+    }
+
+    /// Sets requested parameters.
+    ///
+    /// These replace all existing parameters of the monitored item.
+    #[must_use]
+    pub fn with_requested_parameters(
+        mut self,
+        requested_parameters: &ua::MonitoringParameters,
+    ) -> Self {
+        requested_parameters.clone_into_raw(&mut self.0.requestedParameters);
+        self
+    }
+}
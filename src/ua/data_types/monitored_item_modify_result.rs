@@ -0,0 +1,22 @@
+use crate::ua;
+
+crate::data_type!(MonitoredItemModifyResult);
+
+impl MonitoredItemModifyResult {
+    #[must_use]
+    pub const fn status_code(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.statusCode)
+    }
+
+    /// Gets sampling interval (in milliseconds) as revised by the server.
+    #[must_use]
+    pub const fn revised_sampling_interval(&self) -> f64 {
+        self.0.revisedSamplingInterval
+    }
+
+    /// Gets queue size as revised by the server.
+    #[must_use]
+    pub const fn revised_queue_size(&self) -> u32 {
+        self.0.revisedQueueSize
+    }
+}
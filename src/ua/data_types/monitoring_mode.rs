@@ -0,0 +1,7 @@
+crate::data_type!(MonitoringMode);
+
+crate::enum_variants!(
+    MonitoringMode,
+    UA_MonitoringMode,
+    [DISABLED, SAMPLING, REPORTING],
+);
@@ -0,0 +1,32 @@
+crate::data_type!(MonitoringParameters);
+
+impl MonitoringParameters {
+    /// Sets sampling interval (in milliseconds).
+    ///
+    /// Use `0.0` to request the fastest practical rate, or `-1.0` to use the publishing interval
+    /// of the subscription.
+    #[must_use]
+    pub const fn with_sampling_interval(mut self, sampling_interval: f64) -> Self {
+        self.0.samplingInterval = sampling_interval;
+        self
+    }
+
+    /// Sets size of queue for notifications.
+    #[must_use]
+    pub const fn with_queue_size(mut self, queue_size: u32) -> Self {
+        self.0.queueSize = queue_size;
+        self
+    }
+
+    /// Gets sampling interval (in milliseconds).
+    #[must_use]
+    pub const fn sampling_interval(&self) -> f64 {
+        self.0.samplingInterval
+    }
+
+    /// Gets size of queue for notifications.
+    #[must_use]
+    pub const fn queue_size(&self) -> u32 {
+        self.0.queueSize
+    }
+}
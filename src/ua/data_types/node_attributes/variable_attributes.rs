@@ -15,10 +15,33 @@ impl super::VariableAttributes {
         self
     }
 
+    #[must_use]
+    pub fn with_array_dimensions(mut self, array_dimensions: &[u32]) -> Self {
+        let array = ua::Array::from_iter(array_dimensions.iter().copied().map(ua::UInt32::new));
+        array.move_into_raw(&mut self.0.arrayDimensionsSize, &mut self.0.arrayDimensions);
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::ARRAYDIMENSIONS.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub fn with_value(mut self, value: &ua::Variant) -> Self {
+        value.clone_into_raw(&mut self.0.value);
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::VALUE.as_u32();
+        self
+    }
+
     #[must_use]
     pub const fn with_access_level(mut self, access_level: &ua::AccessLevel) -> Self {
         self.0.accessLevel = access_level.as_u8();
         self.0.specifiedAttributes |= ua::SpecifiedAttributes::ACCESSLEVEL.as_u32();
         self
     }
+
+    pub(crate) fn value(&self) -> &ua::Variant {
+        ua::Variant::raw_ref(&self.0.value)
+    }
+
+    pub(crate) const fn value_rank(&self) -> i32 {
+        self.0.valueRank
+    }
 }
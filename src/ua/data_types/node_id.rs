@@ -1,8 +1,8 @@
 use std::{ffi::CString, fmt, hash, str};
 
 use open62541_sys::{
-    UA_NodeIdType, UA_NodeId_hash, UA_NodeId_parse, UA_NodeId_print, UA_NODEID_NULL,
-    UA_NODEID_NUMERIC, UA_NODEID_STRING_ALLOC,
+    UA_NodeIdType, UA_NodeId_hash, UA_NodeId_parse, UA_NodeId_print, UA_NODEID_GUID,
+    UA_NODEID_NULL, UA_NODEID_NUMERIC, UA_NODEID_STRING_ALLOC,
 };
 
 use crate::{ua, DataType, Error};
@@ -60,6 +60,39 @@ impl NodeId {
         Self(inner)
     }
 
+    /// Creates GUID node ID.
+    #[must_use]
+    pub fn guid(ns_index: u16, guid: ua::Guid) -> Self {
+        // `UA_Guid` is a plain value type without dynamic allocations, so this does not leak.
+        let inner = unsafe { UA_NODEID_GUID(ns_index, guid.into_raw()) };
+        debug_assert_eq!(
+            inner.identifierType,
+            UA_NodeIdType::UA_NODEIDTYPE_GUID,
+            "new node ID should have GUID type"
+        );
+
+        Self(inner)
+    }
+
+    /// Creates byte string node ID.
+    ///
+    /// Other than [`string()`](Self::string), the identifier may contain arbitrary bytes, including
+    /// NUL bytes.
+    #[must_use]
+    pub fn byte_string(ns_index: u16, byte_string: &[u8]) -> Self {
+        // We cannot use `UA_NODEID_BYTESTRING_ALLOC` here: it takes a NUL-terminated C string and
+        // would cut off the identifier at the first NUL byte.
+        let mut node_id = Self::init();
+        node_id.0.namespaceIndex = ns_index;
+        node_id.0.identifierType = UA_NodeIdType::UA_NODEIDTYPE_BYTESTRING;
+        // SAFETY: We have just set this enum variant. The initial value is zero-initialized, i.e.
+        // there is nothing to clean up that we would overwrite (and leak) here.
+        let identifier = unsafe { node_id.0.identifier.byteString.as_mut() };
+        *identifier = ua::ByteString::new(byte_string).into_raw();
+
+        node_id
+    }
+
     /// Creates null node ID.
     #[must_use]
     #[allow(dead_code)]
@@ -108,6 +141,24 @@ impl NodeId {
         })
     }
 
+    /// Gets namespace and identifier of GUID node ID.
+    #[must_use]
+    pub fn as_guid(&self) -> Option<(u16, &ua::Guid)> {
+        (self.0.identifierType == UA_NodeIdType::UA_NODEIDTYPE_GUID).then(|| {
+            let identifier = unsafe { self.0.identifier.guid.as_ref() };
+            (self.0.namespaceIndex, ua::Guid::raw_ref(identifier))
+        })
+    }
+
+    /// Gets namespace and identifier of byte string node ID.
+    #[must_use]
+    pub fn as_byte_string(&self) -> Option<(u16, &ua::ByteString)> {
+        (self.0.identifierType == UA_NodeIdType::UA_NODEIDTYPE_BYTESTRING).then(|| {
+            let identifier = unsafe { self.0.identifier.byteString.as_ref() };
+            (self.0.namespaceIndex, ua::ByteString::raw_ref(identifier))
+        })
+    }
+
     /// Turns node ID into expanded node ID.
     #[must_use]
     pub fn into_expanded_node_id(self) -> ua::ExpandedNodeId {
@@ -124,7 +175,7 @@ impl hash::Hash for NodeId {
 }
 
 impl str::FromStr for NodeId {
-    type Err = Error;
+    type Err = NodeIdParseError;
 
     /// ```
     /// use open62541::ua;
@@ -136,25 +187,88 @@ impl str::FromStr for NodeId {
     /// assert_eq!(node_id.to_string(), "i=2258");
     ///
     /// // Parsing node IDs can fail.
-    /// "LoremIpsum".parse::<ua::NodeId>().expect_err("should be invalid node ID");
+    /// let error = "LoremIpsum".parse::<ua::NodeId>().expect_err("should be invalid node ID");
+    /// assert_eq!(error, ua::NodeIdParseError::IdentifierType);
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut node_id = NodeId::init();
 
         let status_code = ua::StatusCode::new({
-            let str = ua::String::new(s)?;
+            // ERROR: The only way for this to fail is when the string contains NUL bytes. These are
+            // not allowed in any part of the node ID, so we attribute it to the identifier.
+            let str = ua::String::new(s).map_err(|_| NodeIdParseError::Identifier)?;
             // SAFETY: `UA_NodeId_parse()` expects the string passed by value but does not take
             // ownership.
             let str = unsafe { ua::String::to_raw_copy(&str) };
             unsafe { UA_NodeId_parse(node_id.as_mut_ptr(), str) }
         });
-        Error::verify_good(&status_code)?;
+        if !status_code.is_good() {
+            // `UA_NodeId_parse()` does not tell us what went wrong. Find out on our own.
+            return Err(NodeIdParseError::diagnose(s));
+        }
 
         Ok(node_id)
     }
 }
 
+/// Error when parsing [`NodeId`] from string.
+///
+/// This indicates which component of the node ID string failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum NodeIdParseError {
+    /// Namespace index is invalid, e.g. `ns=foo;i=1`.
+    #[error("invalid namespace index in node ID")]
+    Namespace,
+
+    /// Identifier type is unknown, e.g. `ns=1;x=1`.
+    #[error("unknown identifier type in node ID")]
+    IdentifierType,
+
+    /// Identifier does not match its type, e.g. `ns=1;i=foo`.
+    #[error("invalid identifier in node ID")]
+    Identifier,
+}
+
+impl NodeIdParseError {
+    /// Finds component that cannot be parsed.
+    ///
+    /// This must only be called for strings that have been rejected by `UA_NodeId_parse()`.
+    fn diagnose(s: &str) -> Self {
+        // Only the first `;` separates namespace from identifier. String identifiers may contain
+        // additional semicolons.
+        let identifier = match s.strip_prefix("ns=") {
+            Some(s) => {
+                let Some((namespace, identifier)) = s.split_once(';') else {
+                    return Self::Namespace;
+                };
+                if namespace.parse::<u16>().is_err() {
+                    return Self::Namespace;
+                }
+                identifier
+            }
+            None => s,
+        };
+
+        match identifier.get(..2) {
+            Some("i=" | "s=" | "g=" | "b=") => Self::Identifier,
+            _ => Self::IdentifierType,
+        }
+    }
+}
+
 impl fmt::Display for NodeId {
+    /// Formats node ID in its canonical string form.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// assert_eq!(ua::NodeId::numeric(2, 1234).to_string(), "ns=2;i=1234");
+    /// assert_eq!(ua::NodeId::string(1, "Temperature").to_string(), "ns=1;s=Temperature");
+    /// assert_eq!(ua::NodeId::byte_string(4, b"lorem").to_string(), "ns=4;b=bG9yZW0=");
+    ///
+    /// // Namespace 0 is implied and omitted from the output.
+    /// assert_eq!(ua::NodeId::ns0(2258).to_string(), "i=2258");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut output = ua::String::init();
 
@@ -205,8 +319,7 @@ mod serde {
         where
             E: serde::de::Error,
         {
-            v.parse()
-                .map_err(|_| serde::de::Error::custom("invalid node ID"))
+            v.parse().map_err(serde::de::Error::custom)
         }
     }
 
@@ -229,9 +342,9 @@ mod serde {
 
 #[cfg(test)]
 mod tests {
-    use std::str;
+    use std::{collections::HashMap, str};
 
-    use crate::ua;
+    use crate::{ua, DataType as _};
 
     #[test]
     fn string_representation() {
@@ -247,4 +360,161 @@ mod tests {
         //
         let _node_id: ua::NodeId = "ns=0;i=2258".parse().expect("should be valid node ID");
     }
+
+    #[test]
+    fn parse_forms() {
+        let node_id: ua::NodeId = "ns=2;i=1234".parse().expect("should be valid node ID");
+        assert_eq!(node_id.as_numeric(), Some((2, 1234)));
+
+        // Namespace defaults to 0 when missing.
+        let node_id: ua::NodeId = "i=2258".parse().expect("should be valid node ID");
+        assert_eq!(node_id.as_ns0(), Some(2258));
+
+        // Only the first `;` separates namespace from identifier.
+        let node_id: ua::NodeId = "ns=2;s=Boiler;Temp"
+            .parse()
+            .expect("should be valid node ID");
+        let (ns_index, string) = node_id.as_string().expect("should be string node ID");
+        assert_eq!(ns_index, 2);
+        assert_eq!(string.as_str(), Some("Boiler;Temp"));
+
+        let node_id: ua::NodeId = "ns=3;g=00000000-0000-0000-0000-000000000000"
+            .parse()
+            .expect("should be valid node ID");
+        assert_eq!(node_id, ua::NodeId::guid(3, ua::Guid::init()));
+
+        let node_id: ua::NodeId = "ns=4;b=bG8AcmVt".parse().expect("should be valid node ID");
+        assert_eq!(node_id, ua::NodeId::byte_string(4, b"lo\0rem"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "ns=foo;i=1".parse::<ua::NodeId>(),
+            Err(ua::NodeIdParseError::Namespace)
+        );
+        assert_eq!(
+            "ns=1".parse::<ua::NodeId>(),
+            Err(ua::NodeIdParseError::Namespace)
+        );
+        assert_eq!(
+            "ns=1;x=1".parse::<ua::NodeId>(),
+            Err(ua::NodeIdParseError::IdentifierType)
+        );
+        assert_eq!(
+            "ns=1;i=foo".parse::<ua::NodeId>(),
+            Err(ua::NodeIdParseError::Identifier)
+        );
+        assert_eq!(
+            "g=lorem".parse::<ua::NodeId>(),
+            Err(ua::NodeIdParseError::Identifier)
+        );
+    }
+
+    #[test]
+    fn display_representation() {
+        let node_id = ua::NodeId::guid(3, ua::Guid::init());
+        assert_eq!(
+            node_id.to_string(),
+            "ns=3;g=00000000-0000-0000-0000-000000000000"
+        );
+
+        // Formatting works in `format!()` and friends, too.
+        let node_id = ua::NodeId::numeric(0, 85);
+        assert_eq!(format!("node `{node_id}`"), "node `i=85`");
+    }
+
+    #[test]
+    fn compare_node_ids() {
+        // Independently built node IDs compare equal.
+        assert_eq!(ua::NodeId::string(1, "A"), ua::NodeId::string(1, "A"));
+        assert_eq!(ua::NodeId::numeric(1, 1234), ua::NodeId::numeric(1, 1234));
+
+        // Namespace index is part of the node ID.
+        assert_ne!(ua::NodeId::string(1, "A"), ua::NodeId::string(2, "A"));
+        assert_ne!(ua::NodeId::numeric(1, 1234), ua::NodeId::numeric(2, 1234));
+
+        // Identifier type is part of the node ID.
+        assert_ne!(ua::NodeId::string(1, "1234"), ua::NodeId::numeric(1, 1234));
+        assert_ne!(ua::NodeId::string(1, "A"), ua::NodeId::byte_string(1, b"A"));
+    }
+
+    #[test]
+    fn hash_map_keys() {
+        let mut map = HashMap::new();
+        map.insert(ua::NodeId::numeric(0, 85), "Objects");
+        map.insert(ua::NodeId::numeric(1, 85), "Numeric");
+        map.insert(ua::NodeId::string(1, "85"), "String");
+        map.insert(ua::NodeId::byte_string(1, b"85"), "ByteString");
+        assert_eq!(map.len(), 4);
+
+        // Equal node IDs hash equal, so independently built keys find their entries.
+        assert_eq!(map.get(&ua::NodeId::ns0(85)), Some(&"Objects"));
+        assert_eq!(map.get(&ua::NodeId::numeric(1, 85)), Some(&"Numeric"));
+        assert_eq!(map.get(&ua::NodeId::string(1, "85")), Some(&"String"));
+        assert_eq!(
+            map.get(&"ns=1;b=ODU=".parse::<ua::NodeId>().unwrap()),
+            Some(&"ByteString")
+        );
+        assert_eq!(map.get(&ua::NodeId::string(2, "85")), None);
+    }
+
+    #[test]
+    fn typed_accessors() {
+        // Accessors return `None` when the identifier type does not match.
+        let node_id = ua::NodeId::numeric(1, 1234);
+        assert!(node_id.as_numeric().is_some());
+        assert!(node_id.as_string().is_none());
+        assert!(node_id.as_guid().is_none());
+        assert!(node_id.as_byte_string().is_none());
+
+        let node_id = ua::NodeId::string(1, "lorem");
+        assert!(node_id.as_numeric().is_none());
+        assert!(node_id.as_ns0().is_none());
+        assert!(node_id.as_string().is_some());
+        assert!(node_id.as_guid().is_none());
+        assert!(node_id.as_byte_string().is_none());
+    }
+
+    #[test]
+    fn constructors_round_trip() {
+        let node_id = ua::NodeId::numeric(1, 1234);
+        assert_eq!(node_id.namespace_index(), 1);
+        assert_eq!(node_id.identifier_type(), &ua::NodeIdType::NUMERIC);
+        assert_eq!(node_id.as_numeric(), Some((1, 1234)));
+
+        let node_id = ua::NodeId::string(2, "Temperature");
+        assert_eq!(node_id.namespace_index(), 2);
+        assert_eq!(node_id.identifier_type(), &ua::NodeIdType::STRING);
+        let (ns_index, string) = node_id.as_string().expect("should be string node ID");
+        assert_eq!(ns_index, 2);
+        assert_eq!(string.as_str(), Some("Temperature"));
+
+        let node_id = ua::NodeId::guid(3, ua::Guid::init());
+        assert_eq!(node_id.namespace_index(), 3);
+        assert_eq!(node_id.identifier_type(), &ua::NodeIdType::GUID);
+        assert_eq!(
+            node_id.to_string(),
+            "ns=3;g=00000000-0000-0000-0000-000000000000"
+        );
+        let (ns_index, guid) = node_id.as_guid().expect("should be GUID node ID");
+        assert_eq!(ns_index, 3);
+        assert_eq!(guid, &ua::Guid::init());
+
+        // Byte strings may contain NUL bytes.
+        let node_id = ua::NodeId::byte_string(4, b"lo\0rem");
+        assert_eq!(node_id.namespace_index(), 4);
+        assert_eq!(node_id.identifier_type(), &ua::NodeIdType::BYTESTRING);
+        assert_eq!(node_id.to_string(), "ns=4;b=bG8AcmVt");
+        let (ns_index, byte_string) = node_id
+            .as_byte_string()
+            .expect("should be byte string node ID");
+        assert_eq!(ns_index, 4);
+        assert_eq!(byte_string.as_bytes(), Some(&b"lo\0rem"[..]));
+
+        // Cloning (and dropping) must not double-free the allocated identifiers.
+        let cloned = node_id.clone();
+        drop(node_id);
+        assert_eq!(cloned.to_string(), "ns=4;b=bG8AcmVt");
+    }
 }
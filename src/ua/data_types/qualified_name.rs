@@ -57,6 +57,95 @@ impl fmt::Display for QualifiedName {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde {
+    use std::fmt;
+
+    use serde::{de, ser::SerializeStruct as _};
+
+    use crate::{ua, DataType as _};
+
+    use super::QualifiedName;
+
+    const FIELDS: &[&str] = &["namespace_index", "name"];
+
+    impl serde::Serialize for QualifiedName {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let mut state = serializer.serialize_struct("QualifiedName", FIELDS.len())?;
+            state.serialize_field("namespace_index", &self.namespace_index())?;
+            state.serialize_field("name", self.name())?;
+            state.end()
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for QualifiedName {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_struct("QualifiedName", FIELDS, QualifiedNameVisitor)
+        }
+    }
+
+    struct QualifiedNameVisitor;
+
+    impl<'de> de::Visitor<'de> for QualifiedNameVisitor {
+        type Value = QualifiedName;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an OPC UA qualified name")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut namespace_index: Option<u16> = None;
+            let mut name: Option<String> = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "namespace_index" => namespace_index = Some(map.next_value()?),
+                    "name" => name = Some(map.next_value()?),
+                    _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                }
+            }
+
+            let namespace_index =
+                namespace_index.ok_or_else(|| de::Error::missing_field("namespace_index"))?;
+            let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+
+            // Unlike `QualifiedName::new()`, this does not panic on invalid names.
+            let mut qualified_name = QualifiedName::init();
+            qualified_name.0.namespaceIndex = namespace_index;
+            ua::String::new(&name)
+                .map_err(de::Error::custom)?
+                .move_into_raw(&mut qualified_name.0.name);
+
+            Ok(qualified_name)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::ua;
+
+        #[test]
+        fn json_serialization() {
+            let name = ua::QualifiedName::new(1, "lorem");
+            let json = serde_json::to_string(&name).expect("should serialize qualified name");
+            assert_eq!(json, r#"{"namespace_index":1,"name":"lorem"}"#);
+
+            let name: ua::QualifiedName =
+                serde_json::from_str(&json).expect("should deserialize qualified name");
+            assert_eq!(name, ua::QualifiedName::new(1, "lorem"));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ua;
@@ -69,5 +158,27 @@ mod tests {
         //
         assert_eq!(name.namespace_index(), 123);
         assert_eq!(name.name().as_str(), Some("lorem"));
+
+        // Clones own their strings and outlive the original value.
+        let clone = name.clone();
+        drop(name);
+        assert_eq!(clone.namespace_index(), 123);
+        assert_eq!(clone.name().as_str(), Some("lorem"));
+    }
+
+    #[test]
+    fn compare_names() {
+        assert_eq!(
+            ua::QualifiedName::new(1, "lorem"),
+            ua::QualifiedName::new(1, "lorem")
+        );
+        assert_ne!(
+            ua::QualifiedName::new(1, "lorem"),
+            ua::QualifiedName::new(2, "lorem")
+        );
+        assert_ne!(
+            ua::QualifiedName::new(1, "lorem"),
+            ua::QualifiedName::new(1, "ipsum")
+        );
     }
 }
@@ -0,0 +1,54 @@
+crate::data_type!(Range);
+
+impl Range {
+    /// Creates range from lower and upper limit.
+    ///
+    /// This is used, e.g., for the `EURange` property of analog items that holds the range of
+    /// values that the item is expected to have during normal operation.
+    #[must_use]
+    pub fn new(low: f64, high: f64) -> Self {
+        Self::init().with_low(low).with_high(high)
+    }
+
+    #[must_use]
+    pub const fn with_low(mut self, low: f64) -> Self {
+        self.0.low = low;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_high(mut self, high: f64) -> Self {
+        self.0.high = high;
+        self
+    }
+
+    /// Gets lower limit.
+    #[must_use]
+    pub const fn low(&self) -> f64 {
+        self.0.low
+    }
+
+    /// Gets upper limit.
+    #[must_use]
+    pub const fn high(&self) -> f64 {
+        self.0.high
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    #[test]
+    fn decode_from_extension_object() {
+        let range = ua::Range::new(-10.0, 40.0);
+
+        // Structured values may arrive as extension objects that have not been decoded yet.
+        let variant = ua::Variant::scalar(ua::ExtensionObject::new(&range));
+        let decoded = variant
+            .to_scalar::<ua::Range>()
+            .expect("should decode range");
+
+        assert_eq!(decoded, range);
+    }
+}
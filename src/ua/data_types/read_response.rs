@@ -8,6 +8,45 @@ impl ReadResponse {
         // TODO: Adjust signature to return non-owned value instead.
         ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
     }
+
+    /// Gets results without copying.
+    ///
+    /// Unlike [`results()`](Self::results), this borrows the data values from the response. This
+    /// avoids copying all values when only some of them are needed: clone those you want to keep.
+    /// The returned slice lives only as long as the response itself.
+    ///
+    /// This returns an empty slice when the response holds no results.
+    #[must_use]
+    pub fn values(&self) -> &[ua::DataValue] {
+        // SAFETY: We only borrow from `self` and do not give away ownership. The returned slice is
+        // tied to the lifetime of `self`.
+        let values = unsafe { ua::Array::slice_from_raw_parts(self.0.resultsSize, self.0.results) };
+        values.unwrap_or(&[])
+    }
+
+    /// Gets diagnostic infos.
+    ///
+    /// When diagnostics have been requested, the size and order of the list matches the size and
+    /// order of the results. String fields of diagnostic infos refer to the
+    /// [`string_table()`](Self::string_table).
+    #[must_use]
+    pub fn diagnostics(&self) -> Option<ua::Array<ua::DiagnosticInfo>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.diagnosticInfosSize, self.0.diagnosticInfos)
+    }
+
+    /// Gets string table of response.
+    ///
+    /// This resolves string fields of the [`diagnostics()`](Self::diagnostics), e.g. with
+    /// [`ua::DiagnosticInfo::symbolic_id()`].
+    #[must_use]
+    pub fn string_table(&self) -> Option<ua::Array<ua::String>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(
+            self.0.responseHeader.stringTableSize,
+            self.0.responseHeader.stringTable,
+        )
+    }
 }
 
 impl ServiceResponse for ReadResponse {
@@ -17,3 +56,27 @@ impl ServiceResponse for ReadResponse {
         ua::StatusCode::new(self.0.responseHeader.serviceResult)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn borrow_values() {
+        let mut response = ua::ReadResponse::init();
+        assert!(response.values().is_empty());
+
+        let values = [
+            ua::DataValue::new(ua::Variant::scalar(ua::Int32::new(1))),
+            ua::DataValue::init().with_status_code(&ua::StatusCode::BADNODEIDUNKNOWN),
+            ua::DataValue::new(ua::Variant::scalar(ua::String::new("lorem").unwrap())),
+        ];
+        ua::Array::from_slice(&values)
+            .move_into_raw(&mut response.0.resultsSize, &mut response.0.results);
+
+        // Borrowed values match the ones copied out of the response.
+        let results = response.results().expect("should have results");
+        assert_eq!(response.values(), results.as_slice());
+        assert_eq!(response.values(), values.as_slice());
+    }
+}
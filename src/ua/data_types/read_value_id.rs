@@ -14,4 +14,15 @@ impl ReadValueId {
         self.0.attributeId = attribute_id.as_u32();
         self
     }
+
+    /// Sets index range.
+    ///
+    /// This reads only the given part of array values, instead of the entire array.
+    #[must_use]
+    pub fn with_index_range(mut self, index_range: &ua::NumericRange) -> Self {
+        index_range
+            .to_string_value()
+            .move_into_raw(&mut self.0.indexRange);
+        self
+    }
 }
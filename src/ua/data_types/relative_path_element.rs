@@ -1,3 +1,5 @@
+use open62541_sys::UA_NS0ID_HIERARCHICALREFERENCES;
+
 use crate::{ua, DataType as _};
 
 crate::data_type!(RelativePathElement);
@@ -27,3 +29,37 @@ impl RelativePathElement {
         self
     }
 }
+
+impl Default for RelativePathElement {
+    /// Creates element that follows forward references of the `HierarchicalReferences` type (or
+    /// any of its subtypes), matching the defaults of [`ua::BrowseDescription`].
+    fn default() -> Self {
+        Self::init()
+            .with_reference_type_id(&ua::NodeId::ns0(UA_NS0ID_HIERARCHICALREFERENCES))
+            .with_include_subtypes(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use open62541_sys::UA_NS0ID_HIERARCHICALREFERENCES;
+
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn default_element() {
+        let target_name = ua::QualifiedName::new(2, "Boiler");
+        let element = ua::RelativePathElement::default().with_target_name(&target_name);
+
+        assert_eq!(
+            ua::NodeId::raw_ref(&element.0.referenceTypeId),
+            &ua::NodeId::ns0(UA_NS0ID_HIERARCHICALREFERENCES)
+        );
+        assert!(!element.0.isInverse);
+        assert!(element.0.includeSubtypes);
+        assert_eq!(
+            ua::QualifiedName::raw_ref(&element.0.targetName),
+            &target_name
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(ResponseHeader);
+
+impl ResponseHeader {
+    /// Gets time when the server sent the response.
+    #[must_use]
+    pub fn timestamp(&self) -> &ua::DateTime {
+        ua::DateTime::raw_ref(&self.0.timestamp)
+    }
+
+    /// Gets request handle.
+    ///
+    /// This is the handle that the client assigned to the request, to match them with responses.
+    #[must_use]
+    pub const fn request_handle(&self) -> u32 {
+        self.0.requestHandle
+    }
+
+    /// Gets service result.
+    ///
+    /// This is the status code of the service request as a whole. Individual operations in the
+    /// request have their own status codes.
+    #[must_use]
+    pub fn service_result(&self) -> &ua::StatusCode {
+        ua::StatusCode::raw_ref(&self.0.serviceResult)
+    }
+
+    /// Gets diagnostic info for service result.
+    ///
+    /// Servers return this only when requested, and many never do. Use the
+    /// [`string_table()`](Self::string_table) to resolve its string fields.
+    #[must_use]
+    pub fn service_diagnostics(&self) -> &ua::DiagnosticInfo {
+        ua::DiagnosticInfo::raw_ref(&self.0.serviceDiagnostics)
+    }
+
+    /// Gets string table for diagnostic info.
+    ///
+    /// This resolves string fields of the [`service_diagnostics()`](Self::service_diagnostics),
+    /// e.g. with [`ua::DiagnosticInfo::symbolic_id()`].
+    #[must_use]
+    pub fn string_table(&self) -> Option<ua::Array<ua::String>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.stringTableSize, self.0.stringTable)
+    }
+}
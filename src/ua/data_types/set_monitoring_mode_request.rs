@@ -0,0 +1,35 @@
+use crate::{ua, DataType as _, ServiceRequest};
+
+crate::data_type!(SetMonitoringModeRequest);
+
+impl SetMonitoringModeRequest {
+    #[must_use]
+    pub const fn with_subscription_id(mut self, subscription_id: ua::SubscriptionId) -> Self {
+        self.0.subscriptionId = subscription_id.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub fn with_monitoring_mode(mut self, monitoring_mode: &ua::MonitoringMode) -> Self {
+        monitoring_mode.clone_into_raw(&mut self.0.monitoringMode);
+        self
+    }
+
+    #[must_use]
+    pub fn with_monitored_item_ids(mut self, monitored_item_ids: &[ua::MonitoredItemId]) -> Self {
+        let array = ua::Array::from_iter(
+            monitored_item_ids
+                .iter()
+                .map(|monitored_item_id| monitored_item_id.to_uint32()),
+        );
+        array.move_into_raw(
+            &mut self.0.monitoredItemIdsSize,
+            &mut self.0.monitoredItemIds,
+        );
+        self
+    }
+}
+
+impl ServiceRequest for SetMonitoringModeRequest {
+    type Response = ua::SetMonitoringModeResponse;
+}
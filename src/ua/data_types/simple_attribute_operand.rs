@@ -0,0 +1,40 @@
+use open62541_sys::UA_NS0ID_BASEEVENTTYPE;
+
+use crate::{ua, DataType as _};
+
+crate::data_type!(SimpleAttributeOperand);
+
+impl SimpleAttributeOperand {
+    #[must_use]
+    pub fn with_type_definition_id(mut self, type_definition_id: &ua::NodeId) -> Self {
+        type_definition_id.clone_into_raw(&mut self.0.typeDefinitionId);
+        self
+    }
+
+    /// Sets browse path.
+    ///
+    /// This is the path of browse names from the type definition to the node whose attribute is
+    /// selected, e.g. the single name `Message` for the message of events.
+    #[must_use]
+    pub fn with_browse_path(mut self, browse_path: &[ua::QualifiedName]) -> Self {
+        let array = ua::Array::from_slice(browse_path);
+        array.move_into_raw(&mut self.0.browsePathSize, &mut self.0.browsePath);
+        self
+    }
+
+    #[must_use]
+    pub fn with_attribute_id(mut self, attribute_id: &ua::AttributeId) -> Self {
+        self.0.attributeId = attribute_id.as_u32();
+        self
+    }
+}
+
+impl Default for SimpleAttributeOperand {
+    /// Creates operand that selects the value attribute of nodes below the `BaseEventType`. Use
+    /// [`with_browse_path()`](Self::with_browse_path) to select the event field.
+    fn default() -> Self {
+        Self::init()
+            .with_type_definition_id(&ua::NodeId::ns0(UA_NS0ID_BASEEVENTTYPE))
+            .with_attribute_id(&ua::AttributeId::VALUE)
+    }
+}
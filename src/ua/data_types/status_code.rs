@@ -368,3 +368,40 @@ enum_variants!(
         BADMAXCONNECTIONSREACHED,
     ],
 );
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, Error};
+
+    #[test]
+    fn status_code_name() {
+        assert_eq!(ua::StatusCode::GOOD.name(), "Good");
+        assert_eq!(ua::StatusCode::BADNODEIDUNKNOWN.name(), "BadNodeIdUnknown");
+        assert_eq!(
+            ua::StatusCode::BADNODEIDUNKNOWN.to_string(),
+            "BadNodeIdUnknown"
+        );
+
+        // Errors derived from status codes are self-describing.
+        let error = Error::verify_good(&ua::StatusCode::BADNODEIDUNKNOWN).unwrap_err();
+        assert_eq!(error.to_string(), "BadNodeIdUnknown");
+    }
+
+    #[test]
+    fn status_code_severity() {
+        let status_code = ua::StatusCode::GOOD;
+        assert!(status_code.is_good());
+        assert!(!status_code.is_uncertain());
+        assert!(!status_code.is_bad());
+
+        let status_code = ua::StatusCode::UNCERTAIN;
+        assert!(!status_code.is_good());
+        assert!(status_code.is_uncertain());
+        assert!(!status_code.is_bad());
+
+        let status_code = ua::StatusCode::BADNODEIDUNKNOWN;
+        assert!(!status_code.is_good());
+        assert!(!status_code.is_uncertain());
+        assert!(status_code.is_bad());
+    }
+}
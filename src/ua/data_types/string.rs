@@ -1,4 +1,4 @@
-use std::{ffi::CString, fmt, ptr, slice, str};
+use std::{borrow::Cow, ffi::CString, fmt, ptr, slice, str};
 
 use open62541_sys::UA_String_fromChars;
 
@@ -61,7 +61,12 @@ impl String {
 
     /// Returns string contents as byte slice.
     ///
-    /// This may return [`None`] when the string itself is invalid (as defined by OPC UA).
+    /// This may return [`None`] when the string itself is invalid (as defined by OPC UA). Empty
+    /// strings return an empty slice instead, i.e. "no value" and "zero-length value" can be told
+    /// apart.
+    ///
+    /// Unlike [`as_str()`](Self::as_str), this returns the exact bytes even when they are not valid
+    /// UTF-8, e.g. when a server sends strings in legacy encodings such as Latin-1.
     #[must_use]
     pub fn as_bytes(&self) -> Option<&[u8]> {
         // Internally, `open62541` represents strings as `Byte` array and has the same special cases
@@ -85,6 +90,18 @@ impl String {
         self.as_bytes().and_then(|slice| str::from_utf8(slice).ok())
     }
 
+    /// Returns string contents as string, replacing invalid Unicode.
+    ///
+    /// Byte sequences that are not valid UTF-8 are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    /// Invalid strings (as defined by OPC UA) are returned as empty strings, as in [`Display`].
+    ///
+    /// [`Display`]: fmt::Display
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        self.as_bytes()
+            .map_or(Cow::Borrowed(""), std::string::String::from_utf8_lossy)
+    }
+
     fn array_value(&self) -> ArrayValue<u8> {
         // Internally, `open62541` represents strings as `Byte` array and has the same special cases
         // as regular arrays, i.e. empty and invalid states.
@@ -111,6 +128,32 @@ impl fmt::Display for String {
     }
 }
 
+impl TryFrom<&str> for String {
+    type Error = Error;
+
+    /// Creates string from string slice.
+    ///
+    /// See [`String::new()`] for details.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for std::string::String {
+    type Error = Error;
+
+    /// Converts string into owned Rust string.
+    ///
+    /// # Errors
+    ///
+    /// The string must not be invalid (as defined by OPC UA) and must be valid Unicode (UTF-8).
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.as_str()
+            .map(ToOwned::to_owned)
+            .ok_or(Error::internal("string should be valid UTF-8"))
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for String {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -125,7 +168,7 @@ impl serde::Serialize for String {
 
 #[cfg(test)]
 mod tests {
-    use crate::ua;
+    use crate::{ua, DataType as _};
 
     #[test]
     fn valid_string() {
@@ -143,6 +186,44 @@ mod tests {
         assert_eq!(str.to_string(), "");
     }
 
+    #[test]
+    fn invalid_string() {
+        // Invalid strings ("null" in OPC UA) are distinct from empty strings.
+        let str = ua::String::invalid();
+        assert!(str.is_invalid());
+        assert!(!str.is_empty());
+        assert_eq!(str.as_bytes(), None);
+        assert_eq!(str.as_str(), None);
+        assert_eq!(str.to_string_lossy(), "");
+        assert!(std::string::String::try_from(str).is_err());
+
+        let str = ua::String::empty();
+        assert!(!str.is_invalid());
+        assert!(str.is_empty());
+        assert_eq!(str.as_bytes(), Some(&[][..]));
+        assert_eq!(str.as_str(), Some(""));
+    }
+
+    #[test]
+    fn non_utf8_string() {
+        // Latin-1 encoding of "café", which is not valid UTF-8.
+        let bytes = [0x63, 0x61, 0x66, 0xe9];
+        let str = ua::String(ua::ByteString::new(&bytes).into_raw());
+        assert_eq!(str.as_bytes(), Some(&bytes[..]));
+        assert_eq!(str.as_str(), None);
+        assert_eq!(str.to_string_lossy(), "caf\u{fffd}");
+        assert!(std::string::String::try_from(str).is_err());
+    }
+
+    #[test]
+    fn convert_string() {
+        let str = ua::String::try_from("lorem").expect("should convert string");
+        assert_eq!(str.as_str(), Some("lorem"));
+        assert_eq!(std::string::String::try_from(str).unwrap(), "lorem");
+
+        ua::String::try_from("lorem\0ipsum").expect_err("should not convert string with NUL");
+    }
+
     #[test]
     fn pad_string() {
         let str = ua::String::new("hello").unwrap();
@@ -0,0 +1,19 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(TranslateBrowsePathsToNodeIdsResponse);
+
+impl TranslateBrowsePathsToNodeIdsResponse {
+    #[must_use]
+    pub fn results(&self) -> Option<ua::Array<ua::BrowsePathResult>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
+    }
+}
+
+impl ServiceResponse for TranslateBrowsePathsToNodeIdsResponse {
+    type Request = ua::TranslateBrowsePathsToNodeIdsRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
@@ -1,16 +1,35 @@
-use std::ffi::c_void;
+use std::{
+    ffi::{c_void, CStr},
+    fmt, ptr, slice,
+};
 
 use open62541_sys::{
-    UA_Variant_clear, UA_Variant_hasArrayType, UA_Variant_hasScalarType, UA_Variant_isEmpty,
-    UA_Variant_isScalar, UA_Variant_setArray, UA_Variant_setScalar, UA_Variant_setScalarCopy,
+    UA_DataType, UA_DataTypeKind, UA_Variant_clear, UA_Variant_hasArrayType,
+    UA_Variant_hasScalarType, UA_Variant_isEmpty, UA_Variant_isScalar, UA_Variant_setArray,
+    UA_Variant_setScalar, UA_Variant_setScalarCopy, UA_calcSizeBinary, UA_EMPTY_ARRAY_SENTINEL,
 };
 
-use crate::{ua, DataType, NonScalarValue, ScalarValue, ValueType, VariantValue};
+use crate::{ua, DataType, Error, NonScalarValue, ScalarValue, ValueType, VariantValue};
 
-crate::data_type!(Variant);
+crate::data_type!(@custom_debug Variant);
 
 impl Variant {
     /// Creates variant from scalar.
+    ///
+    /// This takes ownership of the value without copying it. Use [`with_scalar()`] to create the
+    /// variant from a reference instead.
+    ///
+    /// ```
+    /// # use open62541::{Client, Result, ua};
+    /// # fn example(client: &Client) -> Result<()> {
+    /// let value = ua::Variant::scalar(ua::String::new("lorem ipsum")?);
+    ///
+    /// client.write_value(&ua::NodeId::string(1, "Message"), &value)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`with_scalar()`]: Self::with_scalar
     #[must_use]
     pub fn scalar<T: DataType>(value: T) -> Self {
         let mut variant = Self::init();
@@ -24,6 +43,17 @@ impl Variant {
     }
 
     /// Creates variant from array.
+    ///
+    /// This takes ownership of the array without copying it. Use [`ua::Array::from_slice()`] to
+    /// create the array from a slice of values.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let array = ua::Array::from_slice(&[1.0, 2.0, 3.0].map(ua::Double::new));
+    /// let value = ua::Variant::array(array);
+    /// assert!(value.is_array());
+    /// ```
     #[must_use]
     pub fn array<T: DataType>(value: ua::Array<T>) -> Self {
         let mut variant = Self::init();
@@ -41,6 +71,41 @@ impl Variant {
         variant
     }
 
+    /// Creates variant from multi-dimensional array.
+    ///
+    /// The elements in `data` are expected in row-major order, i.e. the last dimension varies
+    /// fastest. This copies the values into the variant.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// // 2x3 matrix with rows `[1, 2, 3]` and `[4, 5, 6]`.
+    /// let data = [1, 2, 3, 4, 5, 6].map(ua::Int32::new);
+    /// let value = ua::Variant::matrix(&data, &[2, 3]).unwrap();
+    /// assert_eq!(value.array_dimensions(), [2, 3]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The product of all dimensions must match the number of elements in `data`.
+    pub fn matrix<T: DataType>(data: &[T], dimensions: &[u32]) -> Result<Self, Error> {
+        let length = array_length(dimensions);
+        if length != Some(data.len()) {
+            return Err(Error::internal("dimensions should match array length"));
+        }
+
+        let mut variant = Self::array(ua::Array::from_slice(data));
+        let dimensions = ua::Array::from_iter(dimensions.iter().copied().map(ua::UInt32::new));
+        dimensions.move_into_raw(
+            &mut variant.0.arrayDimensionsSize,
+            &mut variant.0.arrayDimensions,
+        );
+        Ok(variant)
+    }
+
+    /// Sets scalar value.
+    ///
+    /// This copies the value into the variant, replacing any previous value.
     #[must_use]
     pub fn with_scalar<T: DataType>(mut self, value: &T) -> Self {
         // The call to `UA_Variant_setScalarCopy()` does not free held memory which would lead to a
@@ -74,30 +139,111 @@ impl Variant {
         self.type_id().map(ValueType::from_data_type)
     }
 
+    /// Checks if variant is empty.
+    ///
+    /// Empty variants hold no value at all, neither scalar nor array.
     #[must_use]
     pub fn is_empty(&self) -> bool {
         unsafe { UA_Variant_isEmpty(self.as_ptr()) }
     }
 
+    /// Checks if variant holds scalar value.
     #[must_use]
     pub fn is_scalar(&self) -> bool {
         unsafe { UA_Variant_isScalar(self.as_ptr()) }
     }
 
+    /// Checks if variant holds array value.
+    ///
+    /// This includes empty arrays, i.e. arrays of length 0. Note that these are distinct from empty
+    /// variants, see [`is_empty()`](Self::is_empty).
+    #[must_use]
+    pub fn is_array(&self) -> bool {
+        // `UA_Variant_isScalar()` checks for `arrayLength == 0` and data that is not the sentinel
+        // value of empty arrays. Everything else that has a data type is an array.
+        !self.is_empty() && !self.is_scalar()
+    }
+
+    /// Gets total number of array elements.
+    ///
+    /// This returns `None` when the variant does not hold an array.
+    pub(crate) fn array_length(&self) -> Option<usize> {
+        self.is_array().then_some(self.0.arrayLength)
+    }
+
+    /// Gets array dimensions.
+    ///
+    /// This returns the lengths of all dimensions of multi-dimensional arrays. The slice is empty
+    /// when no dimensions have been set: this is the case for scalars and usually also the case for
+    /// one-dimensional arrays (where the single dimension is the array length itself).
+    #[must_use]
+    pub fn array_dimensions(&self) -> &[u32] {
+        // SAFETY: We only borrow from `self` and do not give away ownership.
+        let array_dimensions = unsafe {
+            ua::Array::<ua::UInt32>::slice_from_raw_parts(
+                self.0.arrayDimensionsSize,
+                self.0.arrayDimensions,
+            )
+        };
+        let array_dimensions = array_dimensions.unwrap_or(&[]);
+        // SAFETY: `ua::UInt32` is a transparent wrapper around `UA_UInt32` which is `u32`.
+        unsafe {
+            slice::from_raw_parts(
+                array_dimensions.as_ptr().cast::<u32>(),
+                array_dimensions.len(),
+            )
+        }
+    }
+
+    /// Gets reference to scalar value.
+    ///
+    /// This returns `None` when the variant is empty, holds an array, or holds a scalar of another
+    /// data type than `T`. See [`to_scalar()`](Self::to_scalar) for an owned copy of the value.
     #[must_use]
     pub fn as_scalar<T: DataType>(&self) -> Option<&T> {
         self.scalar_data::<T>().map(T::raw_ref)
     }
 
+    /// Gets copy of scalar value.
+    ///
+    /// This returns `None` when the variant is empty, holds an array, or holds a scalar of another
+    /// data type than `T`. No conversion between data types is attempted, except that structured
+    /// values wrapped in [`ua::ExtensionObject`] are decoded (if their data type matches `T`), and
+    /// that [`ua::Int32`] values are accepted for enumeration data types such as [`ua::NodeClass`].
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let variant = ua::Variant::scalar(ua::Double::new(12.5));
+    ///
+    /// let value = variant.to_scalar::<ua::Double>().map(|value| value.value());
+    /// assert_eq!(value, Some(12.5));
+    ///
+    /// // Data type must match exactly.
+    /// assert_eq!(variant.to_scalar::<ua::Float>(), None);
+    /// ```
     #[must_use]
     pub fn to_scalar<T: DataType>(&self) -> Option<T> {
-        self.scalar_data::<T>().map(T::clone_raw)
+        if let Some(value) = self.scalar_data::<T>() {
+            return Some(T::clone_raw(value));
+        }
+        // Structured values are transmitted as extension objects. open62541 decodes them only when
+        // it knows the data type, so we may still have to do so here.
+        self.scalar_data::<ua::ExtensionObject>()
+            .map(ua::ExtensionObject::raw_ref)
+            .and_then(ua::ExtensionObject::decode::<T>)
     }
 
     #[must_use]
     fn scalar_data<T: DataType>(&self) -> Option<&T::Inner> {
         if unsafe { UA_Variant_hasScalarType(self.as_ptr(), T::data_type()) } {
             unsafe { self.0.data.cast::<T::Inner>().as_ref() }
+        } else if is_enum_data_type(T::data_type())
+            && unsafe { UA_Variant_hasScalarType(self.as_ptr(), ua::Int32::data_type()) }
+        {
+            // Enumeration values are transmitted as `Int32`: the receiver cannot know which of the
+            // enumeration data types was used. Both share the same memory layout.
+            unsafe { self.0.data.cast::<T::Inner>().as_ref() }
         } else {
             if T::data_type() != Self::data_type() {
                 return None;
@@ -109,6 +255,25 @@ impl Variant {
         }
     }
 
+    /// Gets copy of array value.
+    ///
+    /// This returns `None` when the variant is empty, holds a scalar, or holds an array of another
+    /// element type than `T`. Use [`ua::Array::into_vec()`] to turn the result into a [`Vec`].
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let array = ua::Array::from_slice(&[1.0, 2.5].map(ua::Double::new));
+    /// let variant = ua::Variant::array(array);
+    ///
+    /// let values: Vec<f64> = variant
+    ///     .to_array::<ua::Double>()
+    ///     .expect("should be array of doubles")
+    ///     .iter()
+    ///     .map(ua::Double::value)
+    ///     .collect();
+    /// assert_eq!(values, [1.0, 2.5]);
+    /// ```
     #[must_use]
     pub fn to_array<T: DataType>(&self) -> Option<ua::Array<T>> {
         if !unsafe { UA_Variant_hasArrayType(self.as_ptr(), T::data_type()) } {
@@ -131,6 +296,132 @@ impl Variant {
         ua::Array::from_raw_parts(self.0.arrayLength, self.0.data.cast::<T::Inner>())
     }
 
+    /// Gets copy of array of structured values.
+    ///
+    /// Arrays of structures, e.g. the `InputArguments` property of methods, are transmitted as
+    /// arrays of [`ua::ExtensionObject`]. Unlike [`to_array()`](Self::to_array), this decodes each
+    /// element into `T`. Arrays that already hold elements of type `T` are returned as-is.
+    ///
+    /// This returns `None` when the variant is empty, holds a scalar, or when any of the elements
+    /// cannot be decoded into `T`.
+    ///
+    /// ```
+    /// use open62541::{ua, DataType as _};
+    ///
+    /// let argument = ua::ExtensionObject::new(&ua::Argument::init());
+    /// let variant = ua::Variant::array(ua::Array::from_slice(&[argument.clone(), argument]));
+    ///
+    /// let arguments = variant.to_struct_array::<ua::Argument>().unwrap();
+    /// assert_eq!(arguments.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn to_struct_array<T: DataType>(&self) -> Option<Vec<T>> {
+        if let Some(array) = self.to_array::<T>() {
+            return Some(array.into_vec());
+        }
+        self.to_array::<ua::ExtensionObject>()?
+            .iter()
+            .map(ua::ExtensionObject::decode::<T>)
+            .collect()
+    }
+
+    /// Gets copy of multi-dimensional array value.
+    ///
+    /// This returns the elements in row-major order along with the array dimensions. When the
+    /// variant holds a one-dimensional array without explicit dimensions, the array length is
+    /// returned as single dimension.
+    ///
+    /// This returns `None` under the same conditions as [`to_array()`](Self::to_array), or when the
+    /// dimensions do not match the array length.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6].map(ua::Int32::new);
+    /// let value = ua::Variant::matrix(&data, &[2, 3]).unwrap();
+    ///
+    /// let (elements, dimensions) = value.to_matrix::<ua::Int32>().unwrap();
+    /// assert_eq!(elements, data);
+    /// assert_eq!(dimensions, [2, 3]);
+    /// ```
+    #[must_use]
+    pub fn to_matrix<T: DataType>(&self) -> Option<(Vec<T>, Vec<u32>)> {
+        let elements = self.to_array::<T>()?.into_vec();
+
+        let dimensions = self.array_dimensions();
+        if dimensions.is_empty() {
+            let length = u32::try_from(elements.len()).ok()?;
+            return Some((elements, vec![length]));
+        }
+
+        let length = array_length(dimensions);
+        // Reject inconsistent values that we might have received from remote peers.
+        if length != Some(elements.len()) {
+            return None;
+        }
+
+        Some((elements, dimensions.to_vec()))
+    }
+
+    /// Checks if variant is approximately equal to other variant.
+    ///
+    /// Floating-point values of types [`ua::Float`] and [`ua::Double`] are considered equal when
+    /// they differ by at most `epsilon`. This applies to scalars and, element by element, to arrays
+    /// with the same dimensions. All other values must be exactly equal, as with `==`. Values of
+    /// different data types are never equal, not even `Float` and `Double`.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let value = ua::Variant::scalar(ua::Double::new(21.5));
+    /// let other = ua::Variant::scalar(ua::Double::new(21.500_001));
+    ///
+    /// assert_ne!(value, other);
+    /// assert!(value.approx_eq(&other, 1e-3));
+    /// ```
+    ///
+    /// Unlike `==`, this never considers floating-point values equal when either of them is NaN.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let is_close = |a: f64, b: f64| (a - b).abs() <= epsilon;
+
+        if let (Some(a), Some(b)) = (
+            self.as_scalar::<ua::Double>(),
+            other.as_scalar::<ua::Double>(),
+        ) {
+            return is_close(a.value(), b.value());
+        }
+        if let (Some(a), Some(b)) = (
+            self.as_scalar::<ua::Float>(),
+            other.as_scalar::<ua::Float>(),
+        ) {
+            return is_close(f64::from(a.value()), f64::from(b.value()));
+        }
+
+        if self.type_id() == other.type_id() && self.array_dimensions() == other.array_dimensions()
+        {
+            if let (Some(a), Some(b)) = (
+                self.to_array::<ua::Double>(),
+                other.to_array::<ua::Double>(),
+            ) {
+                return a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(a, b)| is_close(a.value(), b.value()));
+            }
+            if let (Some(a), Some(b)) =
+                (self.to_array::<ua::Float>(), other.to_array::<ua::Float>())
+            {
+                return a.len() == b.len()
+                    && a.iter()
+                        .zip(b.iter())
+                        .all(|(a, b)| is_close(f64::from(a.value()), f64::from(b.value())));
+            }
+        }
+
+        self == other
+    }
+
     #[must_use]
     pub fn to_value(&self) -> VariantValue {
         if self.is_empty() {
@@ -183,6 +474,357 @@ impl Variant {
     pub fn json(&self) -> Option<serde_json::Value> {
         serde_json::to_value(self).ok()
     }
+
+    /// Encodes variant with OPC UA JSON encoding.
+    ///
+    /// Unlike the `serde` serialization (see [`json()`](Self::json)), this uses the reversible
+    /// encoding defined by the OPC UA specification. It includes the data type of the value and
+    /// thus supports all data types:
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let value = ua::Variant::scalar(ua::Float::new(1.5));
+    /// assert_eq!(value.to_json().unwrap(), r#"{"Type":10,"Body":1.5}"#);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the value cannot be encoded.
+    pub fn to_json(&self) -> Result<String, Error> {
+        crate::encoding::encode_json(self)
+    }
+
+    /// Decodes variant from OPC UA JSON encoding.
+    ///
+    /// This is the inverse of [`to_json()`](Self::to_json).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the string is not valid JSON or does not describe a variant.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        crate::encoding::decode_json(json)
+    }
+}
+
+/// Gets number of elements in array with the given dimensions.
+///
+/// This returns `None` when the number would overflow.
+fn array_length(dimensions: &[u32]) -> Option<usize> {
+    dimensions.iter().try_fold(1_usize, |length, &dimension| {
+        usize::try_from(dimension)
+            .ok()
+            .and_then(|dimension| length.checked_mul(dimension))
+    })
+}
+
+macro_rules! from_primitive {
+    ($( ($name:ident, $type:ty) ),* $(,)?) => {
+        $(
+            impl From<$type> for Variant {
+                #[doc = concat!("Creates variant with scalar [`ua::", stringify!($name), "`].")]
+                fn from(value: $type) -> Self {
+                    Self::scalar(ua::$name::new(value))
+                }
+            }
+
+            impl From<&[$type]> for Variant {
+                #[doc = concat!("Creates variant with array of [`ua::", stringify!($name), "`].")]
+                fn from(value: &[$type]) -> Self {
+                    Self::array(ua::Array::from_iter(value.iter().copied().map(ua::$name::new)))
+                }
+            }
+
+            impl From<Vec<$type>> for Variant {
+                #[doc = concat!("Creates variant with array of [`ua::", stringify!($name), "`].")]
+                fn from(value: Vec<$type>) -> Self {
+                    Self::from(value.as_slice())
+                }
+            }
+
+            impl TryFrom<Variant> for $type {
+                type Error = TypeMismatchError;
+
+                #[doc = concat!("Gets value of scalar [`ua::", stringify!($name), "`].")]
+                fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                    value
+                        .as_scalar::<ua::$name>()
+                        .map(ua::$name::value)
+                        .ok_or_else(|| TypeMismatchError::new(ua::$name::type_name(), &value))
+                }
+            }
+
+            impl TryFrom<Variant> for Vec<$type> {
+                type Error = TypeMismatchError;
+
+                #[doc = concat!("Gets values of array of [`ua::", stringify!($name), "`].")]
+                fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                    value
+                        .to_array::<ua::$name>()
+                        .map(|array| array.iter().map(ua::$name::value).collect())
+                        .ok_or_else(|| {
+                            let expected = format!("array of {}", ua::$name::type_name());
+                            TypeMismatchError::new(expected, &value)
+                        })
+                }
+            }
+        )*
+    };
+}
+
+from_primitive!(
+    (Boolean, bool),
+    (SByte, i8),
+    (Byte, u8),
+    (Int16, i16),
+    (UInt16, u16),
+    (Int32, i32),
+    (UInt32, u32),
+    (Int64, i64),
+    (UInt64, u64),
+    (Float, f32),
+    (Double, f64),
+);
+
+impl TryFrom<&str> for Variant {
+    type Error = Error;
+
+    /// Creates variant with scalar [`ua::String`].
+    ///
+    /// See [`ua::String::new()`] for details.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ua::String::new(value).map(Self::scalar)
+    }
+}
+
+impl TryFrom<Variant> for String {
+    type Error = TypeMismatchError;
+
+    /// Gets value of scalar [`ua::String`].
+    ///
+    /// This fails when the string is invalid (as defined by OPC UA) or not valid Unicode (UTF-8).
+    fn try_from(value: Variant) -> Result<Self, Self::Error> {
+        value
+            .as_scalar::<ua::String>()
+            .and_then(ua::String::as_str)
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| TypeMismatchError::new("String with valid UTF-8", &value))
+    }
+}
+
+/// Error when converting [`Variant`] into Rust type.
+///
+/// This tells the expected data type and the data type that the variant held instead.
+///
+/// ```
+/// use open62541::ua;
+///
+/// let error = i32::try_from(ua::Variant::from(2.5)).unwrap_err();
+/// assert_eq!(error.expected(), "Int32");
+/// assert_eq!(error.actual(), "Double");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("expected {expected}, got {actual}")]
+pub struct TypeMismatchError {
+    expected: String,
+    actual: String,
+}
+
+impl TypeMismatchError {
+    fn new(expected: impl Into<String>, value: &Variant) -> Self {
+        let actual = match unsafe { value.0.type_.as_ref() } {
+            None => "empty variant".to_owned(),
+            Some(data_type) if value.is_scalar() => type_name(data_type).to_owned(),
+            Some(data_type) => format!("array of {}", type_name(data_type)),
+        };
+        Self {
+            expected: expected.into(),
+            actual,
+        }
+    }
+
+    /// Gets description of expected data type.
+    #[must_use]
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Gets description of data type that was found instead.
+    #[must_use]
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+}
+
+impl fmt::Debug for Variant {
+    /// Formats variant with its data type and value.
+    ///
+    /// Values of built-in data types such as numbers, booleans, and strings are rendered as such.
+    /// Other values are summarized by their data type and their length in binary encoding.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let value = ua::Variant::scalar(ua::Int32::new(42));
+    /// assert_eq!(format!("{value:?}"), r#"Variant { type: "Int32", value: 42 }"#);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: When set, the pointer refers to a data type description which lives for as long
+        // as `self` does (usually, this is a static variable).
+        let Some(data_type) = (unsafe { self.0.type_.as_ref() }) else {
+            return f.write_str("Variant(Empty)");
+        };
+
+        let mut output = f.debug_struct("Variant");
+        output.field("type", &type_name(data_type));
+
+        if self.is_scalar() {
+            output.field(
+                "value",
+                &DebugValue {
+                    data_type,
+                    data: self.0.data,
+                },
+            );
+        } else {
+            output.field("length", &self.0.arrayLength);
+            let dimensions = self.array_dimensions();
+            if !dimensions.is_empty() {
+                output.field("dimensions", &dimensions);
+            }
+            // Undefined arrays have no values at all. Empty arrays use the sentinel value that must
+            // not be accessed, even when the array length would be inconsistent with that.
+            if !self.0.data.is_null() {
+                let is_empty = self.0.data.cast_const() == unsafe { UA_EMPTY_ARRAY_SENTINEL };
+                output.field(
+                    "values",
+                    &DebugValues {
+                        data_type,
+                        data: self.0.data,
+                        length: if is_empty { 0 } else { self.0.arrayLength },
+                    },
+                );
+            }
+        }
+
+        output.finish()
+    }
+}
+
+/// Gets name of data type.
+///
+/// This falls back to a generic name when the data type has no name.
+fn type_name(data_type: &UA_DataType) -> &str {
+    if data_type.typeName.is_null() {
+        return "Unknown";
+    }
+    // SAFETY: When set, `typeName` is a NUL-terminated string that lives as long as the data type.
+    let type_name = unsafe { CStr::from_ptr(data_type.typeName) };
+    type_name.to_str().unwrap_or("Unknown")
+}
+
+/// Helper to format single value in variant.
+struct DebugValue<'a> {
+    data_type: &'a UA_DataType,
+    /// Pointer to valid value of `data_type`.
+    data: *const c_void,
+}
+
+impl fmt::Debug for DebugValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        macro_rules! primitive {
+            ($( $name:ident ),* $(,)?) => {
+                $(
+                    if ptr::eq(self.data_type, ua::$name::data_type()) {
+                        // SAFETY: Data type matches, the value lives as long as `self` does.
+                        let value = unsafe { &*self.data.cast::<<ua::$name as DataType>::Inner>() };
+                        return fmt::Debug::fmt(&ua::$name::raw_ref(value).value(), f);
+                    }
+                )*
+            };
+        }
+
+        macro_rules! builtin {
+            ($( $name:ident ),* $(,)?) => {
+                $(
+                    if ptr::eq(self.data_type, ua::$name::data_type()) {
+                        // SAFETY: Data type matches, the value lives as long as `self` does.
+                        let value = unsafe { &*self.data.cast::<<ua::$name as DataType>::Inner>() };
+                        return fmt::Debug::fmt(ua::$name::raw_ref(value), f);
+                    }
+                )*
+            };
+        }
+
+        primitive!(
+            Boolean, // Data type ns=0;i=1
+            SByte,   // Data type ns=0;i=2
+            Byte,    // Data type ns=0;i=3
+            Int16,   // Data type ns=0;i=4
+            UInt16,  // Data type ns=0;i=5
+            Int32,   // Data type ns=0;i=6
+            UInt32,  // Data type ns=0;i=7
+            Int64,   // Data type ns=0;i=8
+            UInt64,  // Data type ns=0;i=9
+            Float,   // Data type ns=0;i=10
+            Double,  // Data type ns=0;i=11
+        );
+
+        if ptr::eq(self.data_type, ua::String::data_type()) {
+            // SAFETY: Data type matches, the value lives as long as `self` does.
+            let value = unsafe { &*self.data.cast::<<ua::String as DataType>::Inner>() };
+            return fmt::Debug::fmt(&ua::String::raw_ref(value).to_string_lossy(), f);
+        }
+
+        builtin!(
+            DateTime,       // Data type ns=0;i=13
+            NodeId,         // Data type ns=0;i=17
+            ExpandedNodeId, // Data type ns=0;i=18
+            StatusCode,     // Data type ns=0;i=19
+            QualifiedName,  // Data type ns=0;i=20
+            LocalizedText,  // Data type ns=0;i=21
+        );
+
+        // SAFETY: `data` points to valid value of `data_type`.
+        let length = unsafe { UA_calcSizeBinary(self.data, self.data_type) };
+        write!(f, "{} ({length} bytes)", type_name(self.data_type))
+    }
+}
+
+/// Checks if data type is an enumeration.
+fn is_enum_data_type(data_type: *const UA_DataType) -> bool {
+    // This cast is necessary on Windows builds with inner type `i32`.
+    #[allow(clippy::as_conversions, trivial_numeric_casts)]
+    let enum_kind = UA_DataTypeKind::UA_DATATYPEKIND_ENUM.0 as u32;
+    // SAFETY: Data types are valid for the lifetime of the program.
+    unsafe { data_type.as_ref() }.is_some_and(|data_type| data_type.typeKind() == enum_kind)
+}
+
+/// Helper to format array values in variant.
+struct DebugValues<'a> {
+    data_type: &'a UA_DataType,
+    /// Pointer to `length` consecutive values of `data_type`.
+    data: *const c_void,
+    length: usize,
+}
+
+impl fmt::Debug for DebugValues<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Ok(element_size) = usize::try_from(self.data_type.memSize()) else {
+            return f.write_str("[..]");
+        };
+
+        let values = (0..self.length).map_while(|index| {
+            let offset = index.checked_mul(element_size)?;
+            Some(DebugValue {
+                data_type: self.data_type,
+                // SAFETY: Offset stays within the allocated array of `length` elements.
+                data: unsafe { self.data.cast::<u8>().add(offset) }.cast::<c_void>(),
+            })
+        });
+
+        f.debug_list().entries(values).finish()
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -244,14 +886,331 @@ impl serde::Serialize for Variant {
     }
 }
 
+#[cfg(feature = "serde")]
+mod deserialize {
+    use std::fmt;
+
+    use serde::de::{self, Visitor as _};
+
+    use crate::{ua, DataType};
+
+    /// Deserializes variant.
+    ///
+    /// This accepts the format that the `Serialize` implementation of [`ua::Variant`] emits: bare
+    /// values without their data type. The data type is inferred from the value as follows:
+    ///
+    /// - Booleans become [`ua::Boolean`].
+    /// - Integers become [`ua::Int32`], or [`ua::Int64`] and [`ua::UInt64`] when out of range.
+    /// - Floating-point numbers become [`ua::Double`].
+    /// - Strings become [`ua::String`].
+    /// - Sequences become arrays of the narrowest of the above types that holds all elements.
+    ///   Mixing integers and floating-point numbers results in [`ua::Double`] arrays, as long as
+    ///   all integers can be represented exactly (up to 2^53 in magnitude).
+    ///
+    /// Thus, values round-trip only when they have one of these types: [`ua::Float`] values and
+    /// other integer types come back as [`ua::Double`] and [`ua::Int32`] (or wider) respectively,
+    /// while [`ua::ByteString`], [`ua::DateTime`], and [`ua::NodeId`] values come back as integer
+    /// arrays or strings. Unsupported payloads that fail to deserialize are `null` (the empty
+    /// variant), maps, empty sequences (with no element to infer the data type from), nested
+    /// sequences, and sequences that mix booleans, numbers, and strings (or larger integers and
+    /// floating-point numbers).
+    impl<'de> serde::Deserialize<'de> for ua::Variant {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(VariantVisitor)
+        }
+    }
+
+    /// Scalar value before turning it into [`ua::Variant`].
+    enum Value {
+        Boolean(bool),
+        Int32(i32),
+        Int64(i64),
+        UInt64(u64),
+        Double(f64),
+        String(ua::String),
+    }
+
+    impl Value {
+        fn from_i64(value: i64) -> Self {
+            i32::try_from(value).map_or(Self::Int64(value), Self::Int32)
+        }
+
+        fn from_u64(value: u64) -> Self {
+            i64::try_from(value).map_or(Self::UInt64(value), Self::from_i64)
+        }
+
+        fn into_variant(self) -> ua::Variant {
+            match self {
+                Self::Boolean(value) => ua::Variant::scalar(ua::Boolean::new(value)),
+                Self::Int32(value) => ua::Variant::scalar(ua::Int32::new(value)),
+                Self::Int64(value) => ua::Variant::scalar(ua::Int64::new(value)),
+                Self::UInt64(value) => ua::Variant::scalar(ua::UInt64::new(value)),
+                Self::Double(value) => ua::Variant::scalar(ua::Double::new(value)),
+                Self::String(value) => ua::Variant::scalar(value),
+            }
+        }
+
+        const fn as_boolean(&self) -> Option<bool> {
+            match self {
+                Self::Boolean(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        fn as_string(&self) -> Option<ua::String> {
+            match self {
+                Self::String(value) => Some(value.clone()),
+                _ => None,
+            }
+        }
+
+        const fn as_i32(&self) -> Option<i32> {
+            match self {
+                Self::Int32(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        fn as_i64(&self) -> Option<i64> {
+            match self {
+                Self::Int32(value) => Some(i64::from(*value)),
+                Self::Int64(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        fn as_u64(&self) -> Option<u64> {
+            match self {
+                Self::Int32(value) => u64::try_from(*value).ok(),
+                Self::Int64(value) => u64::try_from(*value).ok(),
+                Self::UInt64(value) => Some(*value),
+                _ => None,
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)] // We check the range before casting.
+        fn as_f64(&self) -> Option<f64> {
+            // Integers up to this magnitude convert losslessly into `f64`.
+            const MAX_EXACT: u64 = 1 << f64::MANTISSA_DIGITS;
+
+            match self {
+                Self::Int32(value) => Some(f64::from(*value)),
+                Self::Int64(value) => (value.unsigned_abs() <= MAX_EXACT).then_some(*value as f64),
+                Self::UInt64(value) => (*value <= MAX_EXACT).then_some(*value as f64),
+                Self::Double(value) => Some(*value),
+                _ => None,
+            }
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Value {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ValueVisitor)
+        }
+    }
+
+    struct ValueVisitor;
+
+    impl<'de> de::Visitor<'de> for ValueVisitor {
+        type Value = Value;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a boolean, number, or string")
+        }
+
+        fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+            Ok(Value::Boolean(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(Value::from_i64(v))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(Value::from_u64(v))
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            Ok(Value::Double(v))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            ua::String::new(v)
+                .map(Value::String)
+                .map_err(de::Error::custom)
+        }
+    }
+
+    struct VariantVisitor;
+
+    impl<'de> de::Visitor<'de> for VariantVisitor {
+        type Value = ua::Variant;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a boolean, number, or string, or a sequence of them")
+        }
+
+        fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+            ValueVisitor.visit_bool(v).map(Value::into_variant)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            ValueVisitor.visit_i64(v).map(Value::into_variant)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            ValueVisitor.visit_u64(v).map(Value::into_variant)
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            ValueVisitor.visit_f64(v).map(Value::into_variant)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            ValueVisitor.visit_str(v).map(Value::into_variant)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut values = Vec::new();
+            while let Some(value) = seq.next_element::<Value>()? {
+                values.push(value);
+            }
+
+            if values.is_empty() {
+                return Err(de::Error::custom(
+                    "cannot infer data type of empty sequence",
+                ));
+            }
+
+            // Try narrower types first. Each attempt succeeds only when all elements fit.
+            to_array(&values, |value| value.as_boolean().map(ua::Boolean::new))
+                .or_else(|| to_array(&values, Value::as_string))
+                .or_else(|| to_array(&values, |value| value.as_i32().map(ua::Int32::new)))
+                .or_else(|| to_array(&values, |value| value.as_i64().map(ua::Int64::new)))
+                .or_else(|| to_array(&values, |value| value.as_u64().map(ua::UInt64::new)))
+                .or_else(|| to_array(&values, |value| value.as_f64().map(ua::Double::new)))
+                .ok_or_else(|| de::Error::custom("sequence elements should have compatible types"))
+        }
+    }
+
+    /// Creates array variant when all values can be converted.
+    fn to_array<T: DataType>(
+        values: &[Value],
+        f: impl Fn(&Value) -> Option<T>,
+    ) -> Option<ua::Variant> {
+        let elements: Vec<T> = values.iter().map(f).collect::<Option<_>>()?;
+        let array = ua::Array::from_iter(elements.into_iter());
+        Some(ua::Variant::array(array))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use open62541_sys::{
-        UA_NS0ID_BOOLEAN, UA_NS0ID_BYTE, UA_NS0ID_INT16, UA_NS0ID_INT64, UA_NS0ID_UINT32,
+        UA_NS0ID_BOOLEAN, UA_NS0ID_BYTE, UA_NS0ID_DOUBLE, UA_NS0ID_INT16, UA_NS0ID_INT32,
+        UA_NS0ID_INT64, UA_NS0ID_STRING, UA_NS0ID_UINT16, UA_NS0ID_UINT32,
     };
 
     use crate::{ua, DataType as _, ValueType};
 
+    #[test]
+    fn enum_from_int32() {
+        // Enumeration values are received as `Int32`.
+        let variant = ua::Variant::scalar(ua::Int32::new(2));
+        assert_eq!(
+            variant.to_scalar::<ua::NodeClass>(),
+            Some(ua::NodeClass::VARIABLE)
+        );
+        assert_eq!(
+            variant.as_scalar::<ua::NodeClass>(),
+            Some(&ua::NodeClass::VARIABLE)
+        );
+
+        // This does not apply to other data types.
+        assert_eq!(variant.to_scalar::<ua::UInt32>(), None);
+    }
+
+    #[test]
+    fn struct_array() {
+        let arguments = [
+            ua::Argument::new(
+                "setpoint",
+                &ua::NodeId::ns0(UA_NS0ID_DOUBLE),
+                -1,
+                &ua::LocalizedText::new("", "Setpoint").unwrap(),
+            )
+            .unwrap(),
+            ua::Argument::new(
+                "names",
+                &ua::NodeId::ns0(UA_NS0ID_STRING),
+                1,
+                &ua::LocalizedText::new("", "Names").unwrap(),
+            )
+            .unwrap(),
+        ];
+        // This is how arrays of structures are received from servers.
+        let extension_objects: Vec<_> = arguments
+            .iter()
+            .map(|argument| ua::ExtensionObject::encode(argument).unwrap())
+            .collect();
+        let variant = ua::Variant::array(ua::Array::from_slice(&extension_objects));
+
+        assert_eq!(
+            variant
+                .to_array::<ua::ExtensionObject>()
+                .map(ua::Array::into_vec),
+            Some(extension_objects)
+        );
+        assert_eq!(
+            variant.to_struct_array::<ua::Argument>(),
+            Some(arguments.to_vec())
+        );
+
+        // All elements must be decodable.
+        assert_eq!(variant.to_struct_array::<ua::ReadValueId>(), None);
+        let variant = ua::Variant::scalar(arguments[0].clone());
+        assert_eq!(variant.to_struct_array::<ua::Argument>(), None);
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let data = [1, 2, 3, 4, 5, 6].map(ua::Int32::new);
+        let variant = ua::Variant::matrix(&data, &[2, 3]).expect("should create matrix");
+        assert!(variant.is_array());
+        assert_eq!(variant.array_dimensions(), [2, 3]);
+
+        let (elements, dimensions) = variant.to_matrix::<ua::Int32>().expect("should get matrix");
+        assert_eq!(elements, data);
+        assert_eq!(dimensions, [2, 3]);
+
+        // Element type must match.
+        assert_eq!(variant.to_matrix::<ua::UInt32>(), None);
+
+        // One-dimensional arrays have their length as single dimension.
+        let variant = ua::Variant::array(ua::Array::from_slice(&data));
+        let (elements, dimensions) = variant.to_matrix::<ua::Int32>().expect("should get matrix");
+        assert_eq!(elements, data);
+        assert_eq!(dimensions, [6]);
+    }
+
+    #[test]
+    fn matrix_dimension_mismatch() {
+        let data = [1, 2, 3, 4, 5, 6].map(ua::Int32::new);
+        assert!(ua::Variant::matrix(&data, &[2, 2]).is_err());
+        assert!(ua::Variant::matrix(&data, &[2, 3, 2]).is_err());
+        assert!(ua::Variant::matrix(&data, &[]).is_err());
+        assert!(ua::Variant::matrix(&data, &[u32::MAX, u32::MAX, u32::MAX]).is_err());
+    }
+
     #[test]
     fn type_empty() {
         let ua_variant = ua::Variant::init();
@@ -323,6 +1282,237 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_variant() {
+        // Owned scalar is moved into variant.
+        let ua_variant = ua::Variant::scalar(ua::String::new("lorem").unwrap());
+        assert_eq!(
+            ua_variant
+                .as_scalar::<ua::String>()
+                .and_then(ua::String::as_str),
+            Some("lorem")
+        );
+
+        // Borrowed scalar is copied into variant, the original value remains usable.
+        let ua_string = ua::String::new("ipsum").unwrap();
+        let ua_variant = ua::Variant::init()
+            .with_scalar(&ua::Int32::new(1))
+            .with_scalar(&ua_string);
+        assert_eq!(ua_variant.to_scalar(), Some(ua_string));
+        assert_eq!(ua_variant.to_scalar::<ua::Int32>(), None);
+    }
+
+    #[test]
+    fn convert_from_primitive() {
+        let variant = ua::Variant::from(2.5_f64);
+        assert_eq!(variant.type_id(), Some(&ua::NodeId::ns0(UA_NS0ID_DOUBLE)));
+        assert_eq!(variant.to_scalar(), Some(ua::Double::new(2.5)));
+
+        let variant = ua::Variant::from(42_i32);
+        assert_eq!(variant.type_id(), Some(&ua::NodeId::ns0(UA_NS0ID_INT32)));
+        assert_eq!(variant.to_scalar(), Some(ua::Int32::new(42)));
+
+        let variant = ua::Variant::from(true);
+        assert_eq!(variant.type_id(), Some(&ua::NodeId::ns0(UA_NS0ID_BOOLEAN)));
+        assert_eq!(variant.to_scalar(), Some(ua::Boolean::new(true)));
+
+        let variant = ua::Variant::from(8080_u16);
+        assert_eq!(variant.type_id(), Some(&ua::NodeId::ns0(UA_NS0ID_UINT16)));
+        assert_eq!(variant.to_scalar(), Some(ua::UInt16::new(8080)));
+
+        // The string is copied into the variant and released along with it.
+        let variant = ua::Variant::try_from("lorem ipsum").unwrap();
+        assert_eq!(variant.type_id(), Some(&ua::NodeId::ns0(UA_NS0ID_STRING)));
+        assert_eq!(
+            variant
+                .as_scalar::<ua::String>()
+                .and_then(ua::String::as_str),
+            Some("lorem ipsum")
+        );
+        assert!(ua::Variant::try_from("lorem\0ipsum").is_err());
+
+        let variant = ua::Variant::from(&[1, 2, 3][..]);
+        assert_eq!(variant.type_id(), Some(&ua::NodeId::ns0(UA_NS0ID_INT32)));
+        assert!(variant.is_array());
+        assert_eq!(
+            variant.to_array::<ua::Int32>().unwrap().into_vec(),
+            vec![ua::Int32::new(1), ua::Int32::new(2), ua::Int32::new(3)]
+        );
+
+        let variant = ua::Variant::from(vec![1.5, 2.5]);
+        assert_eq!(
+            variant.to_array::<ua::Double>().unwrap().into_vec(),
+            vec![ua::Double::new(1.5), ua::Double::new(2.5)]
+        );
+    }
+
+    #[test]
+    fn approx_eq() {
+        let value = ua::Variant::scalar(ua::Double::new(1.0));
+        let close = ua::Variant::scalar(ua::Double::new(1.0 + 1e-9));
+        let distant = ua::Variant::scalar(ua::Double::new(1.1));
+
+        assert!(value.approx_eq(&close, 1e-6));
+        assert_ne!(value, close);
+        assert!(!value.approx_eq(&distant, 1e-6));
+        assert!(value.approx_eq(&value.clone(), 0.0));
+
+        // Data types must match.
+        assert!(!value.approx_eq(&ua::Variant::scalar(ua::Float::new(1.0)), 1e-6));
+        assert!(!value.approx_eq(&ua::Variant::scalar(ua::Int32::new(1)), 1e-6));
+        let nan = ua::Variant::scalar(ua::Double::new(f64::NAN));
+        assert!(!nan.approx_eq(&nan.clone(), 1e-6));
+
+        // Arrays are compared element by element.
+        let array = |values: &[f32]| {
+            ua::Variant::array(ua::Array::from_iter(
+                values.iter().copied().map(ua::Float::new),
+            ))
+        };
+        assert!(array(&[1.0, 2.0]).approx_eq(&array(&[1.0, 2.000_01]), 1e-3));
+        assert!(!array(&[1.0, 2.0]).approx_eq(&array(&[1.0, 2.1]), 1e-3));
+        assert!(!array(&[1.0, 2.0]).approx_eq(&array(&[1.0, 2.0, 3.0]), 1e-3));
+
+        // Other data types must match exactly.
+        let text = ua::Variant::scalar(ua::String::new("lorem").unwrap());
+        assert!(text.approx_eq(&text.clone(), 1e-6));
+        assert!(!text.approx_eq(
+            &ua::Variant::scalar(ua::String::new("ipsum").unwrap()),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn convert_into_primitive() {
+        let value: f64 = ua::Variant::from(2.5).try_into().unwrap();
+        assert!((value - 2.5).abs() < f64::EPSILON);
+        let value: i32 = ua::Variant::from(42).try_into().unwrap();
+        assert_eq!(value, 42);
+        let value: bool = ua::Variant::from(true).try_into().unwrap();
+        assert!(value);
+        let value: String = ua::Variant::try_from("lorem").unwrap().try_into().unwrap();
+        assert_eq!(value, "lorem");
+        let value: Vec<f64> = ua::Variant::from(vec![1.5]).try_into().unwrap();
+        assert_eq!(value.len(), 1);
+
+        // Reading `Double` as `Int32` fails, there is no implicit conversion.
+        let error = i32::try_from(ua::Variant::from(2.5)).unwrap_err();
+        assert_eq!(error.expected(), "Int32");
+        assert_eq!(error.actual(), "Double");
+        assert_eq!(error.to_string(), "expected Int32, got Double");
+
+        // Scalars and arrays are not interchangeable.
+        let error = Vec::<f64>::try_from(ua::Variant::from(2.5)).unwrap_err();
+        assert_eq!(error.expected(), "array of Double");
+        assert_eq!(error.actual(), "Double");
+        let error = f64::try_from(ua::Variant::from(vec![2.5])).unwrap_err();
+        assert_eq!(error.actual(), "array of Double");
+
+        let error = bool::try_from(ua::Variant::init()).unwrap_err();
+        assert_eq!(error.actual(), "empty variant");
+    }
+
+    #[test]
+    fn clone_variant() {
+        // Clones are deep copies: each of them owns its own heap-allocated string and frees it when
+        // dropped. Run with Miri or ASan to catch double-frees.
+        let ua_variant = ua::Variant::scalar(ua::String::new("lorem ipsum").unwrap());
+        let ua_clone = ua_variant.clone();
+        assert_eq!(ua_variant, ua_clone);
+
+        let original = ua_variant.as_scalar::<ua::String>().unwrap();
+        let cloned = ua_clone.as_scalar::<ua::String>().unwrap();
+        assert_ne!(
+            original.as_bytes().unwrap().as_ptr(),
+            cloned.as_bytes().unwrap().as_ptr()
+        );
+
+        drop(ua_variant);
+        assert_eq!(
+            ua_clone
+                .as_scalar::<ua::String>()
+                .and_then(ua::String::as_str),
+            Some("lorem ipsum")
+        );
+        drop(ua_clone);
+    }
+
+    #[test]
+    fn scalar_type_mismatch() {
+        let ua_variant = ua::Variant::scalar(ua::Int32::new(-123));
+        assert_eq!(ua_variant.to_scalar(), Some(ua::Int32::new(-123)));
+        assert_eq!(ua_variant.as_scalar(), Some(&ua::Int32::new(-123)));
+
+        // Related data types are not converted into each other.
+        assert_eq!(ua_variant.to_scalar::<ua::UInt32>(), None);
+        assert_eq!(ua_variant.to_scalar::<ua::Int64>(), None);
+        assert_eq!(ua_variant.to_scalar::<ua::Double>(), None);
+        assert_eq!(ua_variant.as_scalar::<ua::String>(), None);
+
+        // Empty variants have no scalar value.
+        let ua_variant = ua::Variant::init();
+        assert_eq!(ua_variant.to_scalar::<ua::Int32>(), None);
+
+        // Arrays are not scalars, even when their element type matches.
+        let ua_array = ua::Array::from_slice(&[1, 2, 3].map(ua::Int32::new));
+        let ua_variant = ua::Variant::array(ua_array);
+        assert_eq!(ua_variant.to_scalar::<ua::Int32>(), None);
+    }
+
+    #[test]
+    fn scalar_or_array() {
+        let ua_variant = ua::Variant::init();
+        assert!(ua_variant.is_empty());
+        assert!(!ua_variant.is_scalar());
+        assert!(!ua_variant.is_array());
+        assert_eq!(ua_variant.array_dimensions(), &[]);
+
+        let ua_variant = ua::Variant::scalar(ua::Byte::new(42));
+        assert!(!ua_variant.is_empty());
+        assert!(ua_variant.is_scalar());
+        assert!(!ua_variant.is_array());
+        assert_eq!(ua_variant.array_dimensions(), &[]);
+
+        let ua_array = ua::Array::from_slice(&[1, 2, 3].map(ua::Byte::new));
+        let ua_variant = ua::Variant::array(ua_array);
+        assert!(!ua_variant.is_empty());
+        assert!(!ua_variant.is_scalar());
+        assert!(ua_variant.is_array());
+        assert_eq!(ua_variant.array_dimensions(), &[]);
+
+        // Empty arrays are still arrays.
+        let ua_array = ua::Array::<ua::Byte>::new(0);
+        let ua_variant = ua::Variant::array(ua_array);
+        assert!(!ua_variant.is_empty());
+        assert!(!ua_variant.is_scalar());
+        assert!(ua_variant.is_array());
+    }
+
+    #[test]
+    fn array_extraction() {
+        // Populated array.
+        let ua_array = ua::Array::from_slice(&[1.5, -2.25].map(ua::Double::new));
+        let ua_variant = ua::Variant::array(ua_array);
+        let ua_array = ua_variant.to_array::<ua::Double>().unwrap();
+        assert_eq!(
+            vec![ua::Double::new(1.5), ua::Double::new(-2.25)],
+            ua_array.into_vec(),
+        );
+
+        // Empty array (of length 0 but still with data type).
+        let ua_array = ua::Array::<ua::Double>::new(0);
+        let ua_variant = ua::Variant::array(ua_array);
+        let ua_array = ua_variant.to_array::<ua::Double>().unwrap();
+        assert!(ua_array.is_empty());
+
+        // Element type must match.
+        assert!(ua_variant.to_array::<ua::Float>().is_none());
+
+        // Scalars are not arrays.
+        let ua_variant = ua::Variant::scalar(ua::Double::new(1.5));
+        assert!(ua_variant.to_array::<ua::Double>().is_none());
+    }
+
     #[test]
     fn compare_variant() {
         // Variants of same type compare as expected.
@@ -363,10 +1553,149 @@ mod tests {
         assert_ne!(variant_1, variant_2);
     }
 
+    #[test]
+    fn debug_scalar() {
+        assert_eq!(format!("{:?}", ua::Variant::init()), "Variant(Empty)");
+
+        let variant = ua::Variant::scalar(ua::Boolean::new(true));
+        assert_eq!(
+            format!("{variant:?}"),
+            r#"Variant { type: "Boolean", value: true }"#
+        );
+
+        let variant = ua::Variant::scalar(ua::String::new("lorem").unwrap());
+        assert_eq!(
+            format!("{variant:?}"),
+            r#"Variant { type: "String", value: "lorem" }"#
+        );
+
+        // Structured values are summarized by their encoded length.
+        let variant = ua::Variant::scalar(ua::Argument::init());
+        let output = format!("{variant:?}");
+        assert!(output.starts_with(r#"Variant { type: "Argument", value: Argument ("#));
+        assert!(output.ends_with(" bytes) }"));
+    }
+
+    #[test]
+    fn debug_array() {
+        let variant = ua::Variant::array(ua::Array::from_slice(&[1.5, 2.5].map(ua::Double::new)));
+        assert_eq!(
+            format!("{variant:?}"),
+            r#"Variant { type: "Double", length: 2, values: [1.5, 2.5] }"#
+        );
+
+        let variant = ua::Variant::matrix(&[1, 2, 3, 4].map(ua::Int16::new), &[2, 2]).unwrap();
+        assert_eq!(
+            format!("{variant:?}"),
+            r#"Variant { type: "Int16", length: 4, dimensions: [2, 2], values: [1, 2, 3, 4] }"#
+        );
+
+        let variant = ua::Variant::array(ua::Array::<ua::Byte>::from_slice(&[]));
+        assert_eq!(
+            format!("{variant:?}"),
+            r#"Variant { type: "Byte", length: 0, values: [] }"#
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let variant = ua::Variant::scalar(ua::Float::new(1.5));
+        let json = variant.to_json().expect("should encode variant");
+        // Reversible encoding includes the data type ID of `Float` (ns=0;i=10).
+        assert_eq!(json, r#"{"Type":10,"Body":1.5}"#);
+
+        let variant = ua::Variant::from_json(&json).expect("should decode variant");
+        assert_eq!(variant.as_scalar::<ua::Float>(), Some(&ua::Float::new(1.5)));
+
+        ua::Variant::from_json("lorem").expect_err("should reject invalid JSON");
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use crate::ua;
 
+        #[test]
+        fn round_trip_double() {
+            let ua_variant = ua::Variant::scalar(ua::Double::new(1.5));
+            let json = serde_json::to_string(&ua_variant).unwrap();
+            assert_eq!("1.5", json);
+
+            let ua_variant: ua::Variant = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                ua_variant.as_scalar::<ua::Double>(),
+                Some(&ua::Double::new(1.5))
+            );
+
+            // Integral floating-point numbers keep their data type too.
+            let ua_variant = ua::Variant::scalar(ua::Double::new(2.0));
+            let json = serde_json::to_string(&ua_variant).unwrap();
+            let ua_variant: ua::Variant = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                ua_variant.as_scalar::<ua::Double>(),
+                Some(&ua::Double::new(2.0))
+            );
+        }
+
+        #[test]
+        fn round_trip_int32_array() {
+            let data = [1, -2, 3].map(ua::Int32::new);
+            let ua_variant = ua::Variant::array(ua::Array::from_slice(&data));
+            let json = serde_json::to_string(&ua_variant).unwrap();
+            assert_eq!("[1,-2,3]", json);
+
+            let ua_variant: ua::Variant = serde_json::from_str(&json).unwrap();
+            assert_eq!(ua_variant.to_array::<ua::Int32>().unwrap().as_slice(), data);
+        }
+
+        #[test]
+        fn deserialize_inferred_types() {
+            let ua_variant: ua::Variant = serde_json::from_str("true").unwrap();
+            assert_eq!(
+                ua_variant.as_scalar::<ua::Boolean>(),
+                Some(&ua::Boolean::new(true))
+            );
+
+            let ua_variant: ua::Variant = serde_json::from_str(r#""lorem""#).unwrap();
+            assert_eq!(
+                ua_variant.as_scalar::<ua::String>(),
+                Some(&ua::String::new("lorem").unwrap())
+            );
+
+            // Integers out of range of `Int32` are widened.
+            let ua_variant: ua::Variant = serde_json::from_str("[1, 4294967296]").unwrap();
+            assert_eq!(
+                ua_variant.to_array::<ua::Int64>().unwrap().as_slice(),
+                [1, 4_294_967_296].map(ua::Int64::new)
+            );
+
+            // Integers are converted when mixed with floating-point numbers.
+            let ua_variant: ua::Variant = serde_json::from_str("[1, 2.5]").unwrap();
+            assert_eq!(
+                ua_variant.to_array::<ua::Double>().unwrap().as_slice(),
+                [1.0, 2.5].map(ua::Double::new)
+            );
+
+            // This includes integers out of range of `Int32`, when they can be represented exactly.
+            let ua_variant: ua::Variant =
+                serde_json::from_str("[-4294967296, 9007199254740992, 2.5]").unwrap();
+            assert_eq!(
+                ua_variant.to_array::<ua::Double>().unwrap().as_slice(),
+                [-4_294_967_296.0, 9_007_199_254_740_992.0, 2.5].map(ua::Double::new)
+            );
+
+            // Unsupported payloads.
+            for json in [
+                "null",
+                "{}",
+                "[]",
+                "[[1]]",
+                r#"[1, "lorem"]"#,
+                "[9007199254740993, 2.5]",
+            ] {
+                assert!(serde_json::from_str::<ua::Variant>(json).is_err());
+            }
+        }
+
         #[test]
         fn serialize_bool() {
             // Value `true`
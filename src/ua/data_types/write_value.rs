@@ -20,4 +20,16 @@ impl WriteValue {
         value.clone_into_raw(&mut self.0.value);
         self
     }
+
+    /// Sets index range.
+    ///
+    /// This writes only the given part of array values. The value must hold an array with exactly
+    /// the elements to replace.
+    #[must_use]
+    pub fn with_index_range(mut self, index_range: &ua::NumericRange) -> Self {
+        index_range
+            .to_string_value()
+            .move_into_raw(&mut self.0.indexRange);
+        self
+    }
 }
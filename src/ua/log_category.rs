@@ -0,0 +1,23 @@
+use open62541_sys::UA_LogCategory;
+
+/// Wrapper for [`UA_LogCategory`] from [`open62541_sys`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogCategory(UA_LogCategory);
+
+impl LogCategory {
+    pub const NETWORK: Self = Self(UA_LogCategory::UA_LOGCATEGORY_NETWORK);
+    pub const SECURECHANNEL: Self = Self(UA_LogCategory::UA_LOGCATEGORY_SECURECHANNEL);
+    pub const SESSION: Self = Self(UA_LogCategory::UA_LOGCATEGORY_SESSION);
+    pub const SERVER: Self = Self(UA_LogCategory::UA_LOGCATEGORY_SERVER);
+    pub const CLIENT: Self = Self(UA_LogCategory::UA_LOGCATEGORY_CLIENT);
+    pub const USERLAND: Self = Self(UA_LogCategory::UA_LOGCATEGORY_USERLAND);
+    pub const SECURITYPOLICY: Self = Self(UA_LogCategory::UA_LOGCATEGORY_SECURITYPOLICY);
+    pub const EVENTLOOP: Self = Self(UA_LogCategory::UA_LOGCATEGORY_EVENTLOOP);
+    pub const PUBSUB: Self = Self(UA_LogCategory::UA_LOGCATEGORY_PUBSUB);
+    pub const DISCOVERY: Self = Self(UA_LogCategory::UA_LOGCATEGORY_DISCOVERY);
+
+    /// Creates wrapper from value.
+    pub(crate) const fn from_raw(category: UA_LogCategory) -> Self {
+        Self(category)
+    }
+}
@@ -0,0 +1,19 @@
+use open62541_sys::UA_LogLevel;
+
+/// Wrapper for [`UA_LogLevel`] from [`open62541_sys`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogLevel(UA_LogLevel);
+
+impl LogLevel {
+    pub const TRACE: Self = Self(UA_LogLevel::UA_LOGLEVEL_TRACE);
+    pub const DEBUG: Self = Self(UA_LogLevel::UA_LOGLEVEL_DEBUG);
+    pub const INFO: Self = Self(UA_LogLevel::UA_LOGLEVEL_INFO);
+    pub const WARNING: Self = Self(UA_LogLevel::UA_LOGLEVEL_WARNING);
+    pub const ERROR: Self = Self(UA_LogLevel::UA_LOGLEVEL_ERROR);
+    pub const FATAL: Self = Self(UA_LogLevel::UA_LOGLEVEL_FATAL);
+
+    /// Creates wrapper from value.
+    pub(crate) const fn from_raw(level: UA_LogLevel) -> Self {
+        Self(level)
+    }
+}
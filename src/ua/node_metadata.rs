@@ -0,0 +1,91 @@
+use crate::ua;
+
+/// Metadata of node.
+///
+/// This is returned by [`Client::read_node_metadata()`]. Attributes that only exist for certain
+/// node classes are `None` when the node does not have them, e.g. the data type of object nodes.
+///
+/// [`Client::read_node_metadata()`]: crate::Client::read_node_metadata
+#[derive(Debug, Clone)]
+pub struct NodeMetadata {
+    node_class: ua::NodeClass,
+    browse_name: ua::QualifiedName,
+    display_name: ua::LocalizedText,
+    description: Option<ua::LocalizedText>,
+    data_type: Option<ua::NodeId>,
+    value_rank: Option<i32>,
+    access_level: Option<ua::AccessLevel>,
+}
+
+impl NodeMetadata {
+    pub(crate) const fn new(
+        node_class: ua::NodeClass,
+        browse_name: ua::QualifiedName,
+        display_name: ua::LocalizedText,
+        description: Option<ua::LocalizedText>,
+        data_type: Option<ua::NodeId>,
+        value_rank: Option<i32>,
+        access_level: Option<ua::AccessLevel>,
+    ) -> Self {
+        Self {
+            node_class,
+            browse_name,
+            display_name,
+            description,
+            data_type,
+            value_rank,
+            access_level,
+        }
+    }
+
+    /// Gets node class.
+    #[must_use]
+    pub const fn node_class(&self) -> &ua::NodeClass {
+        &self.node_class
+    }
+
+    /// Gets browse name.
+    #[must_use]
+    pub const fn browse_name(&self) -> &ua::QualifiedName {
+        &self.browse_name
+    }
+
+    /// Gets display name.
+    #[must_use]
+    pub const fn display_name(&self) -> &ua::LocalizedText {
+        &self.display_name
+    }
+
+    /// Gets description.
+    ///
+    /// This is optional for all node classes.
+    #[must_use]
+    pub const fn description(&self) -> Option<&ua::LocalizedText> {
+        self.description.as_ref()
+    }
+
+    /// Gets data type of value.
+    ///
+    /// This is only set for variables and variable types.
+    #[must_use]
+    pub const fn data_type(&self) -> Option<&ua::NodeId> {
+        self.data_type.as_ref()
+    }
+
+    /// Gets value rank.
+    ///
+    /// This is only set for variables and variable types. The value `-1` indicates a scalar, `1`
+    /// and above indicate an array with this number of dimensions.
+    #[must_use]
+    pub const fn value_rank(&self) -> Option<i32> {
+        self.value_rank
+    }
+
+    /// Gets access level of value.
+    ///
+    /// This is only set for variables.
+    #[must_use]
+    pub const fn access_level(&self) -> Option<&ua::AccessLevel> {
+        self.access_level.as_ref()
+    }
+}
@@ -0,0 +1,147 @@
+use std::{fmt, str};
+
+use crate::{ua, Error};
+
+/// Numeric range for accessing parts of array values.
+///
+/// Numeric ranges have no data type of their own: they are transmitted as string, e.g. `5` for the
+/// single element at index 5, `3:7` for the elements at indices 3 through 7, or `1:2,0:3` for a
+/// range in each dimension of a two-dimensional array.
+///
+/// ```
+/// use open62541::ua;
+///
+/// let index_range: ua::NumericRange = "3:7".parse().expect("should be valid range");
+/// assert_eq!(index_range.dimensions(), &[(3, 7)]);
+///
+/// // Ranges of size one must be given as single index.
+/// assert!("5:5".parse::<ua::NumericRange>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericRange(Vec<(u32, u32)>);
+
+impl NumericRange {
+    /// Gets dimensions.
+    ///
+    /// This returns the first and last index (inclusive) of the range in each dimension.
+    #[must_use]
+    pub fn dimensions(&self) -> &[(u32, u32)] {
+        &self.0
+    }
+
+    /// Gets number of elements in range.
+    ///
+    /// This returns `None` when the number does not fit into `usize`.
+    pub(crate) fn element_count(&self) -> Option<usize> {
+        self.0.iter().try_fold(1usize, |count, &(min, max)| {
+            let length = usize::try_from(max - min).ok()?.checked_add(1)?;
+            count.checked_mul(length)
+        })
+    }
+
+    pub(crate) fn to_string_value(&self) -> ua::String {
+        // PANIC: Formatted ranges contain only digits and separators.
+        ua::String::new(&self.to_string()).expect("numeric range should not contain NUL bytes")
+    }
+}
+
+impl str::FromStr for NumericRange {
+    type Err = Error;
+
+    /// Parses numeric range.
+    ///
+    /// This fails with [`ua::StatusCode::BADINDEXRANGEINVALID`] when the string is malformed, the
+    /// same status code that servers respond with.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_index(s: &str) -> Option<u32> {
+            // Do not accept signs or whitespace which `u32::from_str()` would allow.
+            if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            s.parse().ok()
+        }
+
+        fn parse_dimension(s: &str) -> Option<(u32, u32)> {
+            if let Some((min, max)) = s.split_once(':') {
+                let (min, max) = (parse_index(min)?, parse_index(max)?);
+                // Range must have at least two elements, otherwise single index must be used.
+                (min < max).then_some((min, max))
+            } else {
+                let index = parse_index(s)?;
+                Some((index, index))
+            }
+        }
+
+        s.split(',')
+            .map(parse_dimension)
+            .collect::<Option<Vec<_>>>()
+            .map(Self)
+            .ok_or(Error::Server(ua::StatusCode::BADINDEXRANGEINVALID))
+    }
+}
+
+impl fmt::Display for NumericRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &(min, max)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            if min == max {
+                write!(f, "{min}")?;
+            } else {
+                write!(f, "{min}:{max}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    #[test]
+    fn parse_valid_ranges() {
+        for (s, dimensions, element_count) in [
+            ("5", &[(5, 5)][..], 1),
+            ("3:7", &[(3, 7)][..], 5),
+            ("1:2,0:3", &[(1, 2), (0, 3)][..], 8),
+            ("0,4294967295", &[(0, 0), (u32::MAX, u32::MAX)][..], 1),
+        ] {
+            let index_range: ua::NumericRange = s.parse().expect("should be valid range");
+            assert_eq!(index_range.dimensions(), dimensions);
+            assert_eq!(index_range.element_count(), Some(element_count));
+            // Formatting returns the original string.
+            assert_eq!(index_range.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_ranges() {
+        for s in [
+            "",
+            ",",
+            "1,",
+            ":",
+            "1:",
+            ":2",
+            "2:1",
+            "3:3",
+            "-1",
+            "+1",
+            " 1",
+            "1:2:3",
+            "a",
+            "4294967296",
+        ] {
+            let error = s
+                .parse::<ua::NumericRange>()
+                .expect_err("should be invalid range");
+            assert_eq!(
+                error.status_code(),
+                ua::StatusCode::BADINDEXRANGEINVALID,
+                "{s:?}"
+            );
+        }
+    }
+}
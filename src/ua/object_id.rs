@@ -0,0 +1,26 @@
+use open62541_sys::{
+    UA_NS0ID_DATATYPESFOLDER, UA_NS0ID_MODELLINGRULE_MANDATORY, UA_NS0ID_MODELLINGRULE_OPTIONAL,
+    UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_OBJECTTYPESFOLDER, UA_NS0ID_REFERENCETYPESFOLDER,
+    UA_NS0ID_ROOTFOLDER, UA_NS0ID_SERVER, UA_NS0ID_TYPESFOLDER, UA_NS0ID_VARIABLETYPESFOLDER,
+    UA_NS0ID_VIEWSFOLDER,
+};
+
+/// Wrapper for object IDs from [`open62541_sys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectId(u32);
+
+crate::ns0_id!(ObjectId);
+
+impl ObjectId {
+    pub const ROOTFOLDER: Self = Self(UA_NS0ID_ROOTFOLDER);
+    pub const OBJECTSFOLDER: Self = Self(UA_NS0ID_OBJECTSFOLDER);
+    pub const TYPESFOLDER: Self = Self(UA_NS0ID_TYPESFOLDER);
+    pub const VIEWSFOLDER: Self = Self(UA_NS0ID_VIEWSFOLDER);
+    pub const OBJECTTYPESFOLDER: Self = Self(UA_NS0ID_OBJECTTYPESFOLDER);
+    pub const VARIABLETYPESFOLDER: Self = Self(UA_NS0ID_VARIABLETYPESFOLDER);
+    pub const DATATYPESFOLDER: Self = Self(UA_NS0ID_DATATYPESFOLDER);
+    pub const REFERENCETYPESFOLDER: Self = Self(UA_NS0ID_REFERENCETYPESFOLDER);
+    pub const SERVER: Self = Self(UA_NS0ID_SERVER);
+    pub const MODELLINGRULE_MANDATORY: Self = Self(UA_NS0ID_MODELLINGRULE_MANDATORY);
+    pub const MODELLINGRULE_OPTIONAL: Self = Self(UA_NS0ID_MODELLINGRULE_OPTIONAL);
+}
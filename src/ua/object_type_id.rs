@@ -0,0 +1,18 @@
+use open62541_sys::{
+    UA_NS0ID_BASEEVENTTYPE, UA_NS0ID_BASEOBJECTTYPE, UA_NS0ID_FOLDERTYPE,
+    UA_NS0ID_MODELLINGRULETYPE, UA_NS0ID_SERVERTYPE,
+};
+
+/// Wrapper for object type IDs from [`open62541_sys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectTypeId(u32);
+
+crate::ns0_id!(ObjectTypeId);
+
+impl ObjectTypeId {
+    pub const BASEOBJECTTYPE: Self = Self(UA_NS0ID_BASEOBJECTTYPE);
+    pub const FOLDERTYPE: Self = Self(UA_NS0ID_FOLDERTYPE);
+    pub const BASEEVENTTYPE: Self = Self(UA_NS0ID_BASEEVENTTYPE);
+    pub const SERVERTYPE: Self = Self(UA_NS0ID_SERVERTYPE);
+    pub const MODELLINGRULETYPE: Self = Self(UA_NS0ID_MODELLINGRULETYPE);
+}
@@ -0,0 +1,74 @@
+use open62541_sys::{
+    UA_NS0ID_AGGREGATES, UA_NS0ID_GENERATESEVENT, UA_NS0ID_HASCHILD, UA_NS0ID_HASCOMPONENT,
+    UA_NS0ID_HASDESCRIPTION, UA_NS0ID_HASENCODING, UA_NS0ID_HASEVENTSOURCE,
+    UA_NS0ID_HASMODELLINGRULE, UA_NS0ID_HASNOTIFIER, UA_NS0ID_HASORDEREDCOMPONENT,
+    UA_NS0ID_HASPROPERTY, UA_NS0ID_HASSUBTYPE, UA_NS0ID_HASTYPEDEFINITION,
+    UA_NS0ID_HIERARCHICALREFERENCES, UA_NS0ID_NONHIERARCHICALREFERENCES, UA_NS0ID_ORGANIZES,
+    UA_NS0ID_REFERENCES,
+};
+
+/// Wrapper for reference type IDs from [`open62541_sys`].
+///
+/// These are the well-known reference types in namespace 0. Use them instead of numeric node IDs
+/// when adding references or browsing:
+///
+/// ```
+/// use open62541::ua;
+///
+/// let browse_description = ua::BrowseDescription::default()
+///     .with_node_id(&ua::ObjectId::OBJECTSFOLDER.to_node_id())
+///     .with_reference_type_id(&ua::ReferenceTypeId::ORGANIZES.to_node_id());
+/// ```
+///
+/// See also [`ua::ObjectId`], [`ua::ObjectTypeId`], and [`ua::VariableTypeId`].
+///
+/// [`ua::ObjectId`]: crate::ua::ObjectId
+/// [`ua::ObjectTypeId`]: crate::ua::ObjectTypeId
+/// [`ua::VariableTypeId`]: crate::ua::VariableTypeId
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReferenceTypeId(u32);
+
+crate::ns0_id!(ReferenceTypeId);
+
+impl ReferenceTypeId {
+    pub const REFERENCES: Self = Self(UA_NS0ID_REFERENCES);
+    pub const HIERARCHICALREFERENCES: Self = Self(UA_NS0ID_HIERARCHICALREFERENCES);
+    pub const NONHIERARCHICALREFERENCES: Self = Self(UA_NS0ID_NONHIERARCHICALREFERENCES);
+    pub const HASCHILD: Self = Self(UA_NS0ID_HASCHILD);
+    pub const ORGANIZES: Self = Self(UA_NS0ID_ORGANIZES);
+    pub const HASEVENTSOURCE: Self = Self(UA_NS0ID_HASEVENTSOURCE);
+    pub const HASMODELLINGRULE: Self = Self(UA_NS0ID_HASMODELLINGRULE);
+    pub const HASENCODING: Self = Self(UA_NS0ID_HASENCODING);
+    pub const HASDESCRIPTION: Self = Self(UA_NS0ID_HASDESCRIPTION);
+    pub const HASTYPEDEFINITION: Self = Self(UA_NS0ID_HASTYPEDEFINITION);
+    pub const GENERATESEVENT: Self = Self(UA_NS0ID_GENERATESEVENT);
+    pub const AGGREGATES: Self = Self(UA_NS0ID_AGGREGATES);
+    pub const HASSUBTYPE: Self = Self(UA_NS0ID_HASSUBTYPE);
+    pub const HASPROPERTY: Self = Self(UA_NS0ID_HASPROPERTY);
+    pub const HASCOMPONENT: Self = Self(UA_NS0ID_HASCOMPONENT);
+    pub const HASNOTIFIER: Self = Self(UA_NS0ID_HASNOTIFIER);
+    pub const HASORDEREDCOMPONENT: Self = Self(UA_NS0ID_HASORDEREDCOMPONENT);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    #[test]
+    fn resolve_numeric_ids() {
+        // Values from `NodeIds.csv` of the OPC UA specification.
+        assert_eq!(ua::ReferenceTypeId::ORGANIZES.as_u32(), 35);
+        assert_eq!(ua::ReferenceTypeId::HASCOMPONENT.as_u32(), 47);
+        assert_eq!(ua::ObjectTypeId::FOLDERTYPE.as_u32(), 61);
+        assert_eq!(ua::VariableTypeId::BASEDATAVARIABLETYPE.as_u32(), 63);
+
+        assert_eq!(
+            ua::ReferenceTypeId::HASTYPEDEFINITION.to_node_id(),
+            ua::NodeId::numeric(0, 40)
+        );
+        assert_eq!(
+            ua::NodeId::from(ua::ObjectId::OBJECTSFOLDER),
+            ua::NodeId::numeric(0, 85)
+        );
+    }
+}
@@ -1,10 +1,26 @@
 use open62541_sys::UA_SecureChannelState;
 
 /// Wrapper for [`UA_SecureChannelState`] from [`open62541_sys`].
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SecureChannelState(UA_SecureChannelState);
 
 impl SecureChannelState {
+    pub const CLOSED: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_CLOSED);
+    pub const REVERSE_LISTENING: Self =
+        Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_REVERSE_LISTENING);
+    pub const CONNECTING: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_CONNECTING);
+    pub const CONNECTED: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_CONNECTED);
+    pub const REVERSE_CONNECTED: Self =
+        Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_REVERSE_CONNECTED);
+    pub const RHE_SENT: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_RHE_SENT);
+    pub const HEL_SENT: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_HEL_SENT);
+    pub const HEL_RECEIVED: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_HEL_RECEIVED);
+    pub const ACK_SENT: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_ACK_SENT);
+    pub const ACK_RECEIVED: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_ACK_RECEIVED);
+    pub const OPN_SENT: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_OPN_SENT);
+    pub const OPEN: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_OPEN);
+    pub const CLOSING: Self = Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_CLOSING);
+
     /// Creates wrapper initialized with defaults.
     #[must_use]
     #[allow(dead_code)] // --no-default-features
@@ -13,6 +29,11 @@ impl SecureChannelState {
         Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_CLOSED)
     }
 
+    /// Creates wrapper from value.
+    pub(crate) const fn from_raw(state: UA_SecureChannelState) -> Self {
+        Self(state)
+    }
+
     /// Returns mutable pointer to value.
     #[must_use]
     #[allow(dead_code)] // --no-default-features
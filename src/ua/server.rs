@@ -1,4 +1,9 @@
-use std::ptr::{self, NonNull};
+use std::{
+    ffi::c_void,
+    mem,
+    ptr::{self, NonNull},
+    sync::{Mutex, PoisonError},
+};
 
 use open62541_sys::{UA_Server, UA_Server_delete, UA_Server_newWithConfig};
 
@@ -9,7 +14,7 @@ use crate::{ua, Error};
 /// This owns the wrapped data type. When the wrapper is dropped, its inner value is cleaned up with
 /// [`UA_Server_delete()`].
 #[derive(Debug)]
-pub struct Server(NonNull<UA_Server>);
+pub struct Server(NonNull<UA_Server>, Mutex<Vec<CallbackData>>);
 
 // SAFETY: We know that the underlying `UA_Server` allows access from different threads, i.e. it may
 // be dropped in a different thread from where it was created.
@@ -29,7 +34,27 @@ impl Server {
         let inner = unsafe { UA_Server_newWithConfig(ptr::addr_of_mut!(config)) };
         // PANIC: The only possible errors here are out-of-memory.
         let inner = NonNull::new(inner).expect("create UA_Server");
-        Self(inner)
+        Self(inner, Mutex::new(Vec::new()))
+    }
+
+    /// Releases callback data when the server is deleted.
+    ///
+    /// Use this for data that is passed to callbacks which may run for as long as the server lives.
+    /// The data is released with `release` only after `UA_Server_delete()` has returned.
+    ///
+    /// # Safety
+    ///
+    /// `release` must be safe to call with `data` exactly once, and `data` must not be released
+    /// elsewhere.
+    pub(crate) unsafe fn release_on_delete(
+        &self,
+        data: *mut c_void,
+        release: unsafe fn(*mut c_void),
+    ) {
+        self.1
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(CallbackData { data, release });
     }
 
     /// Returns const pointer to value.
@@ -67,5 +92,19 @@ impl Drop for Server {
         if let Err(error) = Error::verify_good(&status_code) {
             log::warn!("Error while dropping server: {error}");
         }
+
+        // The server has been deleted, its callbacks can no longer run.
+        let callback_data = mem::take(self.1.get_mut().unwrap_or_else(PoisonError::into_inner));
+        for CallbackData { data, release } in callback_data {
+            // SAFETY: Data has been registered with `release_on_delete()` and is released only once.
+            unsafe { release(data) };
+        }
     }
 }
+
+/// Data passed to server callbacks.
+#[derive(Debug)]
+struct CallbackData {
+    data: *mut c_void,
+    release: unsafe fn(*mut c_void),
+}
@@ -1,10 +1,17 @@
 use open62541_sys::UA_SessionState;
 
 /// Wrapper for [`UA_SessionState`] from [`open62541_sys`].
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SessionState(UA_SessionState);
 
 impl SessionState {
+    pub const CLOSED: Self = Self(UA_SessionState::UA_SESSIONSTATE_CLOSED);
+    pub const CREATE_REQUESTED: Self = Self(UA_SessionState::UA_SESSIONSTATE_CREATE_REQUESTED);
+    pub const CREATED: Self = Self(UA_SessionState::UA_SESSIONSTATE_CREATED);
+    pub const ACTIVATE_REQUESTED: Self = Self(UA_SessionState::UA_SESSIONSTATE_ACTIVATE_REQUESTED);
+    pub const ACTIVATED: Self = Self(UA_SessionState::UA_SESSIONSTATE_ACTIVATED);
+    pub const CLOSING: Self = Self(UA_SessionState::UA_SESSIONSTATE_CLOSING);
+
     /// Creates wrapper initialized with defaults.
     #[must_use]
     pub(crate) const fn init() -> Self {
@@ -12,6 +19,11 @@ impl SessionState {
         Self(UA_SessionState::UA_SESSIONSTATE_CLOSED)
     }
 
+    /// Creates wrapper from value.
+    pub(crate) const fn from_raw(state: UA_SessionState) -> Self {
+        Self(state)
+    }
+
     /// Returns mutable pointer to value.
     #[must_use]
     pub(crate) fn as_mut_ptr(&mut self) -> *mut UA_SessionState {
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use crate::ua;
+
+/// Parameters for creating subscriptions.
+///
+/// This is passed to [`Client::create_subscription()`]. The default parameters match those of
+/// [`UA_CreateSubscriptionRequest_default()`].
+///
+/// The server may revise the requested values, e.g. to clamp them to the limits it supports. Use
+/// [`Subscription::revised_publishing_interval()`] and related methods to get the actual values.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use open62541::ua;
+///
+/// let parameters = ua::SubscriptionParameters::default()
+///     .publishing_interval(Duration::from_millis(250))
+///     .max_keep_alive_count(20)
+///     .lifetime_count(60)
+///     .priority(1);
+/// ```
+///
+/// [`Client::create_subscription()`]: crate::Client::create_subscription
+/// [`Subscription::revised_publishing_interval()`]: crate::Subscription::revised_publishing_interval
+/// [`UA_CreateSubscriptionRequest_default()`]: open62541_sys::UA_CreateSubscriptionRequest_default
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionParameters(ua::CreateSubscriptionRequest);
+
+impl SubscriptionParameters {
+    /// Sets requested publishing interval.
+    ///
+    /// This is the interval in which the server sends notifications to the client.
+    #[must_use]
+    pub fn publishing_interval(mut self, publishing_interval: Duration) -> Self {
+        // `UA_CreateSubscriptionRequest` expects the publishing interval given in milliseconds.
+        let publishing_interval = publishing_interval.as_secs_f64() * 1000.0;
+        self.0 = self
+            .0
+            .with_requested_publishing_interval(publishing_interval);
+        self
+    }
+
+    /// Sets requested lifetime count.
+    ///
+    /// The subscription is deleted by the server when it has not been able to send notifications for
+    /// this many publishing intervals. This should be at least three times the keep-alive count.
+    #[must_use]
+    pub fn lifetime_count(mut self, lifetime_count: u32) -> Self {
+        self.0 = self.0.with_requested_lifetime_count(lifetime_count);
+        self
+    }
+
+    /// Sets requested maximum keep-alive count.
+    ///
+    /// When there are no notifications, the server sends a keep-alive message after this many
+    /// publishing intervals.
+    #[must_use]
+    pub fn max_keep_alive_count(mut self, max_keep_alive_count: u32) -> Self {
+        self.0 = self
+            .0
+            .with_requested_max_keep_alive_count(max_keep_alive_count);
+        self
+    }
+
+    /// Sets maximum number of notifications per publish response.
+    ///
+    /// Use `0` to not limit the number of notifications.
+    #[must_use]
+    pub fn max_notifications_per_publish(mut self, max_notifications_per_publish: u32) -> Self {
+        self.0 = self
+            .0
+            .with_max_notifications_per_publish(max_notifications_per_publish);
+        self
+    }
+
+    /// Sets priority.
+    ///
+    /// Subscriptions with higher priority are served first when the server needs to choose.
+    #[must_use]
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.0 = self.0.with_priority(priority);
+        self
+    }
+
+    pub(crate) const fn as_request(&self) -> &ua::CreateSubscriptionRequest {
+        &self.0
+    }
+}
@@ -0,0 +1,18 @@
+use open62541_sys::{
+    UA_NS0ID_ANALOGITEMTYPE, UA_NS0ID_BASEDATAVARIABLETYPE, UA_NS0ID_BASEVARIABLETYPE,
+    UA_NS0ID_DATAITEMTYPE, UA_NS0ID_PROPERTYTYPE,
+};
+
+/// Wrapper for variable type IDs from [`open62541_sys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariableTypeId(u32);
+
+crate::ns0_id!(VariableTypeId);
+
+impl VariableTypeId {
+    pub const BASEVARIABLETYPE: Self = Self(UA_NS0ID_BASEVARIABLETYPE);
+    pub const BASEDATAVARIABLETYPE: Self = Self(UA_NS0ID_BASEDATAVARIABLETYPE);
+    pub const PROPERTYTYPE: Self = Self(UA_NS0ID_PROPERTYTYPE);
+    pub const DATAITEMTYPE: Self = Self(UA_NS0ID_DATAITEMTYPE);
+    pub const ANALOGITEMTYPE: Self = Self(UA_NS0ID_ANALOGITEMTYPE);
+}